@@ -0,0 +1,45 @@
+//! Benchmarking for searching algorithms on sorted numeric data
+//! 
+//! This module compares binary search against interpolation, jump and Fibonacci search.
+//! The data is the uniformly distributed output of `generate_sorted_integers`, plus a
+//! skewed (quadratic) distribution that defeats naive interpolation.
+//! 
+//! # Benchmarking Framework
+//! 
+//! We use the Criterion.rs library for benchmarking, which provides statistically rigorous measurements.
+//! Each benchmark searches for a fixed set of targets so all algorithms do identical work.
+
+extern crate criterion;
+use criterion::{criterion_group, criterion_main, Criterion, black_box};
+use dsa_in_rust::algorithms::searching::binary_search::binary_search;
+use dsa_in_rust::algorithms::searching::numeric_search::{fibonacci_search, interpolation_search, jump_search};
+use dsa_in_rust::utils::helpers::generate_sorted_integers;
+
+/// Benchmarks each search on uniform and skewed data of 1,000,000 integers
+/// 
+/// Targets are every 997th element, giving ~1,000 lookups spread over the whole range.
+fn benchmark_numeric_search(c: &mut Criterion) {
+    let uniform: Vec<i64> = generate_sorted_integers(1_000_000).into_iter().map(i64::from).collect();
+    let skewed: Vec<i64> = uniform.iter().map(|&x| x * x).collect();
+
+    for (name, data) in [("uniform", &uniform), ("skewed", &skewed)] {
+        let targets: Vec<i64> = data.iter().step_by(997).copied().collect();
+
+        c.bench_function(&format!("binary_search/{}", name), |b| {
+            b.iter(|| targets.iter().filter(|t| binary_search(black_box(data), t).is_ok()).count())
+        });
+        c.bench_function(&format!("interpolation_search/{}", name), |b| {
+            b.iter(|| targets.iter().map(|t| interpolation_search(black_box(data), t).probes).sum::<usize>())
+        });
+        c.bench_function(&format!("jump_search/{}", name), |b| {
+            b.iter(|| targets.iter().map(|t| jump_search(black_box(data), t).probes).sum::<usize>())
+        });
+        c.bench_function(&format!("fibonacci_search/{}", name), |b| {
+            b.iter(|| targets.iter().map(|t| fibonacci_search(black_box(data), t).probes).sum::<usize>())
+        });
+    }
+}
+
+// Group the benchmarks and define the main function
+criterion_group!(benches, benchmark_numeric_search);
+criterion_main!(benches);
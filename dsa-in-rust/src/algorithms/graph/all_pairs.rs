@@ -0,0 +1,218 @@
+//! All-Pairs Shortest Paths: Floyd–Warshall and Johnson
+//!
+//! DEV NOTES:
+//! - Both algorithms return a `DistanceMatrix`: a V x V table of distances
+//!   plus a next-hop table, so any path is rebuilt by following next hops
+//! - Floyd–Warshall lets every vertex in turn act as an intermediate stop; it
+//!   is simple and cache friendly, the right choice for dense graphs
+//! - Johnson's algorithm computes potentials `h` with Bellman–Ford from a
+//!   virtual source, reweights each edge to `w(u, v) + h(u) - h(v) >= 0`, runs
+//!   Dijkstra from every vertex and undoes the reweighting; on sparse graphs
+//!   with negative edges it beats Floyd–Warshall's O(V^3)
+//! - Negative cycles make shortest paths undefined; both algorithms report
+//!   `GraphError::NegativeCycle` with a concrete cycle (found by Bellman–Ford)
+//! - Among equally short paths the two algorithms may pick different ones
+//!
+//! PERFORMANCE CONSIDERATIONS:
+//! - Floyd–Warshall: O(V^3) time, O(V^2) space
+//! - Johnson: O(V * E log V) time, O(V^2) space for the result
+
+use super::shortest_path::Weight;
+use crate::data_structures::graph::{Graph, GraphError};
+
+/// Shortest distances and next hops between every pair of vertices
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::data_structures::graph::Graph;
+///
+/// let mut graph: Graph<(), i32> = Graph::weighted(4);
+/// graph.add_weighted_edge(0, 1, 5).unwrap();
+/// graph.add_weighted_edge(0, 2, 2).unwrap();
+/// graph.add_weighted_edge(2, 1, -1).unwrap();
+/// graph.add_weighted_edge(1, 3, 1).unwrap();
+///
+/// let matrix = graph.floyd_warshall().unwrap();
+/// assert_eq!(matrix.distance(0, 3), Some(2));
+/// assert_eq!(matrix.next_hop(0, 3), Some(2));
+/// assert_eq!(matrix.path(0, 3).unwrap(), [0, 2, 1, 3]);
+/// assert_eq!(matrix.distance(3, 0), None);
+/// assert_eq!(graph.johnson().unwrap(), matrix);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DistanceMatrix<W> {
+    node_count: usize,
+    /// `distances[from * node_count + to]`
+    distances: Vec<Option<W>>,
+    /// First vertex after `from` on a shortest path to `to`
+    next: Vec<Option<usize>>,
+}
+
+impl<W: Copy> DistanceMatrix<W> {
+    /// Returns the number of vertices
+    pub fn node_count(&self) -> usize {
+        self.node_count
+    }
+
+    /// Returns the shortest distance, None if unreachable or out of range
+    pub fn distance(&self, from: usize, to: usize) -> Option<W> {
+        self.index(from, to).and_then(|index| self.distances[index])
+    }
+
+    /// Returns the distances from `from` to every vertex
+    ///
+    /// # Panics
+    /// Panics if `from >= node_count()`
+    pub fn row(&self, from: usize) -> &[Option<W>] {
+        &self.distances[from * self.node_count..(from + 1) * self.node_count]
+    }
+
+    /// Returns the vertex following `from` on a shortest path to `to`;
+    /// None if `to` is unreachable or equal to `from`
+    pub fn next_hop(&self, from: usize, to: usize) -> Option<usize> {
+        self.index(from, to).and_then(|index| self.next[index])
+    }
+
+    /// Rebuilds a shortest path by following next hops
+    ///
+    /// # Returns
+    /// * `Result<Vec<usize>, GraphError>` - Vertices from `from` to `to` inclusive;
+    ///   Err(NoPath) if unreachable, Err(InvalidNode) if out of range
+    ///
+    /// # Performance
+    /// * Time Complexity: O(path length)
+    pub fn path(&self, from: usize, to: usize) -> Result<Vec<usize>, GraphError> {
+        for node in [from, to] {
+            if node >= self.node_count {
+                return Err(GraphError::InvalidNode(node));
+            }
+        }
+        if self.distance(from, to).is_none() {
+            return Err(GraphError::NoPath(from, to));
+        }
+        let mut path = vec![from];
+        let mut node = from;
+        while node != to {
+            node = self.next_hop(node, to).expect("reachable targets have next hops");
+            path.push(node);
+        }
+        Ok(path)
+    }
+
+    fn index(&self, from: usize, to: usize) -> Option<usize> {
+        (from < self.node_count && to < self.node_count).then_some(from * self.node_count + to)
+    }
+}
+
+impl<N, W: Weight> Graph<N, W> {
+    /// Computes all-pairs shortest paths with the Floyd–Warshall algorithm
+    ///
+    /// # Returns
+    /// * `Result<DistanceMatrix<W>, GraphError>` - Distances and next hops;
+    ///   Err(NegativeCycle) if the graph has a negative cycle
+    ///
+    /// # Performance
+    /// * Time Complexity: O(V^3)
+    /// * Space Complexity: O(V^2)
+    pub fn floyd_warshall(&self) -> Result<DistanceMatrix<W>, GraphError> {
+        let n = self.node_count();
+        let mut distances: Vec<Option<W>> = vec![None; n * n];
+        let mut next = vec![None; n * n];
+        for from in 0..n {
+            distances[from * n + from] = Some(W::ZERO);
+            for (to, &weight) in self.edges(from) {
+                let cell = &mut distances[from * n + to];
+                if cell.is_none_or(|current| weight < current) {
+                    *cell = Some(weight);
+                    next[from * n + to] = Some(to);
+                }
+            }
+        }
+
+        for via in 0..n {
+            for from in 0..n {
+                let Some(first) = distances[from * n + via] else { continue };
+                for to in 0..n {
+                    let Some(second) = distances[via * n + to] else { continue };
+                    let candidate = first + second;
+                    if distances[from * n + to].is_none_or(|current| candidate < current) {
+                        distances[from * n + to] = Some(candidate);
+                        next[from * n + to] = next[from * n + via];
+                    }
+                }
+            }
+            // Stop before repeated doubling around a negative cycle overflows
+            if (0..n).any(|node| distances[node * n + node].is_some_and(|d| d < W::ZERO)) {
+                let cycle = self.negative_cycle().expect("a vertex has a negative distance to itself");
+                return Err(GraphError::NegativeCycle(cycle));
+            }
+        }
+        // `from -> from` is the empty path, not a zero-weight cycle
+        for node in 0..n {
+            next[node * n + node] = None;
+        }
+        Ok(DistanceMatrix { node_count: n, distances, next })
+    }
+
+    /// Computes all-pairs shortest paths with Johnson's reweighting algorithm
+    ///
+    /// # Returns
+    /// * `Result<DistanceMatrix<W>, GraphError>` - Distances and next hops;
+    ///   Err(NegativeCycle) if the graph has a negative cycle
+    ///
+    /// # Performance
+    /// * Time Complexity: O(V * E + V * (V + E) log V)
+    /// * Space Complexity: O(V^2)
+    pub fn johnson(&self) -> Result<DistanceMatrix<W>, GraphError> {
+        let n = self.node_count();
+        // Potentials: distances from a virtual source with a zero edge to every vertex
+        let mut potentials = vec![Some(W::ZERO); n];
+        self.relax_rounds(&mut potentials).map_err(GraphError::NegativeCycle)?;
+        let potential = |node: usize| potentials[node].expect("every vertex is reached");
+
+        let mut reweighted: Graph<(), W> = Graph::weighted(n);
+        for from in 0..n {
+            for (to, &weight) in self.edges(from) {
+                reweighted
+                    .add_weighted_edge(from, to, weight + potential(from) - potential(to))
+                    .expect("edges are copied from a valid graph");
+            }
+        }
+
+        let mut distances = Vec::with_capacity(n * n);
+        let mut next = Vec::with_capacity(n * n);
+        for source in 0..n {
+            let tree = reweighted.dijkstra(source)?;
+            distances.extend(
+                (0..n).map(|to| tree.distance(to).map(|d| d - potential(source) + potential(to))),
+            );
+
+            // First hop towards each vertex: the hop towards its predecessor,
+            // or the vertex itself when the predecessor is the source
+            let mut first_hop: Vec<Option<usize>> = vec![None; n];
+            for target in 0..n {
+                let mut chain = Vec::new();
+                let mut node = target;
+                let hop = loop {
+                    if first_hop[node].is_some() {
+                        break first_hop[node];
+                    }
+                    match tree.predecessor(node) {
+                        Some(parent) if parent == source => break Some(node),
+                        Some(parent) => {
+                            chain.push(node);
+                            node = parent;
+                        }
+                        None => break None,
+                    }
+                };
+                first_hop[node] = hop;
+                for node in chain {
+                    first_hop[node] = hop;
+                }
+            }
+            next.extend(first_hop);
+        }
+        Ok(DistanceMatrix { node_count: n, distances, next })
+    }
+}
@@ -0,0 +1,197 @@
+//! A* Search with Pluggable Heuristics
+//!
+//! DEV NOTES:
+//! - Best-first search on `f = g + h`: `g` is the cost so far, `h` the
+//!   caller's estimate of the remaining cost
+//! - The heuristic must be admissible (never overestimate) for the returned
+//!   path to be optimal; with a zero heuristic A* is Dijkstra
+//! - Consistency is not required: a vertex reached again more cheaply after
+//!   being expanded is reopened (counted in `SearchStats::reopened`)
+//! - Ties on `f` are common on grids; `TieBreak` decides which entry goes
+//!   first, which changes how many vertices get expanded but not the cost
+//! - Works on any `WeightedGraphView`: `Graph<N, W>` or the implicit `GridGraph`
+//!
+//! PERFORMANCE CONSIDERATIONS:
+//! - Time: O((V + E) log V) with a consistent heuristic, usually far fewer
+//!   expansions than Dijkstra with an informed one
+//! - Space: O(V) bookkeeping plus the open list
+
+use super::shortest_path::Weight;
+use crate::data_structures::graph::GraphError;
+use crate::data_structures::graph_view::WeightedGraphView;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// Order among open entries with equal `f`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TieBreak {
+    /// Prefer the larger `g` (closer to the goal); usually the fewest expansions
+    #[default]
+    LargerCost,
+    /// Prefer the smaller `g`
+    SmallerCost,
+    /// Prefer the entry added first
+    Fifo,
+    /// Prefer the entry added last
+    Lifo,
+}
+
+/// Counters collected during a search
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SearchStats {
+    /// Vertices taken off the open list and expanded
+    pub expanded: usize,
+    /// Entries pushed onto the open list
+    pub generated: usize,
+    /// Expansions of vertices that had already been expanded
+    pub reopened: usize,
+}
+
+/// A path found by A*
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AStarPath<W> {
+    /// Total cost of the path
+    pub cost: W,
+    /// Vertices from start to goal inclusive
+    pub path: Vec<usize>,
+    /// Search statistics
+    pub stats: SearchStats,
+}
+
+/// A* searcher over a weighted graph
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::graph::astar::AStar;
+/// use dsa_in_rust::data_structures::grid_graph::{Connectivity, GridGraph};
+///
+/// // A wall with a gap at the bottom
+/// let mut grid = GridGraph::new(5, 5, Connectivity::Four);
+/// for y in 0..4 {
+///     grid.set_blocked(2, y);
+/// }
+/// let (start, goal) = (grid.node(0, 0).unwrap(), grid.node(4, 0).unwrap());
+/// let found = AStar::new(&grid).search(start, goal, grid.heuristic(goal)).unwrap();
+/// assert_eq!(found.cost, 12);
+/// assert_eq!(found.path.len(), 13);
+/// assert!(found.stats.expanded <= 25);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct AStar<'a, G> {
+    graph: &'a G,
+    tie_break: TieBreak,
+}
+
+impl<'a, G> AStar<'a, G>
+where
+    G: WeightedGraphView,
+    G::Weight: Weight,
+{
+    /// Creates a searcher for `graph` with the default tie-breaking
+    pub fn new(graph: &'a G) -> Self {
+        Self { graph, tie_break: TieBreak::default() }
+    }
+
+    /// Sets the order among open entries with equal `f`
+    pub fn tie_break(mut self, tie_break: TieBreak) -> Self {
+        self.tie_break = tie_break;
+        self
+    }
+
+    /// Finds a cheapest path from `start` to `goal`
+    ///
+    /// # Arguments
+    /// * `start` - Start vertex
+    /// * `goal` - Goal vertex
+    /// * `heuristic` - Admissible estimate of the cost from a vertex to `goal`
+    ///
+    /// # Returns
+    /// * `Result<AStarPath<G::Weight>, GraphError>` - Cost, path and statistics;
+    ///   Err(NoPath) if `goal` is unreachable, Err(InvalidNode) for bad vertices
+    ///
+    /// # Performance
+    /// * Time Complexity: O((V + E) log V) with a consistent heuristic
+    /// * Space Complexity: O(V)
+    pub fn search<H>(&self, start: usize, goal: usize, mut heuristic: H) -> Result<AStarPath<G::Weight>, GraphError>
+    where
+        H: FnMut(usize) -> G::Weight,
+    {
+        self.graph.check_node(start)?;
+        self.graph.check_node(goal)?;
+        let n = self.graph.node_count();
+        let mut best: Vec<Option<G::Weight>> = vec![None; n];
+        let mut parents = vec![None; n];
+        let mut expanded = vec![false; n];
+        let mut stats = SearchStats::default();
+        let mut open = BinaryHeap::new();
+
+        let push = |open: &mut BinaryHeap<Entry<G::Weight>>, stats: &mut SearchStats, node, cost, estimate| {
+            open.push(Entry { estimate, cost, sequence: stats.generated, node, tie_break: self.tie_break });
+            stats.generated += 1;
+        };
+        best[start] = Some(G::Weight::ZERO);
+        push(&mut open, &mut stats, start, G::Weight::ZERO, heuristic(start));
+
+        while let Some(Entry { cost, node, .. }) = open.pop() {
+            if best[node].is_some_and(|known| known < cost) {
+                continue;
+            }
+            stats.expanded += 1;
+            if std::mem::replace(&mut expanded[node], true) {
+                stats.reopened += 1;
+            }
+            if node == goal {
+                let mut path = vec![goal];
+                while let Some(parent) = parents[*path.last().expect("path is never empty")] {
+                    path.push(parent);
+                }
+                path.reverse();
+                return Ok(AStarPath { cost, path, stats });
+            }
+            for (next, weight) in self.graph.weighted_neighbors(node) {
+                let candidate = cost + weight;
+                if best[next].is_none_or(|known| candidate < known) {
+                    best[next] = Some(candidate);
+                    parents[next] = Some(node);
+                    push(&mut open, &mut stats, next, candidate, candidate + heuristic(next));
+                }
+            }
+        }
+        Err(GraphError::NoPath(start, goal))
+    }
+}
+
+/// Open list entry; the "greatest" entry is popped first
+struct Entry<W> {
+    estimate: W,
+    cost: W,
+    sequence: usize,
+    node: usize,
+    tie_break: TieBreak,
+}
+
+impl<W: Ord> Ord for Entry<W> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let tie = match self.tie_break {
+            TieBreak::LargerCost => self.cost.cmp(&other.cost),
+            TieBreak::SmallerCost => other.cost.cmp(&self.cost),
+            TieBreak::Fifo => other.sequence.cmp(&self.sequence),
+            TieBreak::Lifo => self.sequence.cmp(&other.sequence),
+        };
+        other.estimate.cmp(&self.estimate).then(tie).then_with(|| other.sequence.cmp(&self.sequence))
+    }
+}
+
+impl<W: Ord> PartialOrd for Entry<W> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<W: Ord> PartialEq for Entry<W> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<W: Ord> Eq for Entry<W> {}
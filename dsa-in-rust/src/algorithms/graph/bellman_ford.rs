@@ -0,0 +1,191 @@
+//! Shortest Paths with Negative Weights: Bellman–Ford and SPFA
+//!
+//! DEV NOTES:
+//! - Bellman–Ford relaxes every edge once per round; without a negative cycle
+//!   nothing changes after V - 1 rounds, and it stops early once a round is quiet
+//! - SPFA (the queue-based variant) only re-relaxes edges out of vertices
+//!   whose distance just improved, and counts the edges on each vertex's
+//!   current path: a path of V edges must repeat a vertex
+//! - A negative cycle is reported as `GraphError::NegativeCycle` with its
+//!   vertices in edge order, rotated to start at the smallest index, so
+//!   callers (e.g. arbitrage detection) get the cycle itself, not just a flag
+//! - Cycles are read off the predecessor graph: any cycle there has negative
+//!   weight. After a suspicious relaxation the predecessors are walked back
+//!   from the relaxed vertex; if that walk does not loop yet, relaxation
+//!   simply continues until one does
+//! - `negative_cycle` searches the whole graph by starting every vertex at
+//!   distance zero, as if from a virtual source joined to all of them
+//!
+//! PERFORMANCE CONSIDERATIONS:
+//! - Bellman–Ford: O(V * E) time, O(V) space
+//! - SPFA: O(V * E) worst case, typically close to O(E) on sparse graphs
+
+use super::shortest_path::{ShortestPaths, Weight};
+use crate::data_structures::graph::{Graph, GraphError};
+use crate::data_structures::graph_view::GraphView;
+use std::collections::VecDeque;
+
+impl<N, W: Weight> Graph<N, W> {
+    /// Computes shortest paths from `source` with the Bellman–Ford algorithm
+    ///
+    /// # Arguments
+    /// * `source` - Start vertex
+    ///
+    /// # Returns
+    /// * `Result<ShortestPaths<W>, GraphError>` - The shortest path tree;
+    ///   Err(NegativeCycle) if a negative cycle is reachable from `source`
+    ///
+    /// # Examples
+    /// ```
+    /// use dsa_in_rust::data_structures::graph::{Graph, GraphError};
+    ///
+    /// let mut graph: Graph<(), i32> = Graph::weighted(4);
+    /// graph.add_weighted_edge(0, 1, 4).unwrap();
+    /// graph.add_weighted_edge(0, 2, 5).unwrap();
+    /// graph.add_weighted_edge(2, 1, -3).unwrap();
+    /// let paths = graph.bellman_ford(0).unwrap();
+    /// assert_eq!(paths.distance(1), Some(2));
+    /// assert_eq!(paths.path_to(1).unwrap(), [0, 2, 1]);
+    ///
+    /// graph.add_weighted_edge(1, 3, 1).unwrap();
+    /// graph.add_weighted_edge(3, 2, -4).unwrap();
+    /// assert_eq!(graph.bellman_ford(0), Err(GraphError::NegativeCycle(vec![1, 3, 2])));
+    /// ```
+    ///
+    /// # Performance
+    /// * Time Complexity: O(V * E)
+    /// * Space Complexity: O(V)
+    pub fn bellman_ford(&self, source: usize) -> Result<ShortestPaths<W>, GraphError> {
+        self.check_node(source)?;
+        let mut distances = vec![None; self.node_count()];
+        distances[source] = Some(W::ZERO);
+        let predecessors = self.relax_rounds(&mut distances).map_err(GraphError::NegativeCycle)?;
+        Ok(ShortestPaths::new(source, distances, predecessors))
+    }
+
+    /// Computes shortest paths from `source` with the queue-based SPFA variant
+    ///
+    /// # Returns
+    /// * `Result<ShortestPaths<W>, GraphError>` - Same results as `bellman_ford`
+    ///
+    /// # Performance
+    /// * Time Complexity: O(V * E) worst case
+    /// * Space Complexity: O(V)
+    pub fn spfa(&self, source: usize) -> Result<ShortestPaths<W>, GraphError> {
+        self.check_node(source)?;
+        let n = self.node_count();
+        let mut distances: Vec<Option<W>> = vec![None; n];
+        let mut predecessors = vec![None; n];
+        // Edges on the path that produced each distance
+        let mut lengths = vec![0; n];
+        let mut queued = vec![false; n];
+        let mut queue = VecDeque::from([source]);
+        distances[source] = Some(W::ZERO);
+        queued[source] = true;
+
+        while let Some(node) = queue.pop_front() {
+            queued[node] = false;
+            let distance = distances[node].expect("queued vertices are reached");
+            for (next, &weight) in self.edges(node) {
+                let candidate = distance + weight;
+                if distances[next].is_some_and(|current| current <= candidate) {
+                    continue;
+                }
+                distances[next] = Some(candidate);
+                predecessors[next] = Some(node);
+                lengths[next] = lengths[node] + 1;
+                if lengths[next] >= n {
+                    if let Some(cycle) = predecessor_cycle(&predecessors, next) {
+                        return Err(GraphError::NegativeCycle(cycle));
+                    }
+                }
+                if !std::mem::replace(&mut queued[next], true) {
+                    queue.push_back(next);
+                }
+            }
+        }
+        Ok(ShortestPaths::new(source, distances, predecessors))
+    }
+
+    /// Finds a negative cycle anywhere in the graph
+    ///
+    /// # Returns
+    /// * `Option<Vec<usize>>` - Cycle vertices in edge order, starting at the
+    ///   smallest index; None if every cycle has non-negative weight
+    ///
+    /// # Examples
+    /// ```
+    /// use dsa_in_rust::data_structures::graph::Graph;
+    ///
+    /// // Not reachable from vertex 0, but still found
+    /// let mut graph: Graph<(), i64> = Graph::weighted(4);
+    /// graph.add_weighted_edge(0, 1, 1).unwrap();
+    /// graph.add_weighted_edge(2, 3, -2).unwrap();
+    /// graph.add_weighted_edge(3, 2, 1).unwrap();
+    /// assert_eq!(graph.negative_cycle(), Some(vec![2, 3]));
+    /// ```
+    ///
+    /// # Performance
+    /// * Time Complexity: O(V * E)
+    /// * Space Complexity: O(V)
+    pub fn negative_cycle(&self) -> Option<Vec<usize>> {
+        let mut distances = vec![Some(W::ZERO); self.node_count()];
+        self.relax_rounds(&mut distances).err()
+    }
+
+    /// Runs Bellman–Ford rounds from the given initial distances
+    ///
+    /// Returns the predecessors, or a negative cycle.
+    pub(crate) fn relax_rounds(&self, distances: &mut [Option<W>]) -> Result<Vec<Option<usize>>, Vec<usize>> {
+        let n = self.node_count();
+        let mut predecessors = vec![None; n];
+        for round in 1.. {
+            let mut changed = false;
+            for node in 0..n {
+                let Some(distance) = distances[node] else { continue };
+                for (next, &weight) in self.edges(node) {
+                    let candidate = distance + weight;
+                    if distances[next].is_some_and(|current| current <= candidate) {
+                        continue;
+                    }
+                    distances[next] = Some(candidate);
+                    predecessors[next] = Some(node);
+                    changed = true;
+                    // Improvements after V - 1 rounds can only come from a negative cycle
+                    if round >= n {
+                        if let Some(cycle) = predecessor_cycle(&predecessors, next) {
+                            return Err(cycle);
+                        }
+                    }
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+        Ok(predecessors)
+    }
+}
+
+/// Walks predecessors back from `start`; returns the cycle it runs into, if any
+///
+/// The cycle is returned in edge order and rotated to start at its smallest vertex.
+fn predecessor_cycle(predecessors: &[Option<usize>], start: usize) -> Option<Vec<usize>> {
+    let mut seen = vec![false; predecessors.len()];
+    let mut node = start;
+    while !seen[node] {
+        seen[node] = true;
+        node = predecessors[node]?;
+    }
+    // `node` lies on the cycle; collect it backwards, then restore edge order
+    let mut cycle = vec![node];
+    let mut current = predecessors[node].expect("cycle vertices have predecessors");
+    while current != node {
+        cycle.push(current);
+        current = predecessors[current].expect("cycle vertices have predecessors");
+    }
+    cycle.reverse();
+    let smallest = cycle.iter().enumerate().min_by_key(|&(_, &vertex)| vertex).map_or(0, |(i, _)| i);
+    cycle.rotate_left(smallest);
+    Some(cycle)
+}
@@ -0,0 +1,265 @@
+//! Strongly Connected Components and the Condensation DAG
+//!
+//! DEV NOTES:
+//! - Two vertices share a component when each can reach the other
+//! - Tarjan's algorithm finds all components in one DFS using discovery
+//!   indices and low-links; Kosaraju's runs a DFS for finishing order and a
+//!   second one over the reversed edges
+//! - Both DFS passes are iterative with explicit stacks, so long chains and
+//!   huge cycles cannot overflow the call stack
+//! - Component ids are numbered in a topological order of the condensation:
+//!   every edge between two components goes from the smaller id to the larger
+//! - Both algorithms produce the same partition, but when the condensation
+//!   has several topological orders they may number the components differently
+//! - `condensation` collapses each component to a single vertex and keeps one
+//!   edge per connected pair of components; the result is always a DAG
+//!
+//! PERFORMANCE CONSIDERATIONS:
+//! - Tarjan and Kosaraju: O(V + E) time; Kosaraju also stores the reversed graph
+//! - Condensation: O(V + E) time and space
+
+use crate::data_structures::graph::Graph;
+use crate::data_structures::graph_view::GraphView;
+
+/// Assignment of every vertex to a strongly connected component
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::data_structures::graph::Graph;
+///
+/// let mut graph = Graph::new(5);
+/// graph.add_edge(0, 1).unwrap();
+/// graph.add_edge(1, 2).unwrap();
+/// graph.add_edge(2, 0).unwrap();
+/// graph.add_edge(2, 3).unwrap();
+/// graph.add_edge(3, 4).unwrap();
+/// graph.add_edge(4, 3).unwrap();
+///
+/// let components = graph.tarjan_scc();
+/// assert_eq!(components.count(), 2);
+/// assert_eq!(components.ids(), [0, 0, 0, 1, 1]);
+/// assert_eq!(components.members(), [vec![0, 1, 2], vec![3, 4]]);
+/// assert_eq!(graph.kosaraju_scc(), components);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Components {
+    ids: Vec<usize>,
+    count: usize,
+}
+
+impl Components {
+    /// Returns the number of components
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the component id of `node`
+    ///
+    /// # Panics
+    /// Panics if `node` is not a vertex of the graph
+    pub fn component(&self, node: usize) -> usize {
+        self.ids[node]
+    }
+
+    /// Returns the component id of every vertex
+    pub fn ids(&self) -> &[usize] {
+        &self.ids
+    }
+
+    /// Returns true if `a` and `b` can reach each other
+    pub fn same_component(&self, a: usize, b: usize) -> bool {
+        self.ids[a] == self.ids[b]
+    }
+
+    /// Returns the vertices of each component, indexed by id, in increasing order
+    pub fn members(&self) -> Vec<Vec<usize>> {
+        let mut members = vec![Vec::new(); self.count];
+        for (node, &id) in self.ids.iter().enumerate() {
+            members[id].push(node);
+        }
+        members
+    }
+}
+
+impl<N, W> Graph<N, W> {
+    /// Finds strongly connected components with Tarjan's algorithm
+    ///
+    /// # Returns
+    /// * `Components` - Component id per vertex, numbered in topological order
+    ///
+    /// # Performance
+    /// * Time Complexity: O(V + E)
+    /// * Space Complexity: O(V)
+    pub fn tarjan_scc(&self) -> Components {
+        let n = self.node_count();
+        let mut discovery: Vec<Option<usize>> = vec![None; n];
+        let mut low_link = vec![0; n];
+        let mut on_stack = vec![false; n];
+        let mut component_stack = Vec::new();
+        // Components in the order Tarjan completes them: reverse topological
+        let mut finished = vec![usize::MAX; n];
+        let mut count = 0;
+        let mut next_index = 0;
+
+        for root in 0..n {
+            if discovery[root].is_some() {
+                continue;
+            }
+            let mut call_stack = vec![(root, self.neighbors(root))];
+            discovery[root] = Some(next_index);
+            low_link[root] = next_index;
+            next_index += 1;
+            component_stack.push(root);
+            on_stack[root] = true;
+
+            while let Some((node, neighbors)) = call_stack.last_mut() {
+                let node = *node;
+                if let Some(next) = neighbors.next() {
+                    match discovery[next] {
+                        None => {
+                            discovery[next] = Some(next_index);
+                            low_link[next] = next_index;
+                            next_index += 1;
+                            component_stack.push(next);
+                            on_stack[next] = true;
+                            call_stack.push((next, self.neighbors(next)));
+                        }
+                        Some(index) if on_stack[next] => low_link[node] = low_link[node].min(index),
+                        Some(_) => {}
+                    }
+                    continue;
+                }
+
+                // All edges of `node` are done: "return" to the parent
+                call_stack.pop();
+                if let Some(&(parent, _)) = call_stack.last() {
+                    low_link[parent] = low_link[parent].min(low_link[node]);
+                }
+                if Some(low_link[node]) == discovery[node] {
+                    loop {
+                        let member = component_stack.pop().expect("the root is still on the stack");
+                        on_stack[member] = false;
+                        finished[member] = count;
+                        if member == node {
+                            break;
+                        }
+                    }
+                    count += 1;
+                }
+            }
+        }
+
+        let ids = finished.into_iter().map(|order| count - 1 - order).collect();
+        Components { ids, count }
+    }
+
+    /// Finds strongly connected components with Kosaraju's algorithm
+    ///
+    /// # Returns
+    /// * `Components` - Component id per vertex, numbered in topological order
+    ///
+    /// # Performance
+    /// * Time Complexity: O(V + E)
+    /// * Space Complexity: O(V + E) for the reversed edges
+    pub fn kosaraju_scc(&self) -> Components {
+        let n = self.node_count();
+
+        // Pass 1: vertices by increasing DFS finishing time
+        let mut visited = vec![false; n];
+        let mut finish_order = Vec::with_capacity(n);
+        for root in 0..n {
+            if std::mem::replace(&mut visited[root], true) {
+                continue;
+            }
+            let mut stack = vec![(root, self.neighbors(root))];
+            while let Some((node, neighbors)) = stack.last_mut() {
+                let node = *node;
+                match neighbors.find(|&next| !visited[next]) {
+                    Some(next) => {
+                        visited[next] = true;
+                        stack.push((next, self.neighbors(next)));
+                    }
+                    None => {
+                        finish_order.push(node);
+                        stack.pop();
+                    }
+                }
+            }
+        }
+
+        // Pass 2: on the reversed graph, each search from the latest finisher is one component
+        let mut reversed = vec![Vec::new(); n];
+        for node in 0..n {
+            for next in self.neighbors(node) {
+                reversed[next].push(node);
+            }
+        }
+        let mut ids = vec![usize::MAX; n];
+        let mut count = 0;
+        for &root in finish_order.iter().rev() {
+            if ids[root] != usize::MAX {
+                continue;
+            }
+            ids[root] = count;
+            let mut stack = vec![root];
+            while let Some(node) = stack.pop() {
+                for &previous in &reversed[node] {
+                    if ids[previous] == usize::MAX {
+                        ids[previous] = count;
+                        stack.push(previous);
+                    }
+                }
+            }
+            count += 1;
+        }
+        Components { ids, count }
+    }
+
+    /// Collapses every strongly connected component into a single vertex
+    ///
+    /// # Returns
+    /// * `(Graph, Components)` - The condensation, whose vertex `i` is component
+    ///   `i`, with one edge per pair of components joined by at least one edge;
+    ///   and the components it was built from
+    ///
+    /// # Examples
+    /// ```
+    /// use dsa_in_rust::data_structures::graph::Graph;
+    ///
+    /// let mut graph = Graph::new(4);
+    /// graph.add_edge(0, 1).unwrap();
+    /// graph.add_edge(1, 0).unwrap();
+    /// graph.add_edge(0, 2).unwrap();
+    /// graph.add_edge(1, 2).unwrap();
+    /// graph.add_edge(2, 3).unwrap();
+    /// graph.add_edge(3, 2).unwrap();
+    ///
+    /// let (dag, components) = graph.condensation();
+    /// assert_eq!(components.members(), [vec![0, 1], vec![2, 3]]);
+    /// assert_eq!(dag.node_count(), 2);
+    /// assert_eq!(dag.edge_count(), 1);
+    /// assert!(dag.has_edge(0, 1));
+    /// ```
+    ///
+    /// # Performance
+    /// * Time Complexity: O(V + E), plus `add_edge`'s duplicate check per condensation edge
+    /// * Space Complexity: O(V + E)
+    pub fn condensation(&self) -> (Graph, Components) {
+        let components = self.tarjan_scc();
+        let mut dag = Graph::new(components.count());
+        // `linked[target] == source` once the edge source -> target has been added
+        let mut linked = vec![usize::MAX; components.count()];
+        for (source, members) in components.members().into_iter().enumerate() {
+            for node in members {
+                for next in self.neighbors(node) {
+                    let target = components.component(next);
+                    if target != source && linked[target] != source {
+                        linked[target] = source;
+                        dag.add_edge(source, target).expect("each component pair is linked once");
+                    }
+                }
+            }
+        }
+        (dag, components)
+    }
+}
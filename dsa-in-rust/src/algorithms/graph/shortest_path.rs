@@ -0,0 +1,202 @@
+//! Single-Source Shortest Paths: Dijkstra's Algorithm
+//!
+//! DEV NOTES:
+//! - Edge weights implement `Weight`: a totally ordered, copyable type with a
+//!   zero, addition and subtraction (all primitive integers); floats are not
+//!   `Ord`, so scale them to integers (e.g. milliseconds instead of seconds)
+//! - Dijkstra needs non-negative weights and checks this up front, returning
+//!   `GraphError::NegativeWeight` for the first negative edge
+//! - Binary heap with lazy deletion: a vertex may be pushed several times and
+//!   stale entries are skipped when popped
+//! - Results are a `ShortestPaths` tree: distances plus the predecessor of
+//!   every reached vertex, from which any path is rebuilt in O(length)
+//! - Distances are summed with `+`, so pick a weight type wide enough for
+//!   the longest path
+//!
+//! PERFORMANCE CONSIDERATIONS:
+//! - Time: O((V + E) log V)
+//! - Space: O(V + E) for the heap in the worst case
+
+use crate::data_structures::graph::{Graph, GraphError};
+use crate::data_structures::graph_view::GraphView;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::ops::{Add, Sub};
+
+/// Edge weight usable by shortest path algorithms
+pub trait Weight: Copy + Ord + Add<Output = Self> + Sub<Output = Self> {
+    /// Additive identity: the length of the empty path
+    const ZERO: Self;
+}
+
+macro_rules! impl_weight {
+    ($($t:ty),*) => {
+        $(impl Weight for $t {
+            const ZERO: Self = 0;
+        })*
+    };
+}
+
+impl_weight!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Shortest path tree rooted at a source vertex
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::data_structures::graph::Graph;
+///
+/// let mut graph: Graph<(), u32> = Graph::weighted(4);
+/// graph.add_weighted_edge(0, 1, 4).unwrap();
+/// graph.add_weighted_edge(0, 2, 1).unwrap();
+/// graph.add_weighted_edge(2, 1, 2).unwrap();
+/// graph.add_weighted_edge(1, 3, 5).unwrap();
+///
+/// let paths = graph.dijkstra(0).unwrap();
+/// assert_eq!(paths.distance(3), Some(8));
+/// assert_eq!(paths.path_to(3).unwrap(), [0, 2, 1, 3]);
+/// assert_eq!(paths.predecessor(1), Some(2));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShortestPaths<W> {
+    source: usize,
+    distances: Vec<Option<W>>,
+    predecessors: Vec<Option<usize>>,
+}
+
+impl<W: Copy> ShortestPaths<W> {
+    pub(crate) fn new(source: usize, distances: Vec<Option<W>>, predecessors: Vec<Option<usize>>) -> Self {
+        Self { source, distances, predecessors }
+    }
+
+    /// Returns the source vertex
+    pub fn source(&self) -> usize {
+        self.source
+    }
+
+    /// Returns the distance from the source, None if `node` is unreachable or out of range
+    pub fn distance(&self, node: usize) -> Option<W> {
+        self.distances.get(node).copied().flatten()
+    }
+
+    /// Returns all distances, indexed by vertex
+    pub fn distances(&self) -> &[Option<W>] {
+        &self.distances
+    }
+
+    /// Returns the vertex before `node` on its shortest path; None for the
+    /// source and for unreachable vertices
+    pub fn predecessor(&self, node: usize) -> Option<usize> {
+        self.predecessors.get(node).copied().flatten()
+    }
+
+    /// Returns all predecessors, indexed by vertex
+    pub fn predecessors(&self) -> &[Option<usize>] {
+        &self.predecessors
+    }
+
+    /// Rebuilds the shortest path from the source to `target`
+    ///
+    /// # Returns
+    /// * `Result<Vec<usize>, GraphError>` - Vertices from source to target inclusive;
+    ///   Err(NoPath) if `target` is unreachable, Err(InvalidNode) if out of range
+    ///
+    /// # Performance
+    /// * Time Complexity: O(path length)
+    pub fn path_to(&self, target: usize) -> Result<Vec<usize>, GraphError> {
+        if target >= self.distances.len() {
+            return Err(GraphError::InvalidNode(target));
+        }
+        if self.distances[target].is_none() {
+            return Err(GraphError::NoPath(self.source, target));
+        }
+        let mut path = vec![target];
+        let mut node = target;
+        while let Some(previous) = self.predecessors[node] {
+            path.push(previous);
+            node = previous;
+        }
+        path.reverse();
+        Ok(path)
+    }
+}
+
+impl<N, W: Weight> Graph<N, W> {
+    /// Computes shortest paths from `source` to every vertex with Dijkstra's algorithm
+    ///
+    /// # Arguments
+    /// * `source` - Start vertex
+    ///
+    /// # Returns
+    /// * `Result<ShortestPaths<W>, GraphError>` - The shortest path tree;
+    ///   Err(InvalidNode) for a bad source, Err(NegativeWeight) if any edge is negative
+    ///
+    /// # Performance
+    /// * Time Complexity: O((V + E) log V)
+    /// * Space Complexity: O(V + E)
+    pub fn dijkstra(&self, source: usize) -> Result<ShortestPaths<W>, GraphError> {
+        self.check_node(source)?;
+        self.check_non_negative()?;
+        Ok(self.dijkstra_until(source, None))
+    }
+
+    /// Finds a shortest path between two vertices, stopping as soon as `target` is settled
+    ///
+    /// # Returns
+    /// * `Result<(W, Vec<usize>), GraphError>` - Path length and vertices;
+    ///   Err(NoPath) if `target` is unreachable
+    ///
+    /// # Examples
+    /// ```
+    /// use dsa_in_rust::data_structures::graph::{Graph, GraphError};
+    ///
+    /// let mut graph: Graph<(), u64> = Graph::weighted(3);
+    /// graph.add_weighted_edge(0, 1, 10).unwrap();
+    /// assert_eq!(graph.shortest_path(0, 1), Ok((10, vec![0, 1])));
+    /// assert_eq!(graph.shortest_path(1, 2), Err(GraphError::NoPath(1, 2)));
+    /// ```
+    pub fn shortest_path(&self, source: usize, target: usize) -> Result<(W, Vec<usize>), GraphError> {
+        self.check_node(source)?;
+        self.check_node(target)?;
+        self.check_non_negative()?;
+        let paths = self.dijkstra_until(source, Some(target));
+        let path = paths.path_to(target)?;
+        Ok((paths.distance(target).expect("target was reached"), path))
+    }
+
+    pub(crate) fn check_non_negative(&self) -> Result<(), GraphError> {
+        for node in 0..self.node_count() {
+            if let Some((next, _)) = self.edges(node).find(|&(_, &weight)| weight < W::ZERO) {
+                return Err(GraphError::NegativeWeight(node, next));
+            }
+        }
+        Ok(())
+    }
+
+    fn dijkstra_until(&self, source: usize, target: Option<usize>) -> ShortestPaths<W> {
+        let n = self.node_count();
+        let mut distances: Vec<Option<W>> = vec![None; n];
+        let mut predecessors = vec![None; n];
+        let mut settled = vec![false; n];
+        let mut heap = BinaryHeap::new();
+        distances[source] = Some(W::ZERO);
+        heap.push(Reverse((W::ZERO, source)));
+
+        while let Some(Reverse((distance, node))) = heap.pop() {
+            if std::mem::replace(&mut settled[node], true) {
+                continue;
+            }
+            if target == Some(node) {
+                break;
+            }
+            for (next, &weight) in self.edges(node) {
+                let candidate = distance + weight;
+                if !settled[next] && distances[next].is_none_or(|current| candidate < current) {
+                    distances[next] = Some(candidate);
+                    predecessors[next] = Some(node);
+                    heap.push(Reverse((candidate, next)));
+                }
+            }
+        }
+        ShortestPaths::new(source, distances, predecessors)
+    }
+}
@@ -0,0 +1,191 @@
+//! Minimum Spanning Trees and Forests: Kruskal and Prim
+//!
+//! DEV NOTES:
+//! - Only defined for undirected graphs; directed graphs are rejected with
+//!   `GraphError::DirectedGraph` (their analogue, arborescences, is different)
+//! - Kruskal sorts all edges by weight and keeps each edge that joins two
+//!   different trees, tracked with `UnionFind`
+//! - Prim grows a single tree from vertex 0, always adding the cheapest edge
+//!   leaving it (lazy deletion from a binary heap)
+//! - `kruskal` and `prim` require a connected graph and return
+//!   `GraphError::NoPath(0, v)` naming the first vertex not reachable from 0;
+//!   `minimum_spanning_forest` instead returns one tree per connected component
+//! - Negative weights are fine; self-loops are never part of a tree
+//! - With equal weights several minimum trees may exist; all have the same
+//!   total weight, but Kruskal and Prim may pick different edges
+//!
+//! PERFORMANCE CONSIDERATIONS:
+//! - Kruskal: O(E log E) time, O(V + E) space
+//! - Prim: O(E log V) time, O(V + E) space for the heap
+
+use super::shortest_path::Weight;
+use crate::data_structures::graph::{Graph, GraphError};
+use crate::data_structures::union_find::UnionFind;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Edges of a minimum spanning tree or forest
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::data_structures::graph::{Graph, GraphKind};
+///
+/// let mut graph: Graph<(), u32> = Graph::with_kind(5, GraphKind::Undirected);
+/// graph.add_weighted_edge(0, 1, 4).unwrap();
+/// graph.add_weighted_edge(1, 2, 2).unwrap();
+/// graph.add_weighted_edge(0, 2, 1).unwrap();
+/// graph.add_weighted_edge(3, 4, 7).unwrap();
+///
+/// let forest = graph.minimum_spanning_forest().unwrap();
+/// assert_eq!(forest.edges(), [(0, 2, 1), (1, 2, 2), (3, 4, 7)]);
+/// assert_eq!(forest.total_weight(), 10);
+/// assert_eq!(forest.tree_count(), 2);
+/// assert!(graph.kruskal().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpanningForest<W> {
+    edges: Vec<(usize, usize, W)>,
+    total_weight: W,
+    tree_count: usize,
+}
+
+impl<W: Copy> SpanningForest<W> {
+    /// Returns the chosen edges as `(u, v, weight)`
+    pub fn edges(&self) -> &[(usize, usize, W)] {
+        &self.edges
+    }
+
+    /// Returns the sum of the chosen edge weights
+    pub fn total_weight(&self) -> W {
+        self.total_weight
+    }
+
+    /// Returns the number of trees, one per connected component
+    pub fn tree_count(&self) -> usize {
+        self.tree_count
+    }
+
+    /// Returns true if the forest is a single tree spanning every vertex
+    pub fn is_tree(&self) -> bool {
+        self.tree_count <= 1
+    }
+}
+
+impl<N, W: Weight> Graph<N, W> {
+    /// Computes a minimum spanning tree with Kruskal's algorithm
+    ///
+    /// # Returns
+    /// * `Result<SpanningForest<W>, GraphError>` - Tree edges in the order they
+    ///   were chosen (by increasing weight); Err(NoPath) if the graph is
+    ///   disconnected, Err(DirectedGraph) for directed graphs
+    ///
+    /// # Performance
+    /// * Time Complexity: O(E log E)
+    /// * Space Complexity: O(V + E)
+    pub fn kruskal(&self) -> Result<SpanningForest<W>, GraphError> {
+        let (forest, mut sets) = self.kruskal_forest()?;
+        if let Some(missing) = (1..self.node_count()).find(|&node| !sets.connected(0, node)) {
+            return Err(GraphError::NoPath(0, missing));
+        }
+        Ok(forest)
+    }
+
+    /// Computes a minimum spanning forest: a minimum spanning tree of every
+    /// connected component
+    ///
+    /// # Returns
+    /// * `Result<SpanningForest<W>, GraphError>` - Forest edges chosen by
+    ///   Kruskal's algorithm; Err(DirectedGraph) for directed graphs
+    ///
+    /// # Performance
+    /// * Time Complexity: O(E log E)
+    /// * Space Complexity: O(V + E)
+    pub fn minimum_spanning_forest(&self) -> Result<SpanningForest<W>, GraphError> {
+        self.kruskal_forest().map(|(forest, _)| forest)
+    }
+
+    /// Computes a minimum spanning tree with Prim's algorithm, grown from vertex 0
+    ///
+    /// # Returns
+    /// * `Result<SpanningForest<W>, GraphError>` - Tree edges as
+    ///   `(parent, child, weight)` in the order the children joined;
+    ///   Err(NoPath) if the graph is disconnected, Err(DirectedGraph) for
+    ///   directed graphs
+    ///
+    /// # Examples
+    /// ```
+    /// use dsa_in_rust::data_structures::graph::{Graph, GraphKind};
+    ///
+    /// let mut graph: Graph<(), i32> = Graph::with_kind(4, GraphKind::Undirected);
+    /// graph.add_weighted_edge(0, 1, 3).unwrap();
+    /// graph.add_weighted_edge(1, 2, -1).unwrap();
+    /// graph.add_weighted_edge(2, 3, 2).unwrap();
+    /// graph.add_weighted_edge(3, 0, 4).unwrap();
+    ///
+    /// let tree = graph.prim().unwrap();
+    /// assert_eq!(tree.edges(), [(0, 1, 3), (1, 2, -1), (2, 3, 2)]);
+    /// assert_eq!(tree.total_weight(), graph.kruskal().unwrap().total_weight());
+    /// ```
+    ///
+    /// # Performance
+    /// * Time Complexity: O(E log V)
+    /// * Space Complexity: O(V + E)
+    pub fn prim(&self) -> Result<SpanningForest<W>, GraphError> {
+        self.check_undirected()?;
+        let n = self.node_count();
+        let mut in_tree = vec![false; n];
+        let mut edges = Vec::with_capacity(n.saturating_sub(1));
+        let mut total_weight = W::ZERO;
+        let mut frontier = BinaryHeap::new();
+        if n > 0 {
+            in_tree[0] = true;
+            frontier.extend(self.edges(0).map(|(next, &weight)| Reverse((weight, next, 0))));
+        }
+
+        while let Some(Reverse((weight, node, parent))) = frontier.pop() {
+            if std::mem::replace(&mut in_tree[node], true) {
+                continue;
+            }
+            edges.push((parent, node, weight));
+            total_weight = total_weight + weight;
+            frontier.extend(
+                self.edges(node)
+                    .filter(|&(next, _)| !in_tree[next])
+                    .map(|(next, &weight)| Reverse((weight, next, node))),
+            );
+        }
+
+        if let Some(missing) = in_tree.iter().position(|&reached| !reached) {
+            return Err(GraphError::NoPath(0, missing));
+        }
+        Ok(SpanningForest { edges, total_weight, tree_count: n.min(1) })
+    }
+
+    /// Kruskal's algorithm over all components; also returns the final sets
+    fn kruskal_forest(&self) -> Result<(SpanningForest<W>, UnionFind), GraphError> {
+        self.check_undirected()?;
+        let mut candidates: Vec<(usize, usize, W)> = self.all_edges().map(|(u, v, &weight)| (u, v, weight)).collect();
+        // Stable, so equal weights keep insertion order
+        candidates.sort_by_key(|&(_, _, weight)| weight);
+
+        let mut sets = UnionFind::new(self.node_count());
+        let mut edges = Vec::with_capacity(self.node_count().saturating_sub(1));
+        let mut total_weight = W::ZERO;
+        for (u, v, weight) in candidates {
+            if sets.union(u, v) {
+                edges.push((u, v, weight));
+                total_weight = total_weight + weight;
+            }
+        }
+        let tree_count = sets.set_count();
+        Ok((SpanningForest { edges, total_weight, tree_count }, sets))
+    }
+
+    fn check_undirected(&self) -> Result<(), GraphError> {
+        if self.is_directed() {
+            Err(GraphError::DirectedGraph)
+        } else {
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,184 @@
+//! Topological Sorting with Cycle Reporting
+//!
+//! DEV NOTES:
+//! - Kahn's algorithm repeatedly removes a vertex with no remaining incoming
+//!   edges; ready vertices leave in FIFO order, smallest index first
+//! - The lexicographic variant always removes the smallest ready vertex (a
+//!   min-heap instead of a queue), giving the lexicographically smallest order
+//! - The DFS variant returns vertices in reverse post-order; it is iterative
+//!   with an explicit stack, so deep dependency chains cannot overflow
+//! - When the graph is not a DAG all variants return `GraphError::Cycle` with
+//!   one concrete cycle, in edge order and starting at its smallest vertex;
+//!   for Kahn the cycle is searched among the vertices that never became ready
+//! - Edge `u -> v` means "u before v" (e.g. "u is a dependency of v")
+//!
+//! PERFORMANCE CONSIDERATIONS:
+//! - Kahn and DFS: O(V + E) time, O(V) space
+//! - Lexicographic: O((V + E) log V) time
+
+use crate::data_structures::graph::{Graph, GraphError};
+use crate::data_structures::graph_view::GraphView;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+impl<N, W> Graph<N, W> {
+    /// Orders the vertices so every edge points forward, using Kahn's algorithm
+    ///
+    /// # Returns
+    /// * `Result<Vec<usize>, GraphError>` - A topological order;
+    ///   Err(Cycle) with a concrete cycle if the graph is not a DAG
+    ///
+    /// # Examples
+    /// ```
+    /// use dsa_in_rust::data_structures::graph::{Graph, GraphError};
+    ///
+    /// // 0: core, 1: utils, 2: app, 3: tests
+    /// let mut build = Graph::new(4);
+    /// build.add_edge(0, 1).unwrap();
+    /// build.add_edge(1, 2).unwrap();
+    /// build.add_edge(0, 2).unwrap();
+    /// build.add_edge(2, 3).unwrap();
+    /// assert_eq!(build.topological_sort().unwrap(), [0, 1, 2, 3]);
+    ///
+    /// build.add_edge(3, 1).unwrap();
+    /// assert_eq!(build.topological_sort(), Err(GraphError::Cycle(vec![1, 2, 3])));
+    /// ```
+    ///
+    /// # Performance
+    /// * Time Complexity: O(V + E)
+    /// * Space Complexity: O(V)
+    pub fn topological_sort(&self) -> Result<Vec<usize>, GraphError> {
+        self.kahn(VecDeque::new(), VecDeque::push_back, VecDeque::pop_front)
+    }
+
+    /// Returns the lexicographically smallest topological order
+    ///
+    /// # Returns
+    /// * `Result<Vec<usize>, GraphError>` - Among all valid orders, the one
+    ///   that is smallest when compared element by element; Err(Cycle) if not a DAG
+    ///
+    /// # Examples
+    /// ```
+    /// use dsa_in_rust::data_structures::graph::Graph;
+    ///
+    /// let mut graph = Graph::new(4);
+    /// graph.add_edge(3, 0).unwrap();
+    /// graph.add_edge(2, 1).unwrap();
+    /// assert_eq!(graph.topological_sort().unwrap(), [2, 3, 1, 0]);
+    /// assert_eq!(graph.topological_sort_lexicographic().unwrap(), [2, 1, 3, 0]);
+    /// ```
+    ///
+    /// # Performance
+    /// * Time Complexity: O((V + E) log V)
+    /// * Space Complexity: O(V)
+    pub fn topological_sort_lexicographic(&self) -> Result<Vec<usize>, GraphError> {
+        self.kahn(
+            BinaryHeap::new(),
+            |heap: &mut BinaryHeap<Reverse<usize>>, node| heap.push(Reverse(node)),
+            |heap| heap.pop().map(|Reverse(node)| node),
+        )
+    }
+
+    /// Orders the vertices by reverse DFS post-order
+    ///
+    /// # Returns
+    /// * `Result<Vec<usize>, GraphError>` - A topological order;
+    ///   Err(Cycle) with a concrete cycle if the graph is not a DAG
+    ///
+    /// # Performance
+    /// * Time Complexity: O(V + E)
+    /// * Space Complexity: O(V)
+    pub fn topological_sort_dfs(&self) -> Result<Vec<usize>, GraphError> {
+        let mut order = self.post_order(None).map_err(GraphError::Cycle)?;
+        order.reverse();
+        Ok(order)
+    }
+
+    /// Kahn's algorithm over any container of ready vertices
+    fn kahn<C>(
+        &self,
+        mut ready: C,
+        push: impl Fn(&mut C, usize),
+        pop: impl Fn(&mut C) -> Option<usize>,
+    ) -> Result<Vec<usize>, GraphError> {
+        let n = self.node_count();
+        let mut in_degree = vec![0usize; n];
+        for node in 0..n {
+            for next in self.neighbors(node) {
+                in_degree[next] += 1;
+            }
+        }
+        for node in (0..n).filter(|&node| in_degree[node] == 0) {
+            push(&mut ready, node);
+        }
+
+        let mut order = Vec::with_capacity(n);
+        while let Some(node) = pop(&mut ready) {
+            order.push(node);
+            for next in self.neighbors(node) {
+                in_degree[next] -= 1;
+                if in_degree[next] == 0 {
+                    push(&mut ready, next);
+                }
+            }
+        }
+        if order.len() == n {
+            return Ok(order);
+        }
+
+        // Every vertex left over has a left-over predecessor, so they contain a cycle
+        let blocked: Vec<bool> = in_degree.iter().map(|&degree| degree > 0).collect();
+        match self.post_order(Some(&blocked)) {
+            Err(cycle) => Err(GraphError::Cycle(cycle)),
+            Ok(_) => unreachable!("vertices that never became ready lie on or after a cycle"),
+        }
+    }
+
+    /// Iterative DFS post-order over all vertices (or only those marked in `within`)
+    ///
+    /// Returns the first cycle found instead if a back edge is met.
+    fn post_order(&self, within: Option<&[bool]>) -> Result<Vec<usize>, Vec<usize>> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum Mark {
+            New,
+            Active,
+            Done,
+        }
+
+        let n = self.node_count();
+        let included = |node: usize| within.is_none_or(|within| within[node]);
+        let mut marks = vec![Mark::New; n];
+        let mut order = Vec::with_capacity(n);
+        for root in (0..n).filter(|&root| included(root)) {
+            if marks[root] != Mark::New {
+                continue;
+            }
+            marks[root] = Mark::Active;
+            let mut stack = vec![(root, self.neighbors(root))];
+            while let Some((node, neighbors)) = stack.last_mut() {
+                let node = *node;
+                match neighbors.find(|&next| included(next) && marks[next] != Mark::Done) {
+                    Some(next) if marks[next] == Mark::Active => {
+                        // Active vertices are exactly the stack, so the cycle is its tail from `next`
+                        let path: Vec<usize> = stack.iter().map(|&(on_path, _)| on_path).collect();
+                        let start = path.iter().position(|&on_path| on_path == next).expect("active vertex on stack");
+                        let mut cycle = path[start..].to_vec();
+                        let smallest = (0..cycle.len()).min_by_key(|&i| cycle[i]).unwrap_or(0);
+                        cycle.rotate_left(smallest);
+                        return Err(cycle);
+                    }
+                    Some(next) => {
+                        marks[next] = Mark::Active;
+                        stack.push((next, self.neighbors(next)));
+                    }
+                    None => {
+                        marks[node] = Mark::Done;
+                        order.push(node);
+                        stack.pop();
+                    }
+                }
+            }
+        }
+        Ok(order)
+    }
+}
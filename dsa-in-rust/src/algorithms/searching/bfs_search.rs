@@ -0,0 +1,54 @@
+//! Breadth First Search Implementation
+//!
+//! DEV NOTES:
+//! - Searches the same implicit binary tree layout as `dfs_search`
+//! - Level order of that layout is index order, so BFS needs no queue
+//! - Time complexity: O(n) where n is number of elements
+//! - Space complexity: O(1)
+
+use super::tree_traversal::level_order;
+
+/// Performs breadth-first search on array to find target element
+///
+/// # Arguments
+/// * `arr` - Slice encoding a binary tree level by level
+/// * `target` - Value to find
+///
+/// # Returns
+/// * `Option<usize>` - Index of the shallowest match, None otherwise
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::bfs_search::breadth_first_search;
+///
+/// let arr = vec!["root", "left", "right", "leaf"];
+/// assert_eq!(breadth_first_search(&arr, &"right"), Some(2));
+/// assert_eq!(breadth_first_search(&arr, &"missing"), None);
+/// ```
+pub fn breadth_first_search<T: PartialEq>(arr: &[T], target: &T) -> Option<usize> {
+    breadth_first_search_by(arr, |x| x == target)
+}
+
+/// Performs breadth-first search on array for the first element matching a predicate
+///
+/// # Arguments
+/// * `arr` - Slice encoding a binary tree level by level
+/// * `predicate` - Returns true for the element being searched for
+///
+/// # Returns
+/// * `Option<usize>` - Index of the shallowest match, None otherwise
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::bfs_search::breadth_first_search_by;
+///
+/// let arr = [10, 4, 7, 3, 8];
+/// // Level order visits 10, 4, 7, 3, 8: the shallower 7 wins over 8
+/// assert_eq!(breadth_first_search_by(&arr, |&x| x > 5 && x < 10), Some(2));
+/// ```
+pub fn breadth_first_search_by<T, P>(arr: &[T], mut predicate: P) -> Option<usize>
+where
+    P: FnMut(&T) -> bool,
+{
+    level_order(arr).find(|&(_, x)| predicate(x)).map(|(i, _)| i)
+}
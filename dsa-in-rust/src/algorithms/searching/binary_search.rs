@@ -0,0 +1,401 @@
+//! Binary Search Family Implementation
+//!
+//! DEV NOTES:
+//! - Every search is built on one primitive, `partition_by`, which finds the
+//!   first index whose element is not `Less` than the target
+//! - Matches always report the FIRST equal element, unlike `slice::binary_search`
+//!   which may return any of them
+//! - Misses return `Err(i)` where `i` is the insertion point that keeps the
+//!   sequence sorted, exactly as `slice::binary_search` does
+//! - Exponential (galloping) search needs only element access by index, so it
+//!   also works on unbounded sequences and lazily buffered iterators
+//! - `partition_point` searches a value domain instead of a slice ("binary
+//!   search on the answer"): any integer or float range with a monotone predicate
+//!
+//! PERFORMANCE CONSIDERATIONS:
+//! - Bounded searches: O(log n) comparisons, O(1) space
+//! - Exponential search: O(log i) comparisons where i is the result position
+//! - `partition_point`: O(log((end - start) / precision)) predicate calls
+
+use std::cmp::Ordering;
+use std::ops::Range;
+
+/// Value domain that `partition_point` can bisect
+///
+/// Implemented for all primitive integers and floats.
+pub trait Bisect: Copy + PartialOrd {
+    /// Returns a point in `[low, high)` splitting the interval, or None once
+    /// `high - low` is within `precision` (or the interval cannot be split)
+    fn split(low: Self, high: Self, precision: Self) -> Option<Self>;
+
+    /// Returns the lowest value still unresolved once `mid` satisfies the predicate
+    ///
+    /// `mid + 1` for integers; `mid` itself for floats.
+    fn after(mid: Self) -> Self;
+}
+
+macro_rules! impl_bisect_int {
+    ($($t:ty => $u:ty),*) => {$(
+        impl Bisect for $t {
+            fn split(low: Self, high: Self, precision: Self) -> Option<Self> {
+                // The true difference always fits the unsigned type of the same width
+                let width = high.wrapping_sub(low) as $u;
+                (low < high && width >= (precision as $u).max(1)).then(|| low.wrapping_add((width / 2) as $t))
+            }
+
+            fn after(mid: Self) -> Self {
+                mid + 1
+            }
+        }
+    )*};
+}
+
+impl_bisect_int!(
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize,
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize
+);
+
+macro_rules! impl_bisect_float {
+    ($($t:ty),*) => {$(
+        impl Bisect for $t {
+            fn split(low: Self, high: Self, precision: Self) -> Option<Self> {
+                let mid = low + (high - low) / 2.0;
+                (high - low > precision && low < mid && mid < high).then_some(mid)
+            }
+
+            fn after(mid: Self) -> Self {
+                mid
+            }
+        }
+    )*};
+}
+
+impl_bisect_float!(f32, f64);
+
+/// Returns the first index `i` such that `compare(&arr[i]) != Ordering::Less`
+fn partition_by<T, F>(arr: &[T], mut compare: F) -> usize
+where
+    F: FnMut(&T) -> Ordering,
+{
+    let mut low = 0;
+    let mut high = arr.len();
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if compare(&arr[mid]) == Ordering::Less {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+/// Finds the first position where `target` could be inserted without breaking order
+///
+/// # Arguments
+/// * `arr` - Sorted slice to search
+/// * `target` - Value to locate
+///
+/// # Returns
+/// * `usize` - Index of the first element `>= target`, or `arr.len()`
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::binary_search::lower_bound;
+///
+/// let arr = [1, 2, 2, 2, 5];
+/// assert_eq!(lower_bound(&arr, &2), 1);
+/// assert_eq!(lower_bound(&arr, &3), 4);
+/// assert_eq!(lower_bound(&arr, &9), 5);
+/// ```
+pub fn lower_bound<T: Ord>(arr: &[T], target: &T) -> usize {
+    partition_by(arr, |x| x.cmp(target))
+}
+
+/// Finds the last position where `target` could be inserted without breaking order
+///
+/// # Arguments
+/// * `arr` - Sorted slice to search
+/// * `target` - Value to locate
+///
+/// # Returns
+/// * `usize` - Index of the first element `> target`, or `arr.len()`
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::binary_search::upper_bound;
+///
+/// let arr = [1, 2, 2, 2, 5];
+/// assert_eq!(upper_bound(&arr, &2), 4);
+/// assert_eq!(upper_bound(&arr, &0), 0);
+/// ```
+pub fn upper_bound<T: Ord>(arr: &[T], target: &T) -> usize {
+    partition_by(arr, |x| match x.cmp(target) {
+        Ordering::Greater => Ordering::Greater,
+        _ => Ordering::Less,
+    })
+}
+
+/// Returns the range of elements equal to `target`
+///
+/// # Arguments
+/// * `arr` - Sorted slice to search
+/// * `target` - Value to locate
+///
+/// # Returns
+/// * `Range<usize>` - `lower_bound..upper_bound`; empty (at the insertion point) if absent
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::binary_search::equal_range;
+///
+/// let arr = [1, 2, 2, 2, 5];
+/// assert_eq!(equal_range(&arr, &2), 1..4);
+/// assert_eq!(equal_range(&arr, &3), 4..4);
+/// ```
+pub fn equal_range<T: Ord>(arr: &[T], target: &T) -> Range<usize> {
+    let start = lower_bound(arr, target);
+    let end = start + upper_bound(&arr[start..], target);
+    start..end
+}
+
+/// Binary search returning the first match or the insertion point
+///
+/// # Arguments
+/// * `arr` - Sorted slice to search
+/// * `target` - Value to find
+///
+/// # Returns
+/// * `Ok(usize)` - Index of the first element equal to `target`
+/// * `Err(usize)` - Insertion point if `target` is absent
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::binary_search::binary_search;
+///
+/// let arr = [1, 3, 3, 3, 7];
+/// assert_eq!(binary_search(&arr, &3), Ok(1));
+/// assert_eq!(binary_search(&arr, &4), Err(4));
+/// ```
+pub fn binary_search<T: Ord>(arr: &[T], target: &T) -> Result<usize, usize> {
+    binary_search_by(arr, |x| x.cmp(target))
+}
+
+/// Binary search with a comparator describing each element relative to the target
+///
+/// # Arguments
+/// * `arr` - Slice sorted consistently with `compare`
+/// * `compare` - Returns the `Ordering` of an element relative to the target
+///
+/// # Returns
+/// * `Ok(usize)` - Index of the first element for which `compare` is `Equal`
+/// * `Err(usize)` - Insertion point otherwise
+pub fn binary_search_by<T, F>(arr: &[T], mut compare: F) -> Result<usize, usize>
+where
+    F: FnMut(&T) -> Ordering,
+{
+    let index = partition_by(arr, &mut compare);
+    match arr.get(index) {
+        Some(x) if compare(x) == Ordering::Equal => Ok(index),
+        _ => Err(index),
+    }
+}
+
+/// Binary search on a key extracted from each element
+///
+/// # Arguments
+/// * `arr` - Slice sorted by the extracted key
+/// * `key` - Key value to find
+/// * `extract` - Function mapping an element to its key
+///
+/// # Returns
+/// * `Ok(usize)` - Index of the first element whose key equals `key`
+/// * `Err(usize)` - Insertion point otherwise
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::binary_search::binary_search_by_key;
+///
+/// let people = [("ann", 19), ("bob", 25), ("cid", 25), ("dee", 31)];
+/// assert_eq!(binary_search_by_key(&people, &25, |p| p.1), Ok(1));
+/// assert_eq!(binary_search_by_key(&people, &30, |p| p.1), Err(3));
+/// ```
+pub fn binary_search_by_key<T, B, F>(arr: &[T], key: &B, mut extract: F) -> Result<usize, usize>
+where
+    B: Ord,
+    F: FnMut(&T) -> B,
+{
+    binary_search_by(arr, |x| extract(x).cmp(key))
+}
+
+/// Exponential (galloping) search over a sorted slice
+///
+/// Doubles a probe bound until it passes `target`, then binary searches the
+/// last window. Beats plain binary search when the target lies near the front.
+///
+/// # Arguments
+/// * `arr` - Sorted slice to search
+/// * `target` - Value to find
+///
+/// # Returns
+/// * `Ok(usize)` - Index of the first element equal to `target`
+/// * `Err(usize)` - Insertion point if `target` is absent
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::binary_search::exponential_search;
+///
+/// let arr: Vec<i32> = (0..1000).map(|x| x * 2).collect();
+/// assert_eq!(exponential_search(&arr, &10), Ok(5));
+/// assert_eq!(exponential_search(&arr, &11), Err(6));
+/// ```
+pub fn exponential_search<T: Ord>(arr: &[T], target: &T) -> Result<usize, usize> {
+    exponential_search_by(|i| arr.get(i).map(|x| x.cmp(target)))
+}
+
+/// Exponential search over an unbounded or lazily produced sorted sequence
+///
+/// # Arguments
+/// * `probe` - Returns `Some(ordering)` of the element at an index relative to
+///   the target, or `None` if the index is past the end of the sequence
+///
+/// # Returns
+/// * `Ok(usize)` - Index of the first element for which `probe` is `Equal`
+/// * `Err(usize)` - Insertion point otherwise; saturates at `usize::MAX` if
+///   every index up to `usize::MAX` is `Less`
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::binary_search::exponential_search_by;
+///
+/// // Squares of the natural numbers, without an upper bound
+/// let target = 1_000_000u64;
+/// let result = exponential_search_by(|i| Some((i as u64 * i as u64).cmp(&target)));
+/// assert_eq!(result, Ok(1000));
+/// ```
+///
+/// # Performance
+/// * Time Complexity: O(log i) probes where i is the returned index
+/// * Space Complexity: O(1)
+pub fn exponential_search_by<F>(mut probe: F) -> Result<usize, usize>
+where
+    F: FnMut(usize) -> Option<Ordering>,
+{
+    // Gallop: find a window (low, high] with probe(high) != Less or past the end
+    let mut low = 0;
+    let mut high = 0;
+    while let Some(Ordering::Less) = probe(high) {
+        if high == usize::MAX {
+            // Nothing left to gallop to: search [low, usize::MAX]
+            break;
+        }
+        low = high + 1;
+        high = high.saturating_mul(2).max(1);
+    }
+
+    // Binary search for the first index in [low, high] that is not Less
+    while low < high {
+        let mid = low + (high - low) / 2;
+        match probe(mid) {
+            Some(Ordering::Less) => low = mid + 1,
+            _ => high = mid,
+        }
+    }
+
+    match probe(low) {
+        Some(Ordering::Equal) => Ok(low),
+        _ => Err(low),
+    }
+}
+
+/// Exponential search over a sorted iterator, buffering only what it needs
+///
+/// Consumes at most about twice as many elements as the returned index.
+///
+/// # Arguments
+/// * `iter` - Iterator yielding elements in sorted order
+/// * `target` - Value to find
+///
+/// # Returns
+/// * `Ok(usize)` - Position of the first element equal to `target`
+/// * `Err(usize)` - Insertion point if `target` is absent
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::binary_search::exponential_search_iter;
+///
+/// let evens = (0..).step_by(2);
+/// assert_eq!(exponential_search_iter(evens, &84), Ok(42));
+/// ```
+pub fn exponential_search_iter<I, T>(iter: I, target: &T) -> Result<usize, usize>
+where
+    I: IntoIterator<Item = T>,
+    T: Ord,
+{
+    let mut iter = iter.into_iter();
+    let mut buffer: Vec<T> = Vec::new();
+    let mut exhausted = false;
+    let mut fill_to = |index: usize, buffer: &mut Vec<T>| {
+        while !exhausted && buffer.len() <= index {
+            match iter.next() {
+                Some(item) => buffer.push(item),
+                None => exhausted = true,
+            }
+        }
+    };
+
+    let mut high = 0;
+    loop {
+        fill_to(high, &mut buffer);
+        match buffer.get(high) {
+            Some(x) if x < target => high = high.saturating_mul(2).max(1),
+            _ => break,
+        }
+    }
+
+    // Everything before the window end is buffered, so the slice search's
+    // insertion point is also the insertion point in the full sequence
+    binary_search(&buffer[..buffer.len().min(high + 1)], target)
+}
+
+/// Binary search on the answer: finds where a monotone predicate turns false
+///
+/// `predicate` must be true on a prefix of `range` and false on the rest. For
+/// integers with `precision` 1 the result is exact; otherwise it is within
+/// `precision` above the true boundary.
+///
+/// # Arguments
+/// * `range` - Domain to search, `start..end`
+/// * `precision` - Width at which the search stops (integers treat 0 as 1)
+/// * `predicate` - Monotone test, true below the boundary
+///
+/// # Returns
+/// * `T` - The first value for which `predicate` is false, or `range.end` if there is none
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::binary_search::partition_point;
+///
+/// // Smallest batch size whose total cost reaches 1000
+/// let batch = partition_point(1u64..1_000_000, 1, |b| b * b + 10 * b < 1000);
+/// assert_eq!(batch, 28);
+///
+/// // Square root of 2 to within 1e-9
+/// let root = partition_point(0.0..2.0, 1e-9, |x: f64| x * x < 2.0);
+/// assert!((root - 2f64.sqrt()).abs() <= 1e-9);
+/// ```
+pub fn partition_point<T, P>(range: Range<T>, precision: T, mut predicate: P) -> T
+where
+    T: Bisect,
+    P: FnMut(T) -> bool,
+{
+    let Range { start: mut low, end: mut high } = range;
+    while let Some(mid) = T::split(low, high, precision) {
+        if predicate(mid) {
+            low = T::after(mid);
+        } else {
+            high = mid;
+        }
+    }
+    high
+}
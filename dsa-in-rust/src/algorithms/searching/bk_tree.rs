@@ -0,0 +1,196 @@
+//! BK-Tree for Fuzzy Lookup in Metric Spaces
+//!
+//! DEV NOTES:
+//! - Each child edge is labelled with its distance to the parent; a query
+//!   within `k` of `query` only needs children whose label lies in
+//!   `[d - k, d + k]` where `d` is the parent's distance to `query`
+//!   (triangle inequality)
+//! - Works with any `Metric`: `Levenshtein` for typos, `DamerauLevenshtein` to
+//!   count swaps as one edit, `Hamming` for fixed-width codes, or a closure
+//! - Distances to visited nodes use `Metric::distance_within` bounded by
+//!   `k + largest child label`, since beyond that neither the node nor any
+//!   child can qualify
+//! - Nodes live in a `Vec` and refer to children by index; items are never removed
+//!
+//! PERFORMANCE CONSIDERATIONS:
+//! - Insert: O(depth) distance computations
+//! - Query: visits a fraction of the tree that grows with `k`; small `k`
+//!   relative to typical distances keeps it well below a linear scan
+
+use super::edit_distance::{Levenshtein, Metric};
+use std::borrow::Borrow;
+
+#[derive(Debug, Clone)]
+struct BkNode<T> {
+    item: T,
+    /// `(distance to this node, child index)`
+    children: Vec<(usize, usize)>,
+    /// Largest child distance, for bounding distance computations
+    max_child_distance: usize,
+}
+
+/// BK-tree index over items of type `T` under metric `M`
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::bk_tree::BkTree;
+/// use dsa_in_rust::algorithms::searching::edit_distance::Levenshtein;
+///
+/// let mut tree = BkTree::new(Levenshtein);
+/// for word in ["book", "books", "cake", "boo", "cape", "cart"] {
+///     tree.insert(word.to_string());
+/// }
+/// let hits: Vec<(&String, usize)> = tree.find_within("bood", 1);
+/// assert_eq!(hits, [(&"book".to_string(), 1), (&"boo".to_string(), 1)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BkTree<T, M = Levenshtein> {
+    nodes: Vec<BkNode<T>>,
+    metric: M,
+}
+
+impl<T, M> BkTree<T, M> {
+    /// Creates an empty tree using `metric`
+    pub fn new(metric: M) -> Self {
+        Self { nodes: Vec::new(), metric }
+    }
+
+    /// Returns the number of stored items
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns true if the tree holds no items
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns an iterator over the stored items in insertion order
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.nodes.iter().map(|node| &node.item)
+    }
+}
+
+impl<T, M: Metric<T>> BkTree<T, M> {
+    /// Inserts an item
+    ///
+    /// # Arguments
+    /// * `item` - Item to add
+    ///
+    /// # Returns
+    /// * `bool` - false if an item at distance 0 is already present (nothing is inserted)
+    pub fn insert(&mut self, item: T) -> bool {
+        let index = self.nodes.len();
+        if self.nodes.is_empty() {
+            self.nodes.push(BkNode { item, children: Vec::new(), max_child_distance: 0 });
+            return true;
+        }
+
+        let mut current = 0;
+        loop {
+            let distance = self.metric.distance(&self.nodes[current].item, &item);
+            if distance == 0 {
+                return false;
+            }
+            let node = &mut self.nodes[current];
+            match node.children.iter().find(|&&(d, _)| d == distance) {
+                Some(&(_, child)) => current = child,
+                None => {
+                    node.children.push((distance, index));
+                    node.max_child_distance = node.max_child_distance.max(distance);
+                    break;
+                }
+            }
+        }
+        self.nodes.push(BkNode { item, children: Vec::new(), max_child_distance: 0 });
+        true
+    }
+
+    /// Finds all items within `max_distance` of `query`
+    ///
+    /// # Arguments
+    /// * `query` - Value to look up; anything the stored items borrow as
+    ///   (e.g. `&str` for a tree of `String`)
+    /// * `max_distance` - Largest distance to report
+    ///
+    /// # Returns
+    /// * `Vec<(&T, usize)>` - Matching items with their distances, closest
+    ///   first and in insertion order among equal distances
+    pub fn find_within<Q>(&self, query: &Q, max_distance: usize) -> Vec<(&T, usize)>
+    where
+        Q: ?Sized,
+        T: Borrow<Q>,
+        M: Metric<Q>,
+    {
+        let mut found = Vec::new();
+        let mut stack = if self.nodes.is_empty() { Vec::new() } else { vec![0] };
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            let bound = max_distance.saturating_add(node.max_child_distance);
+            let Some(distance) = self.metric.distance_within(query, node.item.borrow(), bound) else {
+                continue;
+            };
+            if distance <= max_distance {
+                found.push((index, distance));
+            }
+            let low = distance.saturating_sub(max_distance);
+            let high = distance.saturating_add(max_distance);
+            stack.extend(node.children.iter().filter(|&&(d, _)| low <= d && d <= high).map(|&(_, child)| child));
+        }
+        found.sort_unstable_by_key(|&(index, distance)| (distance, index));
+        found.into_iter().map(|(index, distance)| (&self.nodes[index].item, distance)).collect()
+    }
+
+    /// Finds the item closest to `query`
+    ///
+    /// # Returns
+    /// * `Option<(&T, usize)>` - The closest item and its distance (earliest
+    ///   inserted among ties), None if the tree is empty
+    pub fn nearest<Q>(&self, query: &Q) -> Option<(&T, usize)>
+    where
+        Q: ?Sized,
+        T: Borrow<Q>,
+        M: Metric<Q>,
+    {
+        let mut best: Option<(usize, usize)> = None;
+        let mut stack = if self.nodes.is_empty() { Vec::new() } else { vec![0] };
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            let distance = self.metric.distance(query, node.item.borrow());
+            if best.is_none_or(|(best_distance, best_index)| (distance, index) < (best_distance, best_index)) {
+                best = Some((distance, index));
+            }
+            let radius = best.map_or(usize::MAX, |(d, _)| d);
+            stack.extend(
+                node.children.iter().filter(|&&(d, _)| d.abs_diff(distance) <= radius).map(|&(_, child)| child),
+            );
+        }
+        best.map(|(distance, index)| (&self.nodes[index].item, distance))
+    }
+
+    /// Returns true if an item at distance 0 from `query` is stored
+    pub fn contains<Q>(&self, query: &Q) -> bool
+    where
+        Q: ?Sized,
+        T: Borrow<Q>,
+        M: Metric<Q>,
+    {
+        !self.find_within(query, 0).is_empty()
+    }
+}
+
+impl<T, M: Metric<T>> Extend<T> for BkTree<T, M> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.insert(item);
+        }
+    }
+}
+
+impl<T, M: Metric<T> + Default> FromIterator<T> for BkTree<T, M> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut tree = Self::new(M::default());
+        tree.extend(iter);
+        tree
+    }
+}
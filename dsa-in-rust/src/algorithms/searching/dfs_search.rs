@@ -1,73 +1,91 @@
 //! Depth First Search Implementation
 //! 
 //! DEV NOTES:
-//! - Recursive implementation
-//! - Stack space: O(h) where h is height of recursion tree
+//! - Array search walks the implicit binary tree (children of i at 2i+1, 2i+2)
+//!   iteratively with an explicit stack, via `tree_traversal::PreOrder`
+//! - An implicit tree cannot revisit a node, so no visited set is needed
+//! - Stack space: O(h) where h = log2(n) is the height of the implicit tree
 //! - Time complexity: O(n) where n is number of elements
-//! - Space complexity: O(n) for visited set
+//! - Graph search (`DfsSearch`) is also iterative: an explicit stack of
+//!   frames replaces recursion, so path graphs with millions of vertices are safe
+//! - Graph search keeps a visited set: O(V) space
+//! - Graph search is generic over `GraphView`, so it runs on adjacency lists,
+//!   `Graph`, or closures, and reports invalid endpoints as `GraphError`
 
-use std::collections::HashSet;
+use super::tree_traversal::pre_order;
+use crate::data_structures::graph::GraphError;
+use crate::data_structures::graph_view::GraphView;
 
 /// Performs depth-first search on array to find target element
 /// 
+/// The array is treated as an implicit binary tree and visited in pre-order
+/// (node, left subtree, right subtree).
+/// 
 /// # Arguments
-/// * `arr` - Slice of integers to search
+/// * `arr` - Slice encoding a binary tree level by level
 /// * `target` - Value to find
 /// 
 /// # Returns
-/// * `Option<usize>` - Index of target if found, None otherwise
+/// * `Option<usize>` - Index of the first match in pre-order, None otherwise
 /// 
 /// # Examples
 /// ```
-/// use dsa_in_rust::algorithms::searching::dfs::depth_first_search;
+/// use dsa_in_rust::algorithms::searching::dfs_search::depth_first_search;
 /// 
 /// let arr = vec![1, 2, 3, 4, 5];
-/// assert_eq!(depth_first_search(&arr, 3), Some(2));
-/// assert_eq!(depth_first_search(&arr, 6), None);
+/// assert_eq!(depth_first_search(&arr, &3), Some(2));
+/// assert_eq!(depth_first_search(&arr, &6), None);
 /// ```
-pub fn depth_first_search(arr: &[i32], target: i32) -> Option<usize> {
-    if arr.is_empty() {
-        return None;
-    }
-
-    let mut visited = HashSet::new();
-    dfs_recursive(arr, target, 0, &mut visited)
+pub fn depth_first_search<T: PartialEq>(arr: &[T], target: &T) -> Option<usize> {
+    depth_first_search_by(arr, |x| x == target)
 }
 
-fn dfs_recursive(arr: &[i32], target: i32, current: usize, visited: &mut HashSet<usize>) -> Option<usize> {
-    if visited.contains(&current) || current >= arr.len() {
-        return None;
-    }
-
-    visited.insert(current);
-
-    if arr[current] == target {
-        return Some(current);
-    }
-
-    // Search left
-    if let Some(found) = dfs_recursive(arr, target, current * 2 + 1, visited) {
-        return Some(found);
-    }
-
-    // Search right
-    dfs_recursive(arr, target, current * 2 + 2, visited)
+/// Performs depth-first search on array for the first element matching a predicate
+/// 
+/// # Arguments
+/// * `arr` - Slice encoding a binary tree level by level
+/// * `predicate` - Returns true for the element being searched for
+/// 
+/// # Returns
+/// * `Option<usize>` - Index of the first match in pre-order, None otherwise
+/// 
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::dfs_search::depth_first_search_by;
+/// 
+/// //        10
+/// //      /    \
+/// //     4      7
+/// //    / \
+/// //   3   8
+/// let arr = [10, 4, 7, 3, 8];
+/// // Pre-order visits 10, 4, 3, 8, 7: the left subtree's 8 wins over 7
+/// assert_eq!(depth_first_search_by(&arr, |&x| x > 5 && x < 10), Some(4));
+/// ```
+pub fn depth_first_search_by<T, P>(arr: &[T], mut predicate: P) -> Option<usize>
+where
+    P: FnMut(&T) -> bool,
+{
+    pre_order(arr).find(|&(_, x)| predicate(x)).map(|(i, _)| i)
 }
 
 /// `DfsSearch` implements a Depth-First Search algorithm for graph traversal.
 /// 
 /// # Features
-/// - Supports adjacency list representation of graphs
-/// - Finds shortest path between two vertices
+/// - Works on any `GraphView`: adjacency lists, `Graph`, or closures via `FnGraph`
+/// - Finds a path between two vertices; `iterative_deepening_search` finds a shortest one
+/// - Iterative with an explicit stack, safe on graphs with millions of vertices
 /// - Memory efficient with O(V) space complexity
 /// - Handles cyclic and acyclic graphs
 ///
 /// # Performance
 /// - Time Complexity: O(V + E) where V is vertices and E is edges
-/// - Space Complexity: O(V) for visited set and recursion stack
+/// - Space Complexity: O(V) for visited set and explicit stack
 ///
 /// # Example
 /// ```rust
+/// use dsa_in_rust::algorithms::searching::dfs_search::DfsSearch;
+///
 /// let graph = vec![
 ///     vec![1, 2],    // 0 -> [1, 2]
 ///     vec![2],       // 1 -> [2]
@@ -76,79 +94,407 @@ fn dfs_recursive(arr: &[i32], target: i32, current: usize, visited: &mut HashSet
 /// ];
 /// 
 /// let dfs = DfsSearch::new(&graph);
-/// let path = dfs.search(0, 3);
+/// let path = dfs.search(0, 3).unwrap();
 /// assert_eq!(path, Some(vec![0, 1, 2, 3]));
+/// assert_eq!(dfs.iterative_deepening_search(0, 3).unwrap(), Some(vec![0, 2, 3]));
 /// ```
-pub struct DfsSearch<'a> {
-    graph: &'a Vec<Vec<usize>>,
+pub struct DfsSearch<'a, G: GraphView + ?Sized = Vec<Vec<usize>>> {
+    graph: &'a G,
 }
 
-impl<'a> DfsSearch<'a> {
+/// Result of a single (possibly depth-limited) DFS pass
+enum Outcome {
+    Found(Vec<usize>),
+    /// Every reachable vertex was explored
+    Exhausted,
+    /// Some vertex at the depth limit still had unexplored edges
+    CutOff,
+}
+
+impl<'a, G: GraphView + ?Sized> DfsSearch<'a, G> {
     /// Creates a new DFS searcher for the given graph.
     /// 
     /// # Arguments
-    /// * `graph` - Adjacency list representation of the graph
+    /// * `graph` - Any graph implementing `GraphView`
     ///
     /// # Returns
     /// * `DfsSearch` instance configured for the provided graph
-    pub fn new(graph: &'a Vec<Vec<usize>>) -> Self {
+    pub fn new(graph: &'a G) -> Self {
         DfsSearch { graph }
     }
 
     /// Searches for a path from start vertex to target vertex.
     /// 
+    /// Neighbours are explored in adjacency-list order, but a direct edge to
+    /// `target` is always taken before descending further.
+    /// 
+    /// # Arguments
+    /// * `start` - Starting vertex index
+    /// * `target` - Target vertex index
+    ///
+    /// # Returns
+    /// * `Ok(Some(Vec<usize>))` - Path from start to target if found
+    /// * `Ok(None)` - If no path exists
+    /// * `Err(GraphError::InvalidNode)` - If start or target is out of bounds
+    pub fn search(&self, start: usize, target: usize) -> Result<Option<Vec<usize>>, GraphError> {
+        self.check_endpoints(start, target)?;
+        Ok(self.dfs(start, target, None).found())
+    }
+
+    /// Searches for a path using at most `max_depth` edges.
+    /// 
+    /// A vertex reached again by a shorter route is re-explored, so a path
+    /// within the limit is found whenever one exists.
+    /// 
     /// # Arguments
     /// * `start` - Starting vertex index
     /// * `target` - Target vertex index
+    /// * `max_depth` - Maximum number of edges in the returned path
     ///
     /// # Returns
-    /// * `Some(Vec<usize>)` - Path from start to target if found
-    /// * `None` - If no path exists or vertices are invalid
+    /// * `Ok(Some(Vec<usize>))` - Path of at most `max_depth` edges if found
+    /// * `Ok(None)` - If no such path exists
+    /// * `Err(GraphError::InvalidNode)` - If start or target is out of bounds
     ///
-    /// # Panics
-    /// Will panic if start or target vertex indices are out of bounds
-    pub fn search(&self, start: usize, target: usize) -> Option<Vec<usize>> {
-        let mut visited = HashSet::with_capacity(self.graph.len());
-        let mut path = Vec::with_capacity(self.graph.len());
-        self.dfs(start, target, &mut visited, &mut path)
+    /// # Performance
+    /// * Time Complexity: O(d * (V + E)) worst case where d is `max_depth`
+    /// * Space Complexity: O(V)
+    pub fn search_depth_limited(
+        &self,
+        start: usize,
+        target: usize,
+        max_depth: usize,
+    ) -> Result<Option<Vec<usize>>, GraphError> {
+        self.check_endpoints(start, target)?;
+        Ok(self.dfs(start, target, Some(max_depth)).found())
     }
 
-    /// Internal recursive DFS implementation
-    fn dfs(
-        &self, 
-        current: usize, 
-        target: usize, 
-        visited: &mut HashSet<usize>, 
-        path: &mut Vec<usize>
-    ) -> Option<Vec<usize>> {
-        if current >= self.graph.len() {
-            return None;
+    /// Finds a path with the fewest edges using iterative deepening DFS (IDDFS).
+    /// 
+    /// Runs depth-limited searches with limits 0, 1, 2, ... and stops early
+    /// once a pass explores the whole reachable graph without hitting its limit.
+    /// 
+    /// # Arguments
+    /// * `start` - Starting vertex index
+    /// * `target` - Target vertex index
+    ///
+    /// # Returns
+    /// * `Ok(Some(Vec<usize>))` - A shortest path from start to target if found
+    /// * `Ok(None)` - If no path exists
+    /// * `Err(GraphError::InvalidNode)` - If start or target is out of bounds
+    ///
+    /// # Performance
+    /// * Time Complexity: O(d² * (V + E)) worst case where d is the path length
+    /// * Space Complexity: O(V)
+    pub fn iterative_deepening_search(&self, start: usize, target: usize) -> Result<Option<Vec<usize>>, GraphError> {
+        self.check_endpoints(start, target)?;
+        for limit in 0.. {
+            match self.dfs(start, target, Some(limit)) {
+                Outcome::Found(path) => return Ok(Some(path)),
+                Outcome::Exhausted => return Ok(None),
+                Outcome::CutOff => continue,
+            }
         }
+        Ok(None)
+    }
 
-        visited.insert(current);
-        path.push(current);
+    /// Returns a lazy iterator over all simple paths from start to target.
+    /// 
+    /// Paths are produced in depth-first order, one at a time, so only the
+    /// current path is held in memory. Limits can be set on the iterator with
+    /// `AllPaths::max_length` and `AllPaths::max_results`.
+    /// 
+    /// # Arguments
+    /// * `start` - Starting vertex index
+    /// * `target` - Target vertex index
+    ///
+    /// # Returns
+    /// * `Ok(AllPaths)` - Iterator yielding each simple path as a `Vec<usize>`
+    /// * `Err(GraphError::InvalidNode)` - If start or target is out of bounds
+    ///
+    /// # Examples
+    /// ```
+    /// use dsa_in_rust::algorithms::searching::dfs_search::DfsSearch;
+    ///
+    /// let graph = vec![vec![1, 2], vec![3], vec![1, 3], vec![]];
+    /// let dfs = DfsSearch::new(&graph);
+    /// let paths: Vec<_> = dfs.all_paths(0, 3).unwrap().collect();
+    /// assert_eq!(paths, vec![vec![0, 1, 3], vec![0, 2, 1, 3], vec![0, 2, 3]]);
+    ///
+    /// let short: Vec<_> = dfs.all_paths(0, 3).unwrap().max_length(2).collect();
+    /// assert_eq!(short, vec![vec![0, 1, 3], vec![0, 2, 3]]);
+    /// ```
+    ///
+    /// # Performance
+    /// * Time Complexity: Output-sensitive; exponential in the worst case
+    ///   (a complete graph has O(V!) simple paths, and branches that never
+    ///   reach the target are still explored). Use the limits to bound it.
+    /// * Space Complexity: O(V)
+    pub fn all_paths(&self, start: usize, target: usize) -> Result<AllPaths<'a, G>, GraphError> {
+        self.check_endpoints(start, target)?;
+        Ok(AllPaths::new(self.graph, start, target))
+    }
 
-        // Check if target is found
-        if current == target {
-            return Some(path.clone());
+    /// Counts the paths from start to target in a directed acyclic graph.
+    /// 
+    /// Uses dynamic programming over a post-order DFS: the count for a vertex
+    /// is the sum of its successors' counts. The target is treated as a sink,
+    /// so its outgoing edges are ignored.
+    /// 
+    /// # Arguments
+    /// * `start` - Starting vertex index
+    /// * `target` - Target vertex index
+    ///
+    /// # Returns
+    /// * `Ok(Some(u128))` - Number of paths (saturating at `u128::MAX`)
+    /// * `Ok(None)` - If a cycle is reachable from start, so the graph is not a DAG
+    /// * `Err(GraphError::InvalidNode)` - If start or target is out of bounds
+    ///
+    /// # Examples
+    /// ```
+    /// use dsa_in_rust::algorithms::searching::dfs_search::DfsSearch;
+    ///
+    /// // Two diamonds in a row: 2 * 2 paths
+    /// let graph = vec![vec![1, 2], vec![3], vec![3], vec![4, 5], vec![6], vec![6], vec![]];
+    /// assert_eq!(DfsSearch::new(&graph).count_paths(0, 6), Ok(Some(4)));
+    /// ```
+    ///
+    /// # Performance
+    /// * Time Complexity: O(V + E)
+    /// * Space Complexity: O(V)
+    pub fn count_paths(&self, start: usize, target: usize) -> Result<Option<u128>, GraphError> {
+        self.check_endpoints(start, target)?;
+        if start == target {
+            return Ok(Some(1));
         }
 
-        // First check direct edge to target
-        if self.graph[current].contains(&target) {
-            path.push(target);
-            return Some(path.clone());
+        let vertex_count = self.graph.node_count();
+        // 0 = unvisited, 1 = on the DFS stack, 2 = finished
+        let mut state = vec![0u8; vertex_count];
+        let mut counts = vec![0u128; vertex_count];
+        let mut stack = vec![(start, self.graph.neighbors(start))];
+        state[start] = 1;
+
+        while let Some((current, neighbors)) = stack.last_mut() {
+            let current = *current;
+            match neighbors.next() {
+                Some(next) => {
+                    if next >= vertex_count {
+                        continue;
+                    }
+                    if next == target {
+                        counts[current] = counts[current].saturating_add(1);
+                        continue;
+                    }
+                    match state[next] {
+                        0 => {
+                            state[next] = 1;
+                            stack.push((next, self.graph.neighbors(next)));
+                        }
+                        1 => return Ok(None),
+                        _ => counts[current] = counts[current].saturating_add(counts[next]),
+                    }
+                }
+                None => {
+                    state[current] = 2;
+                    stack.pop();
+                    if let Some((parent, _)) = stack.last() {
+                        counts[*parent] = counts[*parent].saturating_add(counts[current]);
+                    }
+                }
+            }
         }
 
-        // Then explore other paths
-        for &next in &self.graph[current] {
-            if !visited.contains(&next) {
-                if let Some(result) = self.dfs(next, target, visited, path) {
-                    return Some(result);
+        Ok(Some(counts[start]))
+    }
+
+    /// Validates both endpoints of a search
+    fn check_endpoints(&self, start: usize, target: usize) -> Result<(), GraphError> {
+        self.graph.check_node(start)?;
+        self.graph.check_node(target)
+    }
+
+    /// Internal iterative DFS implementation
+    /// 
+    /// Keeps an explicit stack of `(vertex, remaining neighbours)` frames that
+    /// mirrors the call stack of a recursive DFS, so paths come out in the same
+    /// order a recursive search would produce them.
+    fn dfs(&self, start: usize, target: usize, limit: Option<usize>) -> Outcome {
+        let vertex_count = self.graph.node_count();
+
+        // Depth at which each vertex was last entered; usize::MAX if never
+        let mut entered_at = vec![usize::MAX; vertex_count];
+        let mut path = Vec::new();
+        let mut stack: Vec<(usize, G::Neighbors<'a>)> = Vec::new();
+        let mut pending = Some(start);
+        let mut cut_off = false;
+
+        loop {
+            if let Some(current) = pending.take() {
+                let depth = path.len();
+                entered_at[current] = depth;
+                path.push(current);
+
+                // Check if target is found
+                if current == target {
+                    return Outcome::Found(path);
+                }
+
+                let can_descend = limit.is_none_or(|limit| depth < limit);
+                if !can_descend {
+                    cut_off |= self.graph.neighbors(current).next().is_some();
+                    path.pop();
+                    continue;
+                }
+
+                // First check direct edge to target
+                if self.graph.contains_edge(current, target) {
+                    path.push(target);
+                    return Outcome::Found(path);
+                }
+
+                stack.push((current, self.graph.neighbors(current)));
+                continue;
+            }
+
+            // Then explore other paths
+            let Some((_, neighbors)) = stack.last_mut() else {
+                break;
+            };
+            match neighbors.next() {
+                Some(next) => {
+                    if next >= vertex_count {
+                        continue;
+                    }
+                    let depth = path.len();
+                    let unvisited = entered_at[next] == usize::MAX;
+                    let shallower = limit.is_some() && depth < entered_at[next];
+                    if unvisited || shallower {
+                        pending = Some(next);
+                    }
+                }
+                None => {
+                    stack.pop();
+                    path.pop();
                 }
             }
         }
 
-        path.pop();
+        if cut_off {
+            Outcome::CutOff
+        } else {
+            Outcome::Exhausted
+        }
+    }
+}
+
+impl Outcome {
+    fn found(self) -> Option<Vec<usize>> {
+        match self {
+            Outcome::Found(path) => Some(path),
+            _ => None,
+        }
+    }
+}
+
+/// Lazy iterator over all simple paths between two vertices
+/// 
+/// Created by `DfsSearch::all_paths`. The number of simple paths can grow
+/// exponentially with the graph size, and so can the time between two
+/// yielded paths; `max_length` and `max_results` cap the search.
+pub struct AllPaths<'a, G: GraphView + ?Sized = Vec<Vec<usize>>> {
+    graph: &'a G,
+    target: usize,
+    /// Frames of `(vertex, remaining neighbours)` for the current path
+    stack: Vec<(usize, G::Neighbors<'a>)>,
+    path: Vec<usize>,
+    on_path: Vec<bool>,
+    max_length: Option<usize>,
+    remaining: Option<usize>,
+}
+
+impl<'a, G: GraphView + ?Sized> AllPaths<'a, G> {
+    fn new(graph: &'a G, start: usize, target: usize) -> Self {
+        let mut on_path = vec![false; graph.node_count()];
+        on_path[start] = true;
+        AllPaths {
+            graph,
+            target,
+            stack: vec![(start, graph.neighbors(start))],
+            path: vec![start],
+            on_path,
+            max_length: None,
+            remaining: None,
+        }
+    }
+
+    /// Only yields paths with at most `edges` edges
+    pub fn max_length(mut self, edges: usize) -> Self {
+        self.max_length = Some(edges);
+        self
+    }
+
+    /// Stops after yielding `count` paths
+    pub fn max_results(mut self, count: usize) -> Self {
+        self.remaining = Some(count);
+        self
+    }
+
+    /// Counts a path against `max_results` and returns it
+    fn emit(&mut self, path: Vec<usize>) -> Option<Vec<usize>> {
+        if let Some(remaining) = self.remaining.as_mut() {
+            *remaining -= 1;
+        }
+        Some(path)
+    }
+}
+
+impl<'a, G: GraphView + ?Sized> Iterator for AllPaths<'a, G> {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == Some(0) {
+            return None;
+        }
+
+        // A path from a vertex to itself is just that vertex
+        if self.path.len() == 1 && self.path[0] == self.target {
+            self.stack.clear();
+            self.path.clear();
+            return self.emit(vec![self.target]);
+        }
+
+        while let Some((_, neighbors)) = self.stack.last_mut() {
+            let Some(next) = neighbors.next() else {
+                let (current, _) = self.stack.pop().unwrap();
+                self.on_path[current] = false;
+                self.path.pop();
+                continue;
+            };
+
+            // Adding `next` would give a path of `self.path.len()` edges
+            let edges = self.path.len();
+            if next >= self.on_path.len()
+                || self.on_path[next]
+                || self.max_length.is_some_and(|max| edges > max)
+            {
+                continue;
+            }
+
+            if next == self.target {
+                let mut found = self.path.clone();
+                found.push(next);
+                return self.emit(found);
+            }
+
+            // Only descend if the target could still be reached within the limit
+            if self.max_length.is_none_or(|max| edges < max) {
+                self.stack.push((next, self.graph.neighbors(next)));
+                self.path.push(next);
+                self.on_path[next] = true;
+            }
+        }
+
         None
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,311 @@
+//! Edit Distances and Distance Metrics
+//!
+//! DEV NOTES:
+//! - Distances work on slices of any `Eq` element type; for text, compare
+//!   `as_bytes()` for byte edits or collect `chars()` for character edits
+//! - Damerau–Levenshtein is the unrestricted variant (Lowrance–Wagner), which
+//!   unlike optimal string alignment satisfies the triangle inequality and is
+//!   therefore safe to index in a `BkTree`
+//! - Every distance has a `_bounded` variant returning None as soon as the
+//!   result is known to exceed `max`; Levenshtein additionally restricts the
+//!   DP to a diagonal band of width 2 * max + 1
+//! - `Metric` lets indexes such as `BkTree` take any distance, including closures
+//!
+//! PERFORMANCE CONSIDERATIONS:
+//! - Levenshtein: O(n * m) time, O(m) space; bounded: O(n * max)
+//! - Damerau–Levenshtein: O(n * m) time and space
+//! - Hamming: O(n) time, O(1) space
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A distance function satisfying the metric axioms
+///
+/// Indexes like `BkTree` rely on the triangle inequality; a "distance" that
+/// violates it makes their queries miss results.
+pub trait Metric<T: ?Sized> {
+    /// Returns the distance between `a` and `b`
+    fn distance(&self, a: &T, b: &T) -> usize;
+
+    /// Returns the distance if it is at most `max`, None otherwise
+    ///
+    /// The default computes the full distance; implementations may stop early.
+    fn distance_within(&self, a: &T, b: &T, max: usize) -> Option<usize> {
+        let distance = self.distance(a, b);
+        (distance <= max).then_some(distance)
+    }
+}
+
+impl<T: ?Sized, F: Fn(&T, &T) -> usize> Metric<T> for F {
+    fn distance(&self, a: &T, b: &T) -> usize {
+        self(a, b)
+    }
+}
+
+/// Levenshtein distance: insertions, deletions and substitutions
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Levenshtein;
+
+/// Damerau–Levenshtein distance: Levenshtein plus transpositions of adjacent elements
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DamerauLevenshtein;
+
+/// Hamming distance: positions that differ, with any length difference
+/// counted as that many extra mismatches
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Hamming;
+
+/// Computes the Levenshtein distance between two sequences
+///
+/// # Arguments
+/// * `a`, `b` - Sequences to compare
+///
+/// # Returns
+/// * `usize` - Minimum number of single-element insertions, deletions and substitutions
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::edit_distance::levenshtein;
+///
+/// assert_eq!(levenshtein(b"kitten", b"sitting"), 3);
+/// assert_eq!(levenshtein(&[1, 2, 3], &[1, 3]), 1);
+/// ```
+pub fn levenshtein<T: Eq>(a: &[T], b: &[T]) -> usize {
+    let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+    for (i, x) in a.iter().enumerate() {
+        curr[0] = i + 1;
+        for (j, y) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(x != y);
+            curr[j + 1] = substitution.min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
+}
+
+/// Computes the Levenshtein distance if it is at most `max`
+///
+/// # Arguments
+/// * `a`, `b` - Sequences to compare
+/// * `max` - Largest distance of interest
+///
+/// # Returns
+/// * `Option<usize>` - The distance, None if it exceeds `max`
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::edit_distance::levenshtein_bounded;
+///
+/// assert_eq!(levenshtein_bounded(b"kitten", b"sitting", 3), Some(3));
+/// assert_eq!(levenshtein_bounded(b"kitten", b"sitting", 2), None);
+/// ```
+pub fn levenshtein_bounded<T: Eq>(a: &[T], b: &[T], max: usize) -> Option<usize> {
+    let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
+    if a.len() - b.len() > max {
+        return None;
+    }
+    // The distance never exceeds the longer length; clamping keeps `max + 1` in range
+    let max = max.min(a.len());
+
+    // Cells outside the band |i - j| <= max can never lead to a result within
+    // `max`; they hold `over`, which stands for "too far"
+    let over = max + 1;
+    let mut prev: Vec<usize> = (0..=b.len()).map(|j| j.min(over)).collect();
+    let mut curr = vec![over; b.len() + 1];
+    for (i, x) in a.iter().enumerate() {
+        let row = i + 1;
+        let low = row.saturating_sub(max).max(1);
+        let high = (row + max).min(b.len());
+        curr[low - 1] = if low == 1 { row.min(over) } else { over };
+        let mut row_min = curr[low - 1];
+        for j in low..=high {
+            let substitution = prev[j - 1] + usize::from(*x != b[j - 1]);
+            curr[j] = substitution.min(prev[j] + 1).min(curr[j - 1] + 1).min(over);
+            row_min = row_min.min(curr[j]);
+        }
+        if high < b.len() {
+            curr[high + 1] = over;
+        }
+        if row_min > max {
+            return None;
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    let distance = prev[b.len()];
+    (distance <= max).then_some(distance)
+}
+
+/// Computes the (unrestricted) Damerau–Levenshtein distance between two sequences
+///
+/// # Arguments
+/// * `a`, `b` - Sequences to compare
+///
+/// # Returns
+/// * `usize` - Minimum number of insertions, deletions, substitutions and
+///   adjacent transpositions, where transposed elements may be edited further
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::edit_distance::damerau_levenshtein;
+///
+/// assert_eq!(damerau_levenshtein(b"ca", b"ac"), 1);
+/// // Optimal string alignment would report 3 here
+/// assert_eq!(damerau_levenshtein(b"ca", b"abc"), 2);
+/// ```
+pub fn damerau_levenshtein<T: Eq + Hash>(a: &[T], b: &[T]) -> usize {
+    damerau_levenshtein_impl(a, b, usize::MAX).expect("an unbounded distance always exists")
+}
+
+/// Computes the Damerau–Levenshtein distance if it is at most `max`
+///
+/// # Returns
+/// * `Option<usize>` - The distance, None if it exceeds `max`
+pub fn damerau_levenshtein_bounded<T: Eq + Hash>(a: &[T], b: &[T], max: usize) -> Option<usize> {
+    damerau_levenshtein_impl(a, b, max)
+}
+
+fn damerau_levenshtein_impl<T: Eq + Hash>(a: &[T], b: &[T], max: usize) -> Option<usize> {
+    if a.len().abs_diff(b.len()) > max {
+        return None;
+    }
+    let (n, m) = (a.len(), b.len());
+    let infinity = n + m;
+    let width = m + 2;
+
+    // d[(i + 1) * width + (j + 1)] is the distance between a[..i] and b[..j];
+    // row and column 0 hold `infinity` as a sentinel for transpositions
+    let mut d = vec![infinity; (n + 2) * width];
+    for i in 0..=n {
+        d[(i + 1) * width + 1] = i;
+    }
+    for j in 0..=m {
+        d[width + j + 1] = j;
+    }
+
+    // Last row of `a` in which each element was seen
+    let mut last_row: HashMap<&T, usize> = HashMap::new();
+    for i in 1..=n {
+        // Last column in this row where a[i - 1] matched
+        let mut last_match_col = 0;
+        let mut row_min = d[(i + 1) * width + 1];
+        for j in 1..=m {
+            let k = last_row.get(&b[j - 1]).copied().unwrap_or(0);
+            let l = last_match_col;
+            let cost = if a[i - 1] == b[j - 1] {
+                last_match_col = j;
+                0
+            } else {
+                1
+            };
+            let value = (d[i * width + j] + cost)
+                .min(d[(i + 1) * width + j] + 1)
+                .min(d[i * width + j + 1] + 1)
+                .min(d[k * width + l] + (i - k - 1) + 1 + (j - l - 1));
+            d[(i + 1) * width + j + 1] = value;
+            row_min = row_min.min(value);
+        }
+        // Every later cell costs at least the minimum of any earlier row
+        if row_min > max {
+            return None;
+        }
+        last_row.insert(&a[i - 1], i);
+    }
+    let distance = d[(n + 1) * width + m + 1];
+    (distance <= max).then_some(distance)
+}
+
+/// Computes the Hamming distance between two sequences of equal length
+///
+/// # Returns
+/// * `Option<usize>` - Number of differing positions, None if the lengths differ
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::edit_distance::hamming;
+///
+/// assert_eq!(hamming(b"karolin", b"kathrin"), Some(3));
+/// assert_eq!(hamming(b"abc", b"ab"), None);
+/// ```
+pub fn hamming<T: Eq>(a: &[T], b: &[T]) -> Option<usize> {
+    (a.len() == b.len()).then(|| a.iter().zip(b).filter(|(x, y)| x != y).count())
+}
+
+/// Computes the Hamming distance if the lengths match and it is at most `max`
+///
+/// # Returns
+/// * `Option<usize>` - The distance, None if the lengths differ or it exceeds `max`
+pub fn hamming_bounded<T: Eq>(a: &[T], b: &[T], max: usize) -> Option<usize> {
+    if a.len() != b.len() {
+        return None;
+    }
+    let mut distance = 0;
+    for (x, y) in a.iter().zip(b) {
+        if x != y {
+            distance += 1;
+            if distance > max {
+                return None;
+            }
+        }
+    }
+    Some(distance)
+}
+
+/// Hamming distance with the length difference counted as mismatches
+fn padded_hamming<T: Eq>(a: &[T], b: &[T], max: usize) -> Option<usize> {
+    let extra = a.len().abs_diff(b.len());
+    let common = a.len().min(b.len());
+    let distance = hamming_bounded(&a[..common], &b[..common], max.checked_sub(extra)?)?;
+    Some(distance + extra)
+}
+
+/// Implements `Metric` for slices, vectors, `str` (by characters) and `String`
+macro_rules! impl_sequence_metric {
+    ($metric:ty, [$($bound:tt)+], $within:expr) => {
+        impl<T: $($bound)+> Metric<[T]> for $metric {
+            fn distance(&self, a: &[T], b: &[T]) -> usize {
+                $within(a, b, usize::MAX).expect("an unbounded distance always exists")
+            }
+
+            fn distance_within(&self, a: &[T], b: &[T], max: usize) -> Option<usize> {
+                $within(a, b, max)
+            }
+        }
+
+        impl<T: $($bound)+> Metric<Vec<T>> for $metric {
+            fn distance(&self, a: &Vec<T>, b: &Vec<T>) -> usize {
+                Metric::<[T]>::distance(self, a, b)
+            }
+
+            fn distance_within(&self, a: &Vec<T>, b: &Vec<T>, max: usize) -> Option<usize> {
+                Metric::<[T]>::distance_within(self, a, b, max)
+            }
+        }
+
+        impl Metric<str> for $metric {
+            fn distance(&self, a: &str, b: &str) -> usize {
+                self.distance_within(a, b, usize::MAX).expect("an unbounded distance always exists")
+            }
+
+            fn distance_within(&self, a: &str, b: &str, max: usize) -> Option<usize> {
+                let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+                $within(&a, &b, max)
+            }
+        }
+
+        impl Metric<String> for $metric {
+            fn distance(&self, a: &String, b: &String) -> usize {
+                Metric::<str>::distance(self, a, b)
+            }
+
+            fn distance_within(&self, a: &String, b: &String, max: usize) -> Option<usize> {
+                Metric::<str>::distance_within(self, a, b, max)
+            }
+        }
+    };
+}
+
+impl_sequence_metric!(Levenshtein, [Eq], levenshtein_bounded);
+impl_sequence_metric!(DamerauLevenshtein, [Eq + Hash], damerau_levenshtein_bounded);
+impl_sequence_metric!(Hamming, [Eq], padded_hamming);
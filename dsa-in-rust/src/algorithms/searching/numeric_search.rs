@@ -0,0 +1,196 @@
+//! Interpolation, Jump and Fibonacci Search Implementation
+//!
+//! DEV NOTES:
+//! - Tailored to sorted numeric data such as `generate_sorted_integers` output
+//! - Every search reports how many probes it made, so distributions can be
+//!   compared without a profiler
+//! - A probe is one comparison of `target` against the element at a position
+//!   the algorithm chose; reads used only to compute the next position (the
+//!   interpolation endpoints) are not counted
+//! - Interpolation search falls back to a binary step whenever an
+//!   interpolation step fails to halve the remaining range, which bounds the
+//!   worst case on skewed data at about 2 * log2(n) probes
+//!
+//! PERFORMANCE CONSIDERATIONS:
+//! - Interpolation: O(log log n) probes on uniform keys, O(log n) worst case
+//! - Jump: O(sqrt(n)) probes, only forward scans (cheap on sequential media)
+//! - Fibonacci: O(log n) probes using additions and subtractions only
+
+use std::cmp::Ordering;
+
+/// Outcome of a probe-counted search
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProbeResult {
+    /// Index of an element equal to the target, if any
+    pub index: Option<usize>,
+    /// Number of element comparisons performed
+    pub probes: usize,
+}
+
+/// Interpolation search with a binary-search safeguard
+///
+/// # Arguments
+/// * `arr` - Sorted slice of integers
+/// * `target` - Value to find
+///
+/// # Returns
+/// * `ProbeResult` - Index of `target` if present and the probe count
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::numeric_search::interpolation_search;
+/// use dsa_in_rust::utils::helpers::generate_sorted_integers;
+///
+/// let arr = generate_sorted_integers(1_000_000);
+/// let result = interpolation_search(&arr, &765_432);
+/// assert_eq!(result.index, Some(765_431));
+/// assert!(result.probes <= 2);
+/// ```
+pub fn interpolation_search<T>(arr: &[T], target: &T) -> ProbeResult
+where
+    T: Copy + Ord + Into<i128>,
+{
+    let mut probes = 0;
+    if arr.is_empty() {
+        return ProbeResult { index: None, probes };
+    }
+
+    let goal: i128 = (*target).into();
+    let mut low = 0;
+    let mut high = arr.len() - 1;
+    let mut binary_step = false;
+
+    while low <= high {
+        let low_value: i128 = arr[low].into();
+        let high_value: i128 = arr[high].into();
+        if goal < low_value || goal > high_value {
+            break;
+        }
+
+        let pos = if binary_step || low_value == high_value {
+            low + (high - low) / 2
+        } else {
+            low + ((goal - low_value) * (high - low) as i128 / (high_value - low_value)) as usize
+        };
+
+        let before = high - low + 1;
+        probes += 1;
+        match arr[pos].cmp(target) {
+            Ordering::Equal => return ProbeResult { index: Some(pos), probes },
+            Ordering::Less => low = pos + 1,
+            Ordering::Greater if pos == 0 => break,
+            Ordering::Greater => high = pos - 1,
+        }
+
+        // Alternate in a binary step after any interpolation that did not halve the range
+        let after = (high + 1).saturating_sub(low);
+        binary_step = !binary_step && after > before / 2;
+    }
+
+    ProbeResult { index: None, probes }
+}
+
+/// Jump search: scan block boundaries of size sqrt(n), then the block itself
+///
+/// # Arguments
+/// * `arr` - Sorted slice
+/// * `target` - Value to find
+///
+/// # Returns
+/// * `ProbeResult` - Index of the first element equal to `target` and the probe count
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::numeric_search::jump_search;
+///
+/// let arr = [1, 3, 5, 7, 9, 11, 13, 15, 17];
+/// assert_eq!(jump_search(&arr, &13).index, Some(6));
+/// assert_eq!(jump_search(&arr, &4).index, None);
+/// ```
+pub fn jump_search<T: Ord>(arr: &[T], target: &T) -> ProbeResult {
+    let mut probes = 0;
+    let n = arr.len();
+    let step = (n as f64).sqrt().max(1.0) as usize;
+
+    // Find the first block whose last element is >= target
+    let mut block_start = 0;
+    while block_start < n {
+        let block_end = (block_start + step).min(n);
+        probes += 1;
+        if arr[block_end - 1] >= *target {
+            break;
+        }
+        block_start = block_end;
+    }
+
+    // Linear scan inside the block
+    for (i, item) in arr.iter().enumerate().take((block_start + step).min(n)).skip(block_start) {
+        probes += 1;
+        match item.cmp(target) {
+            Ordering::Equal => return ProbeResult { index: Some(i), probes },
+            Ordering::Greater => break,
+            Ordering::Less => {}
+        }
+    }
+
+    ProbeResult { index: None, probes }
+}
+
+/// Fibonacci search: splits the range at Fibonacci offsets instead of halves
+///
+/// # Arguments
+/// * `arr` - Sorted slice
+/// * `target` - Value to find
+///
+/// # Returns
+/// * `ProbeResult` - Index of `target` if present and the probe count
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::numeric_search::fibonacci_search;
+///
+/// let arr = [10, 22, 35, 40, 45, 50, 80, 82, 85, 90, 100];
+/// assert_eq!(fibonacci_search(&arr, &85).index, Some(8));
+/// assert_eq!(fibonacci_search(&arr, &23).index, None);
+/// ```
+pub fn fibonacci_search<T: Ord>(arr: &[T], target: &T) -> ProbeResult {
+    let mut probes = 0;
+    let n = arr.len();
+
+    // Smallest Fibonacci number >= n, with its two predecessors
+    let (mut fib2, mut fib1, mut fib) = (0usize, 1usize, 1usize);
+    while fib < n {
+        fib2 = fib1;
+        fib1 = fib;
+        fib = fib1 + fib2;
+    }
+
+    // Number of leading elements already known to be < target
+    let mut eliminated = 0;
+    while fib > 1 {
+        let i = (eliminated + fib2 - 1).min(n - 1);
+        probes += 1;
+        match arr[i].cmp(target) {
+            Ordering::Equal => return ProbeResult { index: Some(i), probes },
+            Ordering::Less => {
+                fib = fib1;
+                fib1 = fib2;
+                fib2 = fib - fib1;
+                eliminated = i + 1;
+            }
+            Ordering::Greater => {
+                fib = fib2;
+                fib1 -= fib2;
+                fib2 = fib - fib1;
+            }
+        }
+    }
+
+    if fib1 == 1 && eliminated < n {
+        probes += 1;
+        if arr[eliminated] == *target {
+            return ProbeResult { index: Some(eliminated), probes };
+        }
+    }
+    ProbeResult { index: None, probes }
+}
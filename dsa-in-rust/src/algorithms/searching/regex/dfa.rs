@@ -0,0 +1,455 @@
+//! Subset Construction and Hopcroft Minimization
+//!
+//! DEV NOTES:
+//! - Each DFA state is the set of NFA states live after reading some prefix;
+//!   states are explored breadth-first from the start set and deduplicated
+//! - The alphabet is not all of Unicode: character class boundaries split the
+//!   `char` range into intervals that every NFA class either fully contains
+//!   or avoids, and each interval is one input symbol
+//! - Anchors: `^` is resolved while building the start state; `$` edges stay
+//!   in the set and decide `accepting_at_end`, the acceptance flag used once
+//!   the text is exhausted
+//! - Unanchored DFAs re-add the NFA start state after every step, so they
+//!   accept any text containing a match; anchored DFAs accept whole-text matches
+//! - Subset construction can blow up exponentially, so it stops with
+//!   `RegexError::DfaTooLarge` past a caller-chosen state limit
+//! - `LazyDfa` builds the same states on demand while matching and caches
+//!   them up to a limit, so only the states a text reaches are ever built
+//! - Hopcroft's algorithm refines the partition {accepting flags} by
+//!   predecessor sets, always queueing the smaller half of a split block
+//!
+//! PERFORMANCE CONSIDERATIONS:
+//! - Matching: O(n log k) for n characters and k input symbols
+//! - Construction: O(2^m) states in the worst case, usually far fewer
+//! - Lazy construction: at most one new state per character, each O(m)
+//! - Minimization: O(k * s log s) for s states
+
+use super::nfa::{Nfa, NfaState};
+use super::parser::{next_char, Anchor, RegexError};
+use std::collections::{HashMap, VecDeque};
+
+/// Deterministic automaton over character intervals
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::regex::dfa::Dfa;
+/// use dsa_in_rust::algorithms::searching::regex::nfa::Nfa;
+/// use dsa_in_rust::algorithms::searching::regex::parser::parse;
+///
+/// let nfa = Nfa::compile(&parse("(a|b)*abb").unwrap());
+/// let dfa = Dfa::from_nfa(&nfa, true, 1000).unwrap().minimize();
+/// // The textbook four states plus a dead state for characters other than a and b
+/// assert_eq!(dfa.state_count(), 5);
+/// assert!(dfa.is_match("babb"));
+/// assert!(!dfa.is_match("abba"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Dfa {
+    /// Sorted interval starts; symbol i covers `[boundaries[i], boundaries[i + 1])`
+    boundaries: Vec<char>,
+    /// `transitions[state * symbol_count + symbol]`
+    transitions: Vec<usize>,
+    accepting: Vec<bool>,
+    accepting_at_end: Vec<bool>,
+    start: usize,
+    anchored: bool,
+}
+
+impl Dfa {
+    /// Builds a DFA from an NFA by subset construction
+    ///
+    /// # Arguments
+    /// * `nfa` - Automaton to determinize
+    /// * `anchored` - true to match whole texts, false to find a match anywhere
+    /// * `state_limit` - Maximum number of DFA states to create
+    ///
+    /// # Returns
+    /// * `Result<Dfa, RegexError>` - Err(DfaTooLarge) if the limit is exceeded
+    pub fn from_nfa(nfa: &Nfa, anchored: bool, state_limit: usize) -> Result<Self, RegexError> {
+        let boundaries = symbol_boundaries(nfa);
+        let symbol_count = boundaries.len();
+
+        let start_set = closure(nfa, &[nfa.start()], true, false);
+        let mut ids: HashMap<(Vec<usize>, bool), usize> = HashMap::new();
+        let mut sets: Vec<(Vec<usize>, bool)> = Vec::new();
+        let mut queue = VecDeque::new();
+        ids.insert((start_set.clone(), true), 0);
+        sets.push((start_set, true));
+        queue.push_back(0);
+
+        let mut transitions = Vec::new();
+        while let Some(id) = queue.pop_front() {
+            transitions.resize((id + 1) * symbol_count, 0);
+            for (symbol, &c) in boundaries.iter().enumerate() {
+                let key = (step(nfa, &sets[id].0, c, anchored), false);
+                let next = match ids.get(&key) {
+                    Some(&next) => next,
+                    None => {
+                        if sets.len() == state_limit {
+                            return Err(RegexError::DfaTooLarge(state_limit));
+                        }
+                        let next = sets.len();
+                        ids.insert(key.clone(), next);
+                        sets.push(key);
+                        queue.push_back(next);
+                        next
+                    }
+                };
+                transitions[id * symbol_count + symbol] = next;
+            }
+        }
+
+        let accepting = sets.iter().map(|(set, _)| set.contains(&0)).collect();
+        let accepting_at_end = sets.iter().map(|(set, initial)| accepts_at_end(nfa, set, *initial)).collect();
+
+        Ok(Self { boundaries, transitions, accepting, accepting_at_end, start: 0, anchored })
+    }
+
+    /// Returns the number of states
+    pub fn state_count(&self) -> usize {
+        self.accepting.len()
+    }
+
+    /// Returns the number of input symbols (character intervals)
+    pub fn symbol_count(&self) -> usize {
+        self.boundaries.len()
+    }
+
+    /// Runs the automaton over `text`
+    ///
+    /// # Returns
+    /// * `bool` - For anchored DFAs, true if the whole text matches; otherwise
+    ///   true if any substring matches
+    pub fn is_match(&self, text: &str) -> bool {
+        let mut state = self.start;
+        for c in text.chars() {
+            if !self.anchored && self.accepting[state] {
+                return true;
+            }
+            state = self.next(state, c);
+        }
+        self.accepting[state] || self.accepting_at_end[state]
+    }
+
+    fn next(&self, state: usize, c: char) -> usize {
+        let symbol = self.boundaries.partition_point(|&b| b <= c) - 1;
+        self.transitions[state * self.symbol_count() + symbol]
+    }
+
+    /// Returns the equivalent DFA with the fewest states (Hopcroft's algorithm)
+    pub fn minimize(&self) -> Self {
+        let n = self.state_count();
+        let k = self.symbol_count();
+
+        // inverse[symbol][target] lists the states moving to `target` on `symbol`
+        let mut inverse = vec![vec![Vec::new(); n]; k];
+        for state in 0..n {
+            for (symbol, edges) in inverse.iter_mut().enumerate() {
+                edges[self.transitions[state * k + symbol]].push(state);
+            }
+        }
+
+        // Initial partition by acceptance behaviour
+        let mut groups: HashMap<(bool, bool), Vec<usize>> = HashMap::new();
+        for state in 0..n {
+            groups.entry((self.accepting[state], self.accepting_at_end[state])).or_default().push(state);
+        }
+        let mut blocks: Vec<Vec<usize>> = groups.into_values().collect();
+        blocks.sort();
+        let mut block_of = vec![0; n];
+        for (id, block) in blocks.iter().enumerate() {
+            for &state in block {
+                block_of[state] = id;
+            }
+        }
+
+        let mut pending: Vec<Vec<bool>> = vec![vec![true; k]; blocks.len()];
+        let mut worklist: Vec<(usize, usize)> =
+            (0..blocks.len()).flat_map(|block| (0..k).map(move |symbol| (block, symbol))).collect();
+        let mut in_splitter = vec![false; n];
+
+        while let Some((splitter, symbol)) = worklist.pop() {
+            pending[splitter][symbol] = false;
+            let predecessors: Vec<usize> =
+                blocks[splitter].iter().flat_map(|&target| inverse[symbol][target].iter().copied()).collect();
+            for &state in &predecessors {
+                in_splitter[state] = true;
+            }
+
+            let mut touched: Vec<usize> = predecessors.iter().map(|&state| block_of[state]).collect();
+            touched.sort_unstable();
+            touched.dedup();
+            for block in touched {
+                let (inside, outside): (Vec<usize>, Vec<usize>) =
+                    blocks[block].iter().partition(|&&state| in_splitter[state]);
+                if outside.is_empty() {
+                    continue;
+                }
+                let new_block = blocks.len();
+                for &state in &outside {
+                    block_of[state] = new_block;
+                }
+                let inside_smaller = inside.len() <= outside.len();
+                blocks[block] = inside;
+                blocks.push(outside);
+                pending.push(vec![false; k]);
+                // A pending splitter must cover both halves; otherwise the smaller one suffices
+                let queue_for: Vec<usize> = pending[block]
+                    .iter()
+                    .map(|&queued| if queued || !inside_smaller { new_block } else { block })
+                    .collect();
+                for (s, queued) in queue_for.into_iter().enumerate() {
+                    if !pending[queued][s] {
+                        pending[queued][s] = true;
+                        worklist.push((queued, s));
+                    }
+                }
+            }
+
+            for &state in &predecessors {
+                in_splitter[state] = false;
+            }
+        }
+
+        // Renumber blocks so the start block comes first, in BFS order
+        let mut order = vec![usize::MAX; blocks.len()];
+        let mut queue = VecDeque::from([block_of[self.start]]);
+        order[block_of[self.start]] = 0;
+        let mut count = 1;
+        let mut reps = Vec::new();
+        while let Some(block) = queue.pop_front() {
+            let rep = blocks[block][0];
+            reps.push(rep);
+            for symbol in 0..k {
+                let target = block_of[self.transitions[rep * k + symbol]];
+                if order[target] == usize::MAX {
+                    order[target] = count;
+                    count += 1;
+                    queue.push_back(target);
+                }
+            }
+        }
+
+        let transitions = reps
+            .iter()
+            .flat_map(|&rep| (0..k).map(move |symbol| (rep, symbol)))
+            .map(|(rep, symbol)| order[block_of[self.transitions[rep * k + symbol]]])
+            .collect();
+        Self {
+            boundaries: self.boundaries.clone(),
+            transitions,
+            accepting: reps.iter().map(|&rep| self.accepting[rep]).collect(),
+            accepting_at_end: reps.iter().map(|&rep| self.accepting_at_end[rep]).collect(),
+            start: 0,
+            anchored: self.anchored,
+        }
+    }
+}
+
+/// Marks a transition of a `LazyDfa` that has not been computed yet
+const UNKNOWN: usize = usize::MAX;
+
+/// Deterministic automaton whose states are built on demand while matching
+///
+/// States are the same NFA state sets that `Dfa::from_nfa` creates, but only
+/// those the searched texts actually reach are built, and each transition is
+/// computed the first time it is taken. Built states are cached across
+/// searches; once `state_limit` states exist, a search that needs another one
+/// gives up and returns None so the caller can fall back to the NFA.
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::regex::dfa::LazyDfa;
+/// use dsa_in_rust::algorithms::searching::regex::nfa::Nfa;
+/// use dsa_in_rust::algorithms::searching::regex::parser::parse;
+///
+/// let nfa = Nfa::compile(&parse("(a|b)*abb").unwrap());
+/// let mut dfa = LazyDfa::new(&nfa, true, 1000);
+/// assert_eq!(dfa.state_count(), 0);
+/// assert_eq!(dfa.is_match_at(&nfa, "babb", 0), Some(true));
+/// assert_eq!(dfa.is_match_at(&nfa, "abba", 0), Some(false));
+/// assert!(dfa.state_count() <= 5);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LazyDfa {
+    /// Sorted interval starts; symbol i covers `[boundaries[i], boundaries[i + 1])`
+    boundaries: Vec<char>,
+    /// State ids by (NFA state set, built at the start of the text)
+    ids: HashMap<(Vec<usize>, bool), usize>,
+    sets: Vec<Vec<usize>>,
+    /// `transitions[state * symbol_count + symbol]`, `UNKNOWN` until first taken
+    transitions: Vec<usize>,
+    accepting: Vec<bool>,
+    accepting_at_end: Vec<bool>,
+    /// Start states for searches beginning at offset 0 and elsewhere
+    starts: [Option<usize>; 2],
+    anchored: bool,
+    state_limit: usize,
+}
+
+impl LazyDfa {
+    /// Creates an empty automaton for `nfa`
+    ///
+    /// # Arguments
+    /// * `nfa` - Automaton to determinize on demand
+    /// * `anchored` - true to match whole texts, false to find a match anywhere
+    /// * `state_limit` - Maximum number of DFA states to cache
+    pub fn new(nfa: &Nfa, anchored: bool, state_limit: usize) -> Self {
+        Self {
+            boundaries: symbol_boundaries(nfa),
+            ids: HashMap::new(),
+            sets: Vec::new(),
+            transitions: Vec::new(),
+            accepting: Vec::new(),
+            accepting_at_end: Vec::new(),
+            starts: [None; 2],
+            anchored,
+            state_limit,
+        }
+    }
+
+    /// Returns the number of states built so far
+    pub fn state_count(&self) -> usize {
+        self.sets.len()
+    }
+
+    /// Runs the automaton over `text[from..]`, building states as needed
+    ///
+    /// `^` holds only if `from` is 0, and `$` at the end of `text`.
+    ///
+    /// # Arguments
+    /// * `nfa` - The NFA this automaton was created for
+    /// * `text` - Text to search
+    /// * `from` - Byte offset to start at; must be a char boundary
+    ///
+    /// # Returns
+    /// * `Option<bool>` - For anchored automata, whether all of `text[from..]`
+    ///   matches; otherwise whether a match starts at or after `from`. None if
+    ///   the state limit was reached before the answer was known
+    pub fn is_match_at(&mut self, nfa: &Nfa, text: &str, from: usize) -> Option<bool> {
+        let mut state = self.start(nfa, from == 0)?;
+        for c in text[from..].chars() {
+            if !self.anchored && self.accepting[state] {
+                return Some(true);
+            }
+            state = self.next(nfa, state, c)?;
+        }
+        Some(self.accepting[state] || self.accepting_at_end[state])
+    }
+
+    fn start(&mut self, nfa: &Nfa, at_start: bool) -> Option<usize> {
+        if let Some(state) = self.starts[usize::from(at_start)] {
+            return Some(state);
+        }
+        let state = self.state(nfa, closure(nfa, &[nfa.start()], at_start, false), at_start)?;
+        self.starts[usize::from(at_start)] = Some(state);
+        Some(state)
+    }
+
+    fn next(&mut self, nfa: &Nfa, state: usize, c: char) -> Option<usize> {
+        let symbol = self.boundaries.partition_point(|&b| b <= c) - 1;
+        let slot = state * self.boundaries.len() + symbol;
+        if self.transitions[slot] == UNKNOWN {
+            let set = step(nfa, &self.sets[state], c, self.anchored);
+            self.transitions[slot] = self.state(nfa, set, false)?;
+        }
+        Some(self.transitions[slot])
+    }
+
+    /// Returns the id of the state for `set`, building it if it is new
+    fn state(&mut self, nfa: &Nfa, set: Vec<usize>, initial: bool) -> Option<usize> {
+        let key = (set, initial);
+        if let Some(&id) = self.ids.get(&key) {
+            return Some(id);
+        }
+        if self.sets.len() == self.state_limit {
+            return None;
+        }
+        let id = self.sets.len();
+        self.accepting.push(key.0.contains(&0));
+        self.accepting_at_end.push(accepts_at_end(nfa, &key.0, initial));
+        self.transitions.resize((id + 1) * self.boundaries.len(), UNKNOWN);
+        self.sets.push(key.0.clone());
+        self.ids.insert(key, id);
+        Some(id)
+    }
+}
+
+/// NFA states live after reading `c` in any state of `set`
+///
+/// Unanchored automata restart the NFA after every character, so they keep
+/// looking for a match that begins later.
+fn step(nfa: &Nfa, set: &[usize], c: char, anchored: bool) -> Vec<usize> {
+    let mut targets: Vec<usize> = set
+        .iter()
+        .filter_map(|&state| match &nfa.states()[state] {
+            NfaState::Class(class, target) if class.contains(c) => Some(*target),
+            _ => None,
+        })
+        .collect();
+    if !anchored {
+        targets.push(nfa.start());
+    }
+    closure(nfa, &targets, false, false)
+}
+
+/// Whether `set` accepts once the text is exhausted, resolving pending `$` edges
+fn accepts_at_end(nfa: &Nfa, set: &[usize], initial: bool) -> bool {
+    let pending: Vec<usize> = set
+        .iter()
+        .filter_map(|&state| match nfa.states()[state] {
+            NfaState::Assert(Anchor::End, target) => Some(target),
+            NfaState::Match => Some(state),
+            _ => None,
+        })
+        .collect();
+    closure(nfa, &pending, initial, true).contains(&0)
+}
+
+/// Splits the `char` range into intervals no NFA class partially covers
+fn symbol_boundaries(nfa: &Nfa) -> Vec<char> {
+    let mut boundaries = vec!['\0'];
+    for state in nfa.states() {
+        if let NfaState::Class(class, _) = state {
+            for &(low, high) in class.ranges() {
+                boundaries.push(low);
+                boundaries.extend(next_char(high));
+            }
+        }
+    }
+    boundaries.sort_unstable();
+    boundaries.dedup();
+    boundaries
+}
+
+/// Epsilon closure keeping only states that matter for transitions and acceptance
+///
+/// Unresolved `$` assertions are kept so acceptance at the end can be decided later.
+fn closure(nfa: &Nfa, seeds: &[usize], at_start: bool, at_end: bool) -> Vec<usize> {
+    let mut seen = vec![false; nfa.state_count()];
+    let mut stack = seeds.to_vec();
+    let mut kernel = Vec::new();
+    while let Some(state) = stack.pop() {
+        if std::mem::replace(&mut seen[state], true) {
+            continue;
+        }
+        match &nfa.states()[state] {
+            NfaState::Split(first, second) => stack.extend([*first, *second]),
+            NfaState::Assert(Anchor::Start, target) => {
+                if at_start {
+                    stack.push(*target);
+                }
+            }
+            NfaState::Assert(Anchor::End, target) => {
+                if at_end {
+                    stack.push(*target);
+                } else {
+                    kernel.push(state);
+                }
+            }
+            NfaState::Class(..) | NfaState::Match => kernel.push(state),
+        }
+    }
+    kernel.sort_unstable();
+    kernel
+}
@@ -0,0 +1,210 @@
+//! Regex Front End
+//!
+//! DEV NOTES:
+//! - `Regex::new` only parses and builds the Thompson NFA; no DFA is built
+//!   up front
+//! - Two lazy DFAs (search and whole-text) build their states while matching
+//!   and cache them in the `Regex` for later calls, up to a state limit
+//!   (`DFA_STATE_LIMIT` by default, see `Regex::dfa_state_limit`); a query
+//!   that would need more states falls back to NFA simulation
+//! - Yes/no questions (`is_match`, `is_full_match`) run on the lazy DFAs;
+//!   `find_iter` asks the search DFA whether any match is left before each
+//!   NFA search, so the tail of the text after the last match is scanned once
+//!   by the DFA rather than by the NFA; match positions come from the NFA
+//! - A longest-match search may scan past its match end (`a|a.*b` over
+//!   `aaaa...` looks for a `b` to the end of the text), so restarting it after
+//!   every match would be quadratic. After the first match, `find_iter`
+//!   computes the NFA's liveness for the text in one backward pass; later
+//!   searches drop dead threads and stop at their match end
+//! - The caches sit behind a `Mutex`, so a `Regex` can be shared between
+//!   threads; concurrent queries on one `Regex` take turns
+//! - Matches are leftmost-longest and non-overlapping; an empty match moves
+//!   the next search one character forward
+//! - Offsets are byte offsets into the text, always on char boundaries
+//!
+//! PERFORMANCE CONSIDERATIONS:
+//! - `Regex::new`: O(m) for pattern size m
+//! - `is_match` / `is_full_match`: O(n) once the states a text needs are
+//!   cached, O(n * m) when building them or on the NFA fallback
+//! - `find`: O(n * m), independent of the pattern's shape
+//! - `find_iter`: O(n * m) for the whole iteration, plus O(n) memory for the
+//!   liveness ids (one per byte) and O(m) per distinct live set
+
+use super::dfa::LazyDfa;
+use super::nfa::{Liveness, Nfa};
+use super::parser::{parse, RegexError};
+use std::ops::Range;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// Default number of states each lazy DFA caches before falling back to the NFA
+pub const DFA_STATE_LIMIT: usize = 10_000;
+
+/// Compiled regular expression
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::regex::engine::Regex;
+///
+/// let re = Regex::new(r"\d+(\.\d+)?").unwrap();
+/// let found: Vec<&str> = re.find_iter("pi is 3.14, e is 2.718, n = 42").map(|m| m.as_str()).collect();
+/// assert_eq!(found, ["3.14", "2.718", "42"]);
+/// assert!(re.is_match("v2"));
+/// assert!(Regex::new("^[a-z]+$").unwrap().is_full_match("hello"));
+/// ```
+#[derive(Debug)]
+pub struct Regex {
+    pattern: String,
+    nfa: Nfa,
+    /// Finds a match anywhere
+    search_dfa: Mutex<LazyDfa>,
+    /// Matches whole texts
+    full_dfa: Mutex<LazyDfa>,
+}
+
+impl Clone for Regex {
+    fn clone(&self) -> Self {
+        Self {
+            pattern: self.pattern.clone(),
+            nfa: self.nfa.clone(),
+            search_dfa: Mutex::new(lock(&self.search_dfa).clone()),
+            full_dfa: Mutex::new(lock(&self.full_dfa).clone()),
+        }
+    }
+}
+
+/// A single match: a byte range of the searched text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match<'t> {
+    text: &'t str,
+    start: usize,
+    end: usize,
+}
+
+impl<'t> Match<'t> {
+    /// Byte offset of the start of the match
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Byte offset one past the end of the match
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Byte range of the match
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Matched text
+    pub fn as_str(&self) -> &'t str {
+        &self.text[self.start..self.end]
+    }
+}
+
+impl Regex {
+    /// Compiles a pattern
+    ///
+    /// # Returns
+    /// * `Result<Regex, RegexError>` - Err on a syntax error
+    pub fn new(pattern: &str) -> Result<Self, RegexError> {
+        let nfa = Nfa::compile(&parse(pattern)?);
+        let search_dfa = Mutex::new(LazyDfa::new(&nfa, false, DFA_STATE_LIMIT));
+        let full_dfa = Mutex::new(LazyDfa::new(&nfa, true, DFA_STATE_LIMIT));
+        Ok(Self { pattern: pattern.to_string(), nfa, search_dfa, full_dfa })
+    }
+
+    /// Sets how many states each lazy DFA may cache, dropping cached states
+    ///
+    /// Queries that need more states fall back to NFA simulation; a limit of
+    /// 0 disables the DFAs entirely.
+    pub fn dfa_state_limit(self, limit: usize) -> Self {
+        let search_dfa = Mutex::new(LazyDfa::new(&self.nfa, false, limit));
+        let full_dfa = Mutex::new(LazyDfa::new(&self.nfa, true, limit));
+        Self { search_dfa, full_dfa, ..self }
+    }
+
+    /// Returns the source pattern
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Returns the Thompson NFA
+    pub fn nfa(&self) -> &Nfa {
+        &self.nfa
+    }
+
+    /// Returns the number of DFA states cached so far by both lazy DFAs
+    pub fn dfa_state_count(&self) -> usize {
+        lock(&self.search_dfa).state_count() + lock(&self.full_dfa).state_count()
+    }
+
+    /// Returns true if the pattern matches anywhere in `text`
+    pub fn is_match(&self, text: &str) -> bool {
+        let answer = lock(&self.search_dfa).is_match_at(&self.nfa, text, 0);
+        answer.unwrap_or_else(|| self.nfa.find_at(text, 0, false).is_some())
+    }
+
+    /// Returns true if the pattern matches all of `text`
+    pub fn is_full_match(&self, text: &str) -> bool {
+        let answer = lock(&self.full_dfa).is_match_at(&self.nfa, text, 0);
+        answer.unwrap_or_else(|| self.nfa.find_at(text, 0, true).is_some_and(|(_, end)| end == text.len()))
+    }
+
+    /// Finds the leftmost-longest match
+    pub fn find<'t>(&self, text: &'t str) -> Option<Match<'t>> {
+        self.find_iter(text).next()
+    }
+
+    /// Returns an iterator over all non-overlapping matches
+    pub fn find_iter<'r, 't>(&'r self, text: &'t str) -> Matches<'r, 't> {
+        Matches { regex: self, text, position: 0, live: None }
+    }
+}
+
+/// Locks a DFA cache, recovering it if a query on another thread panicked
+fn lock(dfa: &Mutex<LazyDfa>) -> MutexGuard<'_, LazyDfa> {
+    dfa.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Iterator over matches, created by `Regex::find_iter`
+#[derive(Debug, Clone)]
+pub struct Matches<'r, 't> {
+    regex: &'r Regex,
+    text: &'t str,
+    position: usize,
+    /// Built before the second search, so it and later ones stop at their match end
+    live: Option<Liveness>,
+}
+
+impl<'t> Iterator for Matches<'_, 't> {
+    type Item = Match<'t>;
+
+    fn next(&mut self) -> Option<Match<'t>> {
+        if self.position > self.text.len() {
+            return None;
+        }
+        // The DFA rules out a fruitless NFA search over the rest of the text
+        let answer = lock(&self.regex.search_dfa).is_match_at(&self.regex.nfa, self.text, self.position);
+        if answer == Some(false) {
+            self.position = self.text.len() + 1;
+            return None;
+        }
+        let nfa = &self.regex.nfa;
+        // Only a second search can rescan text, so a lone `find` skips the liveness pass
+        if self.live.is_none() && self.position > 0 {
+            self.live = Some(nfa.liveness(self.text));
+        }
+        let (start, end) = match &self.live {
+            Some(live) => nfa.find_at_live(self.text, self.position, live),
+            None => nfa.find_at(self.text, self.position, false),
+        }?;
+        self.position = if start == end {
+            // Step over one character so an empty match is not found again
+            end + self.text[end..].chars().next().map_or(1, char::len_utf8)
+        } else {
+            end
+        };
+        Some(Match { text: self.text, start, end })
+    }
+}
@@ -0,0 +1,322 @@
+//! Thompson NFA Construction and Simulation
+//!
+//! DEV NOTES:
+//! - Thompson's construction: every AST node becomes a constant number of
+//!   states joined by epsilon (`Split`) edges, so the NFA has O(m) states for
+//!   a pattern of length m
+//! - Compiled back to front: each node is compiled knowing the state that
+//!   follows it, which avoids patching dangling edges
+//! - Simulation keeps the set of live states per text position (a Pike VM)
+//!   with the start offset of the thread that reached each state; when two
+//!   threads meet, the earlier start wins since both share the same future
+//! - Matches are leftmost-longest (POSIX): the earliest start, then the
+//!   longest end. No backtracking, so each search is O(n * m)
+//! - A longest-match search cannot stop at its match end on its own: threads
+//!   that will never match again run on, possibly to the end of the text, so
+//!   repeated searches could rescan the same text. `liveness` records, in one
+//!   backward pass, which states can still reach a match at each position;
+//!   searches given it drop dead threads and stop right at the match end
+
+use super::parser::{Anchor, Ast, CharClass};
+use std::collections::HashMap;
+
+/// One NFA state
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NfaState {
+    /// Consumes one character in the class, then continues at `next`
+    Class(CharClass, usize),
+    /// Epsilon edges to both targets
+    Split(usize, usize),
+    /// Epsilon edge taken only where the anchor holds
+    Assert(Anchor, usize),
+    /// Accepting state
+    Match,
+}
+
+/// Thompson NFA
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::regex::nfa::Nfa;
+/// use dsa_in_rust::algorithms::searching::regex::parser::parse;
+///
+/// let nfa = Nfa::compile(&parse("a(b|c)*d").unwrap());
+/// assert_eq!(nfa.find_at("xxabcbdyy", 0, false), Some((2, 7)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Nfa {
+    states: Vec<NfaState>,
+    start: usize,
+}
+
+impl Nfa {
+    /// Compiles an AST into an NFA
+    ///
+    /// Recurses once per level of the AST; trees from `parse` are at most
+    /// `NESTING_LIMIT` levels high.
+    pub fn compile(ast: &Ast) -> Self {
+        let mut states = vec![NfaState::Match];
+        let start = compile_node(ast, 0, &mut states);
+        Self { states, start }
+    }
+
+    /// Returns the states; index 0 is the accepting state
+    pub fn states(&self) -> &[NfaState] {
+        &self.states
+    }
+
+    /// Returns the start state
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the number of states
+    pub fn state_count(&self) -> usize {
+        self.states.len()
+    }
+
+    /// Finds the leftmost-longest match starting at or after `from`
+    ///
+    /// # Arguments
+    /// * `text` - Text to search
+    /// * `from` - Byte offset to start at; must be a char boundary
+    /// * `anchored` - If true, only matches starting exactly at `from` count
+    ///
+    /// # Returns
+    /// * `Option<(usize, usize)>` - Byte range of the match
+    pub fn find_at(&self, text: &str, from: usize, anchored: bool) -> Option<(usize, usize)> {
+        self.search(text, from, anchored, None)
+    }
+
+    /// Like `find_at`, but drops threads that `live` marks as unable to match
+    ///
+    /// The search then ends at the match end instead of running on, so a
+    /// sequence of searches over one text costs O(n * m) in total.
+    pub(crate) fn find_at_live(&self, text: &str, from: usize, live: &Liveness) -> Option<(usize, usize)> {
+        self.search(text, from, false, Some(live))
+    }
+
+    /// Computes which states can still reach a match at each position of `text`
+    ///
+    /// Walks the text backwards: a `Class` state is live before a character
+    /// if it accepts the character and its target reaches, through epsilon
+    /// edges that hold at the next position, a live state or `Match`.
+    ///
+    /// # Performance
+    /// * Time Complexity: O(n * m)
+    /// * Space Complexity: O(n) ids plus O(m) per distinct live set
+    pub(crate) fn liveness(&self, text: &str) -> Liveness {
+        // Epsilon edges reversed, with the anchor each one needs
+        let mut predecessors: Vec<Vec<(usize, Option<Anchor>)>> = vec![Vec::new(); self.states.len()];
+        for (state, kind) in self.states.iter().enumerate() {
+            match kind {
+                NfaState::Split(first, second) => {
+                    predecessors[*first].push((state, None));
+                    predecessors[*second].push((state, None));
+                }
+                NfaState::Assert(anchor, target) => predecessors[*target].push((state, Some(*anchor))),
+                NfaState::Class(..) | NfaState::Match => {}
+            }
+        }
+
+        let mut interned: HashMap<Vec<bool>, u32> = HashMap::new();
+        let mut live = Liveness { ids: vec![0; text.len() + 1], sets: Vec::new() };
+        let mut intern = |set: Vec<bool>, live: &mut Liveness| -> u32 {
+            *interned.entry(set).or_insert_with_key(|set| {
+                live.sets.push(set.clone());
+                (live.sets.len() - 1) as u32
+            })
+        };
+
+        let mut entries = vec![false; self.states.len()];
+        entries[0] = true;
+        live.ids[text.len()] = intern(entries.clone(), &mut live);
+        let mut reaches = vec![false; self.states.len()];
+        let mut stack = Vec::new();
+        for (position, c) in text.char_indices().rev() {
+            let after = position + c.len_utf8();
+            // States whose epsilon closure at `after` contains a live entry
+            reaches.fill(false);
+            stack.extend((0..self.states.len()).filter(|&state| entries[state]));
+            while let Some(state) = stack.pop() {
+                if std::mem::replace(&mut reaches[state], true) {
+                    continue;
+                }
+                for &(predecessor, anchor) in &predecessors[state] {
+                    let holds = match anchor {
+                        None => true,
+                        Some(Anchor::Start) => after == 0,
+                        Some(Anchor::End) => after == text.len(),
+                    };
+                    if holds && !reaches[predecessor] {
+                        stack.push(predecessor);
+                    }
+                }
+            }
+            for (state, kind) in self.states.iter().enumerate() {
+                entries[state] = match kind {
+                    NfaState::Class(class, target) => class.contains(c) && reaches[*target],
+                    NfaState::Match => true,
+                    _ => false,
+                };
+            }
+            live.ids[position] = intern(entries.clone(), &mut live);
+        }
+        live
+    }
+
+    fn search(&self, text: &str, from: usize, anchored: bool, live: Option<&Liveness>) -> Option<(usize, usize)> {
+        let mut current = ThreadList::new(self.states.len());
+        let mut next = ThreadList::new(self.states.len());
+        let mut best: Option<(usize, usize)> = None;
+
+        let mut chars = text[from..].char_indices().map(|(i, c)| (from + i, c));
+        let mut position = from;
+        loop {
+            if best.is_none() && (!anchored || position == from) {
+                self.add_thread(&mut current, self.start, position, position, text.len());
+            }
+            if let Some(live) = live {
+                current.threads.retain(|&(state, _)| live.is_live(position, state));
+            }
+            for &(state, start) in &current.threads {
+                if self.states[state] == NfaState::Match
+                    && best.is_none_or(|(s, e)| start < s || (start == s && position > e))
+                {
+                    best = Some((start, position));
+                }
+            }
+            // Threads starting after the best match can never beat it
+            if let Some((best_start, _)) = best {
+                current.threads.retain(|&(_, start)| start <= best_start);
+            }
+
+            let Some((offset, c)) = chars.next() else { break };
+            debug_assert_eq!(offset, position);
+            let after = position + c.len_utf8();
+            next.clear();
+            for &(state, start) in &current.threads {
+                if let NfaState::Class(class, target) = &self.states[state] {
+                    if class.contains(c) {
+                        self.add_thread(&mut next, *target, start, after, text.len());
+                    }
+                }
+            }
+            std::mem::swap(&mut current, &mut next);
+            position = after;
+
+            if current.threads.is_empty() && (best.is_some() || anchored) {
+                break;
+            }
+        }
+        best
+    }
+
+    /// Adds `state` and everything reachable from it by epsilon edges
+    fn add_thread(&self, list: &mut ThreadList, state: usize, start: usize, position: usize, len: usize) {
+        let mut stack = vec![state];
+        while let Some(state) = stack.pop() {
+            if !list.insert_state(state) {
+                continue;
+            }
+            match &self.states[state] {
+                NfaState::Split(first, second) => {
+                    stack.push(*second);
+                    stack.push(*first);
+                }
+                NfaState::Assert(Anchor::Start, target) if position == 0 => stack.push(*target),
+                NfaState::Assert(Anchor::End, target) if position == len => stack.push(*target),
+                NfaState::Assert(..) => {}
+                NfaState::Class(..) | NfaState::Match => list.threads.push((state, start)),
+            }
+        }
+    }
+}
+
+/// Per-position sets of NFA states that can still reach a match
+///
+/// Created by `Nfa::liveness` for one text; positions are byte offsets.
+/// Equal sets are stored once, so texts with repetitive structure need
+/// little more than one id per byte.
+#[derive(Debug, Clone)]
+pub(crate) struct Liveness {
+    ids: Vec<u32>,
+    sets: Vec<Vec<bool>>,
+}
+
+impl Liveness {
+    /// Returns true if a thread in `state` at `position` can still reach `Match`
+    fn is_live(&self, position: usize, state: usize) -> bool {
+        self.sets[self.ids[position] as usize][state]
+    }
+}
+
+/// Live threads for one text position, deduplicated by state
+struct ThreadList {
+    threads: Vec<(usize, usize)>,
+    /// `generation` marks states already added in this round
+    seen: Vec<u32>,
+    generation: u32,
+}
+
+impl ThreadList {
+    fn new(state_count: usize) -> Self {
+        Self { threads: Vec::new(), seen: vec![0; state_count], generation: 1 }
+    }
+
+    fn clear(&mut self) {
+        self.threads.clear();
+        self.generation += 1;
+        if self.generation == u32::MAX {
+            self.seen.fill(0);
+            self.generation = 1;
+        }
+    }
+
+    /// Marks `state`; returns false if it was already present
+    fn insert_state(&mut self, state: usize) -> bool {
+        if self.seen[state] == self.generation {
+            return false;
+        }
+        self.seen[state] = self.generation;
+        true
+    }
+}
+
+/// Compiles `ast` so that a successful match continues at `next`; returns its entry state
+fn compile_node(ast: &Ast, next: usize, states: &mut Vec<NfaState>) -> usize {
+    let push = |states: &mut Vec<NfaState>, state: NfaState| {
+        states.push(state);
+        states.len() - 1
+    };
+    match ast {
+        Ast::Empty => next,
+        Ast::Class(class) => push(states, NfaState::Class(class.clone(), next)),
+        Ast::Anchor(anchor) => push(states, NfaState::Assert(*anchor, next)),
+        Ast::Concat(items) => items.iter().rev().fold(next, |follow, item| compile_node(item, follow, states)),
+        Ast::Alternate(branches) => {
+            let entries: Vec<usize> = branches.iter().map(|branch| compile_node(branch, next, states)).collect();
+            entries
+                .into_iter()
+                .rev()
+                .reduce(|rest, entry| push(states, NfaState::Split(entry, rest)))
+                .expect("alternation has branches")
+        }
+        Ast::Star(inner) => {
+            let split = push(states, NfaState::Match);
+            let body = compile_node(inner, split, states);
+            states[split] = NfaState::Split(body, next);
+            split
+        }
+        Ast::Plus(inner) => {
+            let split = push(states, NfaState::Match);
+            let body = compile_node(inner, split, states);
+            states[split] = NfaState::Split(body, next);
+            body
+        }
+        Ast::Optional(inner) => {
+            let body = compile_node(inner, next, states);
+            push(states, NfaState::Split(body, next))
+        }
+    }
+}
@@ -0,0 +1,375 @@
+//! Regular Expression Parser
+//!
+//! DEV NOTES:
+//! - Recursive descent over the grammar
+//!   `alternation := concat ('|' concat)*`,
+//!   `concat := repeat*`,
+//!   `repeat := atom ('*' | '+' | '?')*`,
+//!   `atom := literal | '.' | class | '(' alternation ')' | '^' | '$' | escape`
+//! - Supported escapes: `\d \w \s` and their negations `\D \W \S`, `\n \t \r`,
+//!   and any punctuation escaped to stand for itself
+//! - `.` matches any character except `\n`; `^` and `$` anchor to the start
+//!   and end of the whole text
+//! - Groups only group; there are no captures
+//! - Error positions are character offsets into the pattern
+//! - Parsing and NFA compilation recurse once per nesting level, so both group
+//!   nesting and the height of the syntax tree (groups, stacked quantifiers,
+//!   alternations) are capped at `NESTING_LIMIT`; deeper patterns are
+//!   rejected with `RegexError::TooDeep` instead of overflowing the stack
+
+use std::error::Error;
+use std::fmt;
+
+/// Deepest group nesting and syntax tree height that `parse` accepts
+pub const NESTING_LIMIT: usize = 250;
+
+/// Custom error type for regex compilation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegexError {
+    /// The pattern ended inside a group, class or escape
+    UnexpectedEnd,
+    /// A `)` without a matching `(`
+    UnmatchedParen(usize),
+    /// A quantifier with nothing before it
+    NothingToRepeat(usize),
+    /// An escape sequence that is not supported
+    InvalidEscape(usize),
+    /// A class range whose start is after its end, like `[z-a]`
+    InvalidRange(usize),
+    /// Subset construction exceeded the state limit
+    DfaTooLarge(usize),
+    /// Nesting deeper than `NESTING_LIMIT` at this position
+    TooDeep(usize),
+}
+
+impl fmt::Display for RegexError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RegexError::UnexpectedEnd => write!(f, "Unexpected end of pattern"),
+            RegexError::UnmatchedParen(at) => write!(f, "Unmatched ')' at position {}", at),
+            RegexError::NothingToRepeat(at) => write!(f, "Quantifier without operand at position {}", at),
+            RegexError::InvalidEscape(at) => write!(f, "Unsupported escape at position {}", at),
+            RegexError::InvalidRange(at) => write!(f, "Invalid class range at position {}", at),
+            RegexError::DfaTooLarge(limit) => write!(f, "DFA exceeds {} states", limit),
+            RegexError::TooDeep(at) => write!(f, "Pattern nested too deeply at position {}", at),
+        }
+    }
+}
+
+impl Error for RegexError {}
+
+/// A set of characters stored as sorted, disjoint, non-adjacent inclusive ranges
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CharClass {
+    ranges: Vec<(char, char)>,
+}
+
+impl CharClass {
+    /// Builds a class from arbitrary ranges, normalizing overlaps
+    pub fn new(mut ranges: Vec<(char, char)>) -> Self {
+        ranges.sort_unstable();
+        let mut merged: Vec<(char, char)> = Vec::with_capacity(ranges.len());
+        for (low, high) in ranges {
+            match merged.last_mut() {
+                Some(last) if next_char(last.1).is_none_or(|next| low <= next) => last.1 = last.1.max(high),
+                _ => merged.push((low, high)),
+            }
+        }
+        Self { ranges: merged }
+    }
+
+    /// Class holding a single character
+    pub fn single(c: char) -> Self {
+        Self { ranges: vec![(c, c)] }
+    }
+
+    /// Returns the ranges in ascending order
+    pub fn ranges(&self) -> &[(char, char)] {
+        &self.ranges
+    }
+
+    /// Returns true if `c` is in the class
+    pub fn contains(&self, c: char) -> bool {
+        self.ranges
+            .binary_search_by(|&(low, high)| {
+                if high < c {
+                    std::cmp::Ordering::Less
+                } else if low > c {
+                    std::cmp::Ordering::Greater
+                } else {
+                    std::cmp::Ordering::Equal
+                }
+            })
+            .is_ok()
+    }
+
+    /// Returns every character not in the class
+    pub fn negate(&self) -> Self {
+        let mut ranges = Vec::new();
+        let mut next = Some('\0');
+        for &(low, high) in &self.ranges {
+            if let Some(start) = next {
+                if start < low {
+                    ranges.push((start, prev_char(low).expect("low is above start")));
+                }
+            }
+            next = next_char(high);
+        }
+        if let Some(start) = next {
+            ranges.push((start, char::MAX));
+        }
+        Self { ranges }
+    }
+}
+
+/// Returns the next valid `char`, skipping the surrogate gap
+pub(crate) fn next_char(c: char) -> Option<char> {
+    match c {
+        '\u{d7ff}' => Some('\u{e000}'),
+        char::MAX => None,
+        _ => char::from_u32(c as u32 + 1),
+    }
+}
+
+/// Returns the previous valid `char`, skipping the surrogate gap
+fn prev_char(c: char) -> Option<char> {
+    match c {
+        '\u{e000}' => Some('\u{d7ff}'),
+        '\0' => None,
+        _ => char::from_u32(c as u32 - 1),
+    }
+}
+
+/// Position assertions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Anchor {
+    /// `^`: start of the text
+    Start,
+    /// `$`: end of the text
+    End,
+}
+
+/// Abstract syntax tree of a regular expression
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Ast {
+    /// Matches the empty string
+    Empty,
+    /// Matches one character from the class
+    Class(CharClass),
+    /// Matches the empty string at a position satisfying the anchor
+    Anchor(Anchor),
+    /// Matches the children one after another
+    Concat(Vec<Ast>),
+    /// Matches any one of the children
+    Alternate(Vec<Ast>),
+    /// `*`: zero or more
+    Star(Box<Ast>),
+    /// `+`: one or more
+    Plus(Box<Ast>),
+    /// `?`: zero or one
+    Optional(Box<Ast>),
+}
+
+/// Parses a pattern into an `Ast`
+///
+/// # Arguments
+/// * `pattern` - Regular expression source
+///
+/// # Returns
+/// * `Result<Ast, RegexError>` - The syntax tree, or the first syntax error
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::regex::parser::{parse, Ast, RegexError};
+///
+/// assert!(matches!(parse("a(b|c)*").unwrap(), Ast::Concat(_)));
+/// assert_eq!(parse("a)").unwrap_err(), RegexError::UnmatchedParen(1));
+/// assert_eq!(parse("*a").unwrap_err(), RegexError::NothingToRepeat(0));
+/// assert_eq!(parse(&"(".repeat(300)).unwrap_err(), RegexError::TooDeep(250));
+/// ```
+pub fn parse(pattern: &str) -> Result<Ast, RegexError> {
+    let mut parser = Parser { chars: pattern.chars().collect(), position: 0, groups: 0 };
+    let (ast, _) = parser.alternation()?;
+    match parser.peek() {
+        None => Ok(ast),
+        Some(_) => Err(RegexError::UnmatchedParen(parser.position)),
+    }
+}
+
+/// A parsed subtree and its height
+type Parsed = (Ast, usize);
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+    /// Groups open at the current position
+    groups: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn bump(&mut self) -> Result<char, RegexError> {
+        let c = self.peek().ok_or(RegexError::UnexpectedEnd)?;
+        self.position += 1;
+        Ok(c)
+    }
+
+    /// Height of a node above a child of `height`; Err(TooDeep(at)) past `NESTING_LIMIT`
+    fn nest(&self, height: usize, at: usize) -> Result<usize, RegexError> {
+        if height >= NESTING_LIMIT {
+            return Err(RegexError::TooDeep(at));
+        }
+        Ok(height + 1)
+    }
+
+    fn alternation(&mut self) -> Result<Parsed, RegexError> {
+        let at = self.position;
+        let (first, mut height) = self.concat()?;
+        let mut branches = vec![first];
+        while self.peek() == Some('|') {
+            self.position += 1;
+            let (branch, branch_height) = self.concat()?;
+            branches.push(branch);
+            height = height.max(branch_height);
+        }
+        if branches.len() == 1 {
+            return Ok((branches.pop().expect("one branch"), height));
+        }
+        Ok((Ast::Alternate(branches), self.nest(height, at)?))
+    }
+
+    fn concat(&mut self) -> Result<Parsed, RegexError> {
+        let at = self.position;
+        let mut items = Vec::new();
+        let mut height = 0;
+        while let Some(c) = self.peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            let (item, item_height) = self.repeat()?;
+            items.push(item);
+            height = height.max(item_height);
+        }
+        Ok(match items.len() {
+            0 => (Ast::Empty, 1),
+            1 => (items.pop().expect("one item"), height),
+            _ => (Ast::Concat(items), self.nest(height, at)?),
+        })
+    }
+
+    fn repeat(&mut self) -> Result<Parsed, RegexError> {
+        let (mut ast, mut height) = self.atom()?;
+        while let Some(op @ ('*' | '+' | '?')) = self.peek() {
+            if matches!(ast, Ast::Anchor(_)) {
+                return Err(RegexError::NothingToRepeat(self.position));
+            }
+            height = self.nest(height, self.position)?;
+            self.position += 1;
+            ast = match op {
+                '*' => Ast::Star(Box::new(ast)),
+                '+' => Ast::Plus(Box::new(ast)),
+                _ => Ast::Optional(Box::new(ast)),
+            };
+        }
+        Ok((ast, height))
+    }
+
+    fn atom(&mut self) -> Result<Parsed, RegexError> {
+        let at = self.position;
+        let ast = match self.bump()? {
+            '(' => {
+                if self.groups == NESTING_LIMIT {
+                    return Err(RegexError::TooDeep(at));
+                }
+                self.groups += 1;
+                let inner = self.alternation()?;
+                self.groups -= 1;
+                return match self.bump()? {
+                    ')' => Ok(inner),
+                    _ => Err(RegexError::UnexpectedEnd),
+                };
+            }
+            '*' | '+' | '?' => return Err(RegexError::NothingToRepeat(at)),
+            '^' => Ast::Anchor(Anchor::Start),
+            '$' => Ast::Anchor(Anchor::End),
+            '.' => Ast::Class(CharClass::single('\n').negate()),
+            '[' => self.class()?,
+            '\\' => Ast::Class(self.escape()?),
+            c => Ast::Class(CharClass::single(c)),
+        };
+        Ok((ast, 1))
+    }
+
+    /// Parses a bracketed class after the opening `[`
+    fn class(&mut self) -> Result<Ast, RegexError> {
+        let negated = self.peek() == Some('^');
+        if negated {
+            self.position += 1;
+        }
+        let mut ranges = Vec::new();
+        let mut first = true;
+        loop {
+            let at = self.position;
+            let low = match self.bump()? {
+                // A leading ']' is a literal
+                ']' if !first => break,
+                '\\' => {
+                    let class = self.escape()?;
+                    match class.ranges() {
+                        &[(c, d)] if c == d => c,
+                        _ => {
+                            ranges.extend_from_slice(class.ranges());
+                            first = false;
+                            continue;
+                        }
+                    }
+                }
+                c => c,
+            };
+            first = false;
+
+            if self.peek() == Some('-') && self.chars.get(self.position + 1).is_some_and(|&c| c != ']') {
+                self.position += 1;
+                let high = match self.bump()? {
+                    '\\' => match self.escape()?.ranges() {
+                        &[(c, d)] if c == d => c,
+                        _ => return Err(RegexError::InvalidRange(at)),
+                    },
+                    c => c,
+                };
+                if low > high {
+                    return Err(RegexError::InvalidRange(at));
+                }
+                ranges.push((low, high));
+            } else {
+                ranges.push((low, low));
+            }
+        }
+        let class = CharClass::new(ranges);
+        Ok(Ast::Class(if negated { class.negate() } else { class }))
+    }
+
+    /// Parses an escape after the backslash
+    fn escape(&mut self) -> Result<CharClass, RegexError> {
+        let at = self.position - 1;
+        let digit = || CharClass::new(vec![('0', '9')]);
+        let word = || CharClass::new(vec![('0', '9'), ('A', 'Z'), ('_', '_'), ('a', 'z')]);
+        let space = || CharClass::new(vec![('\t', '\r'), (' ', ' ')]);
+        Ok(match self.bump()? {
+            'd' => digit(),
+            'D' => digit().negate(),
+            'w' => word(),
+            'W' => word().negate(),
+            's' => space(),
+            'S' => space().negate(),
+            'n' => CharClass::single('\n'),
+            't' => CharClass::single('\t'),
+            'r' => CharClass::single('\r'),
+            c if c.is_ascii_punctuation() => CharClass::single(c),
+            _ => return Err(RegexError::InvalidEscape(at)),
+        })
+    }
+}
@@ -0,0 +1,573 @@
+//! Aho–Corasick Multi-Pattern Matching
+//!
+//! DEV NOTES:
+//! - Byte-oriented: patterns and haystacks are anything `AsRef<[u8]>`, so
+//!   `&str`, `String` and `&[u8]` all work and offsets are byte offsets
+//! - The automaton is a trie with failure links; each state also keeps a
+//!   dictionary link to the nearest proper suffix state that ends a pattern
+//! - Match semantics (`MatchKind`):
+//!   - `LeftmostFirst`: earliest start wins, ties go to the lowest pattern index
+//!   - `LeftmostLongest`: earliest start wins, ties go to the longest pattern
+//!   - `Overlapping`: every occurrence of every pattern, ordered by end position
+//! - Leftmost matches are resolved while scanning: once the current state can
+//!   no longer extend to a match starting at or before the best candidate, the
+//!   candidate is final. No extra automaton is needed per match kind.
+//! - The default mode follows failure links at search time. The DFA mode
+//!   precomputes every transition into a dense table over byte classes
+//!   (bytes that never occur in a pattern share one class), trading build time
+//!   and memory for one table lookup per haystack byte
+//! - ASCII case-insensitive mode folds patterns at build time and haystack
+//!   bytes at search time; in DFA mode the folding is baked into the class map
+//!
+//! PERFORMANCE CONSIDERATIONS:
+//! - Build: O(total pattern length) for the trie, plus O(states * classes) for the DFA
+//! - Search: O(n + matches); leftmost semantics may re-scan at most the longest
+//!   pattern length per reported match
+//! - Streaming keeps only the bytes that can still belong to an unreported match
+
+use super::matcher::PatternError;
+use std::collections::VecDeque;
+use std::io::{self, ErrorKind, Read};
+
+const ROOT: usize = 0;
+const CHUNK_SIZE: usize = 8 * 1024;
+
+/// Which matches a search reports
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MatchKind {
+    /// Non-overlapping; earliest start, then lowest pattern index
+    #[default]
+    LeftmostFirst,
+    /// Non-overlapping; earliest start, then longest pattern
+    LeftmostLongest,
+    /// All matches, including overlapping ones
+    Overlapping,
+}
+
+/// A single pattern occurrence
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Match {
+    /// Index of the pattern in the list given to the builder
+    pub pattern: usize,
+    /// Byte offset of the first byte of the match
+    pub start: usize,
+    /// Byte offset one past the last byte of the match
+    pub end: usize,
+}
+
+/// Builder for `AhoCorasick` automata
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::string::aho_corasick::{AhoCorasickBuilder, MatchKind};
+///
+/// let automaton = AhoCorasickBuilder::new()
+///     .match_kind(MatchKind::LeftmostLongest)
+///     .ascii_case_insensitive(true)
+///     .dfa(true)
+///     .build(["sam", "Samwise"])
+///     .unwrap();
+/// let found = automaton.find("SAMWISE the brave").unwrap();
+/// assert_eq!((found.pattern, found.start, found.end), (1, 0, 7));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AhoCorasickBuilder {
+    match_kind: MatchKind,
+    ascii_case_insensitive: bool,
+    dfa: bool,
+}
+
+impl AhoCorasickBuilder {
+    /// Creates a builder with leftmost-first semantics, case-sensitive, no DFA
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the match semantics
+    pub fn match_kind(mut self, kind: MatchKind) -> Self {
+        self.match_kind = kind;
+        self
+    }
+
+    /// Sets whether ASCII letters match regardless of case
+    pub fn ascii_case_insensitive(mut self, yes: bool) -> Self {
+        self.ascii_case_insensitive = yes;
+        self
+    }
+
+    /// Sets whether to precompute a dense DFA transition table
+    pub fn dfa(mut self, yes: bool) -> Self {
+        self.dfa = yes;
+        self
+    }
+
+    /// Builds the automaton
+    ///
+    /// # Arguments
+    /// * `patterns` - Patterns to search for; duplicates and the empty pattern are allowed
+    ///
+    /// # Returns
+    /// * `Result<AhoCorasick, PatternError>` - Err(NoPatterns) if `patterns` is empty
+    pub fn build<I, P>(&self, patterns: I) -> Result<AhoCorasick, PatternError>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<[u8]>,
+    {
+        let fold = |byte: u8| if self.ascii_case_insensitive { byte.to_ascii_lowercase() } else { byte };
+
+        let mut states = vec![State::new(0)];
+        let mut pattern_lens = Vec::new();
+        for (index, pattern) in patterns.into_iter().enumerate() {
+            let pattern = pattern.as_ref();
+            let mut state = ROOT;
+            for &byte in pattern {
+                let byte = fold(byte);
+                state = match states[state].child(byte) {
+                    Some(next) => next,
+                    None => {
+                        let next = states.len();
+                        states.push(State::new(states[state].depth + 1));
+                        states[state].insert_child(byte, next);
+                        next
+                    }
+                };
+            }
+            states[state].outputs.push(index);
+            pattern_lens.push(pattern.len());
+        }
+        if pattern_lens.is_empty() {
+            return Err(PatternError::NoPatterns);
+        }
+
+        // Failure and dictionary links in breadth-first order, so every
+        // state's failure target is finished before the state itself
+        let mut order = Vec::with_capacity(states.len());
+        let mut queue = VecDeque::from([ROOT]);
+        while let Some(state) = queue.pop_front() {
+            order.push(state);
+            for &(byte, child) in &states[state].transitions.clone() {
+                let fail = if state == ROOT {
+                    ROOT
+                } else {
+                    let mut fallback = states[state].fail;
+                    loop {
+                        if let Some(next) = states[fallback].child(byte) {
+                            break next;
+                        }
+                        if fallback == ROOT {
+                            break ROOT;
+                        }
+                        fallback = states[fallback].fail;
+                    }
+                };
+                states[child].fail = fail;
+                states[child].dict_link =
+                    if states[fail].outputs.is_empty() { states[fail].dict_link } else { Some(fail) };
+                queue.push_back(child);
+            }
+        }
+
+        let dfa = self.dfa.then(|| Dfa::build(&mut states, &order, self.ascii_case_insensitive));
+        Ok(AhoCorasick {
+            states,
+            pattern_lens,
+            dfa,
+            match_kind: self.match_kind,
+            ascii_case_insensitive: self.ascii_case_insensitive,
+        })
+    }
+}
+
+/// Trie node with failure and dictionary links
+#[derive(Debug, Clone)]
+struct State {
+    /// Children sorted by byte; emptied once a DFA table replaces them
+    transitions: Vec<(u8, usize)>,
+    fail: usize,
+    /// Nearest proper suffix state with non-empty `outputs`
+    dict_link: Option<usize>,
+    /// Patterns equal to this state's string, in index order
+    outputs: Vec<usize>,
+    depth: usize,
+}
+
+impl State {
+    fn new(depth: usize) -> Self {
+        Self { transitions: Vec::new(), fail: ROOT, dict_link: None, outputs: Vec::new(), depth }
+    }
+
+    fn child(&self, byte: u8) -> Option<usize> {
+        self.transitions
+            .binary_search_by_key(&byte, |&(b, _)| b)
+            .ok()
+            .map(|i| self.transitions[i].1)
+    }
+
+    fn insert_child(&mut self, byte: u8, child: usize) {
+        let at = self.transitions.partition_point(|&(b, _)| b < byte);
+        self.transitions.insert(at, (byte, child));
+    }
+}
+
+/// Dense transition table over byte equivalence classes
+#[derive(Debug, Clone)]
+struct Dfa {
+    /// Class of every haystack byte; case folding is applied here
+    classes: [u16; 256],
+    class_count: usize,
+    /// `table[state * class_count + class]` is the next state
+    table: Vec<u32>,
+}
+
+impl Dfa {
+    fn build(states: &mut [State], order: &[usize], ascii_case_insensitive: bool) -> Self {
+        // Class 0 holds every byte that no pattern uses; it always leads to the root
+        let mut classes = [0u16; 256];
+        let mut representatives = vec![0u8];
+        for state in states.iter() {
+            for &(byte, _) in &state.transitions {
+                if classes[byte as usize] == 0 {
+                    classes[byte as usize] = representatives.len() as u16;
+                    representatives.push(byte);
+                }
+            }
+        }
+        if ascii_case_insensitive {
+            for upper in b'A'..=b'Z' {
+                classes[upper as usize] = classes[upper.to_ascii_lowercase() as usize];
+            }
+        }
+
+        let class_count = representatives.len();
+        let mut table = vec![ROOT as u32; states.len() * class_count];
+        for &state in order {
+            for (class, &byte) in representatives.iter().enumerate().skip(1) {
+                table[state * class_count + class] = match states[state].child(byte) {
+                    Some(next) => next as u32,
+                    None if state == ROOT => ROOT as u32,
+                    None => table[states[state].fail * class_count + class],
+                };
+            }
+        }
+
+        for state in states.iter_mut() {
+            state.transitions = Vec::new();
+        }
+        Self { classes, class_count, table }
+    }
+}
+
+/// Aho–Corasick automaton over a fixed set of byte patterns
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::string::aho_corasick::AhoCorasick;
+///
+/// let automaton = AhoCorasick::new(["he", "she", "his", "hers"]).unwrap();
+/// let found: Vec<(usize, usize)> = automaton
+///     .find_iter("ushers and his")
+///     .map(|m| (m.pattern, m.start))
+///     .collect();
+/// assert_eq!(found, [(1, 1), (2, 11)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct AhoCorasick {
+    states: Vec<State>,
+    pattern_lens: Vec<usize>,
+    dfa: Option<Dfa>,
+    match_kind: MatchKind,
+    ascii_case_insensitive: bool,
+}
+
+/// Result of advancing a search over a possibly incomplete buffer
+enum Step {
+    Found(Match),
+    Done,
+    /// The buffer ran out before the next match could be decided
+    NeedMore,
+}
+
+/// Search position shared by the in-memory and streaming iterators
+#[derive(Debug, Clone, Default)]
+struct Cursor {
+    /// Absolute offset of the buffer's first byte
+    offset: usize,
+    /// Buffer index of the next byte to scan (leftmost: next search start)
+    position: usize,
+    /// Overlapping only: current state
+    state: usize,
+    /// Overlapping only: state whose outputs are being reported and the next output index
+    pending: Option<(usize, usize)>,
+}
+
+impl AhoCorasick {
+    /// Builds an automaton with default settings (leftmost-first, case-sensitive)
+    ///
+    /// # Returns
+    /// * `Result<AhoCorasick, PatternError>` - Err(NoPatterns) if `patterns` is empty
+    pub fn new<I, P>(patterns: I) -> Result<Self, PatternError>
+    where
+        I: IntoIterator<Item = P>,
+        P: AsRef<[u8]>,
+    {
+        AhoCorasickBuilder::new().build(patterns)
+    }
+
+    /// Returns the configured match semantics
+    pub fn match_kind(&self) -> MatchKind {
+        self.match_kind
+    }
+
+    /// Returns the number of patterns
+    pub fn pattern_count(&self) -> usize {
+        self.pattern_lens.len()
+    }
+
+    /// Returns the number of automaton states (trie nodes)
+    pub fn state_count(&self) -> usize {
+        self.states.len()
+    }
+
+    /// Returns true if the automaton uses a precomputed DFA table
+    pub fn is_dfa(&self) -> bool {
+        self.dfa.is_some()
+    }
+
+    /// Returns true if any pattern occurs in `haystack`
+    pub fn is_match<H: AsRef<[u8]>>(&self, haystack: H) -> bool {
+        self.find(haystack).is_some()
+    }
+
+    /// Finds the first match according to the configured `MatchKind`
+    ///
+    /// For `Overlapping` this is the match that ends first.
+    pub fn find<H: AsRef<[u8]>>(&self, haystack: H) -> Option<Match> {
+        self.find_iter(haystack.as_ref()).next()
+    }
+
+    /// Returns an iterator over matches according to the configured `MatchKind`
+    ///
+    /// # Examples
+    /// ```
+    /// use dsa_in_rust::algorithms::searching::string::aho_corasick::{AhoCorasickBuilder, MatchKind};
+    ///
+    /// let automaton = AhoCorasickBuilder::new()
+    ///     .match_kind(MatchKind::Overlapping)
+    ///     .build(["abc", "bc", "c"])
+    ///     .unwrap();
+    /// let ends: Vec<(usize, usize)> = automaton.find_iter("abc").map(|m| (m.pattern, m.start)).collect();
+    /// assert_eq!(ends, [(0, 0), (1, 1), (2, 2)]);
+    /// ```
+    pub fn find_iter<'a, 'h, H: AsRef<[u8]> + ?Sized>(&'a self, haystack: &'h H) -> FindIter<'a, 'h> {
+        FindIter { automaton: self, haystack: haystack.as_ref(), cursor: self.start_cursor() }
+    }
+
+    /// Returns an iterator over matches read from `reader`
+    ///
+    /// Reports the same matches as `find_iter` on the concatenated input while
+    /// buffering only a window around the bytes currently being decided.
+    ///
+    /// # Examples
+    /// ```
+    /// use dsa_in_rust::algorithms::searching::string::aho_corasick::AhoCorasick;
+    ///
+    /// let automaton = AhoCorasick::new(["error", "warn"]).unwrap();
+    /// let log = "ok\nwarn: disk\nok\nerror: net\n".as_bytes();
+    /// let starts: Vec<usize> = automaton
+    ///     .stream_find_iter(log)
+    ///     .map(|m| m.unwrap().start)
+    ///     .collect();
+    /// assert_eq!(starts, [3, 17]);
+    /// ```
+    pub fn stream_find_iter<R: Read>(&self, reader: R) -> StreamFindIter<'_, R> {
+        StreamFindIter { automaton: self, reader, buffer: Vec::new(), eof: false, cursor: self.start_cursor() }
+    }
+
+    fn start_cursor(&self) -> Cursor {
+        Cursor { pending: Some((ROOT, 0)), ..Cursor::default() }
+    }
+
+    /// Follows one haystack byte from `state`
+    fn next_state(&self, mut state: usize, byte: u8) -> usize {
+        if let Some(dfa) = &self.dfa {
+            return dfa.table[state * dfa.class_count + dfa.classes[byte as usize] as usize] as usize;
+        }
+        let byte = if self.ascii_case_insensitive { byte.to_ascii_lowercase() } else { byte };
+        loop {
+            if let Some(next) = self.states[state].child(byte) {
+                return next;
+            }
+            if state == ROOT {
+                return ROOT;
+            }
+            state = self.states[state].fail;
+        }
+    }
+
+    /// Returns the state ending the longest pattern that is a suffix of `state`'s string
+    fn longest_output(&self, state: usize) -> Option<usize> {
+        if self.states[state].outputs.is_empty() {
+            self.states[state].dict_link
+        } else {
+            Some(state)
+        }
+    }
+
+    /// Returns true if `candidate` should replace `best` under leftmost semantics
+    fn prefer(&self, candidate: &Match, best: &Match) -> bool {
+        if candidate.start != best.start {
+            return candidate.start < best.start;
+        }
+        match self.match_kind {
+            MatchKind::LeftmostLongest => {
+                candidate.end > best.end || (candidate.end == best.end && candidate.pattern < best.pattern)
+            }
+            _ => candidate.pattern < best.pattern,
+        }
+    }
+
+    /// Advances `cursor` over `buffer` to the next match
+    ///
+    /// `eof` tells whether `buffer` holds all remaining input. On `NeedMore`
+    /// the bytes before `cursor.position` are no longer needed.
+    fn step(&self, buffer: &[u8], eof: bool, cursor: &mut Cursor) -> Step {
+        match self.match_kind {
+            MatchKind::Overlapping => self.step_overlapping(buffer, eof, cursor),
+            _ => self.step_leftmost(buffer, eof, cursor),
+        }
+    }
+
+    fn step_overlapping(&self, buffer: &[u8], eof: bool, cursor: &mut Cursor) -> Step {
+        loop {
+            if let Some((state, next_output)) = cursor.pending {
+                if let Some(&pattern) = self.states[state].outputs.get(next_output) {
+                    cursor.pending = Some((state, next_output + 1));
+                    let end = cursor.offset + cursor.position;
+                    return Step::Found(Match { pattern, start: end - self.pattern_lens[pattern], end });
+                }
+                cursor.pending = self.states[state].dict_link.map(|link| (link, 0));
+                continue;
+            }
+            let Some(&byte) = buffer.get(cursor.position) else {
+                return if eof { Step::Done } else { Step::NeedMore };
+            };
+            cursor.state = self.next_state(cursor.state, byte);
+            cursor.position += 1;
+            cursor.pending = Some((cursor.state, 0));
+        }
+    }
+
+    fn step_leftmost(&self, buffer: &[u8], eof: bool, cursor: &mut Cursor) -> Step {
+        let from = cursor.position;
+        if from > buffer.len() {
+            return Step::Done;
+        }
+
+        let mut state = ROOT;
+        let mut position = from;
+        let mut best: Option<Match> = None;
+        loop {
+            if let Some(output) = self.longest_output(state) {
+                let end = cursor.offset + position;
+                let candidate = Match {
+                    pattern: self.states[output].outputs[0],
+                    start: end - self.states[output].depth,
+                    end,
+                };
+                if best.is_none_or(|best| self.prefer(&candidate, &best)) {
+                    best = Some(candidate);
+                }
+            }
+
+            // Every match still reachable from `state` starts at or after this offset
+            let earliest_start = cursor.offset + position - self.states[state].depth;
+            if let Some(found) = best.filter(|best| earliest_start > best.start) {
+                return self.advance_past(found, cursor);
+            }
+
+            let Some(&byte) = buffer.get(position) else {
+                if !eof {
+                    cursor.position = earliest_start - cursor.offset;
+                    return Step::NeedMore;
+                }
+                return match best {
+                    Some(found) => self.advance_past(found, cursor),
+                    None => {
+                        cursor.position = buffer.len() + 1;
+                        Step::Done
+                    }
+                };
+            };
+            state = self.next_state(state, byte);
+            position += 1;
+        }
+    }
+
+    /// Moves a leftmost cursor past `found`, stepping over empty matches
+    fn advance_past(&self, found: Match, cursor: &mut Cursor) -> Step {
+        let resume = if found.end == found.start { found.end + 1 } else { found.end };
+        cursor.position = resume - cursor.offset;
+        Step::Found(found)
+    }
+}
+
+/// Iterator over matches in a byte slice, created by `AhoCorasick::find_iter`
+#[derive(Debug, Clone)]
+pub struct FindIter<'a, 'h> {
+    automaton: &'a AhoCorasick,
+    haystack: &'h [u8],
+    cursor: Cursor,
+}
+
+impl Iterator for FindIter<'_, '_> {
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Match> {
+        match self.automaton.step(self.haystack, true, &mut self.cursor) {
+            Step::Found(found) => Some(found),
+            Step::Done | Step::NeedMore => None,
+        }
+    }
+}
+
+/// Iterator over matches read from a `Read` source, created by
+/// `AhoCorasick::stream_find_iter`
+///
+/// Yields `Err` for read errors other than `Interrupted`; iteration may be
+/// resumed afterwards.
+#[derive(Debug)]
+pub struct StreamFindIter<'a, R> {
+    automaton: &'a AhoCorasick,
+    reader: R,
+    buffer: Vec<u8>,
+    eof: bool,
+    cursor: Cursor,
+}
+
+impl<R: Read> Iterator for StreamFindIter<'_, R> {
+    type Item = io::Result<Match>;
+
+    fn next(&mut self) -> Option<io::Result<Match>> {
+        loop {
+            match self.automaton.step(&self.buffer, self.eof, &mut self.cursor) {
+                Step::Found(found) => return Some(Ok(found)),
+                Step::Done => return None,
+                Step::NeedMore => {}
+            }
+
+            // Drop bytes that can no longer be part of a match, then refill
+            let consumed = self.cursor.position;
+            self.buffer.drain(..consumed);
+            self.cursor.offset += consumed;
+            self.cursor.position = 0;
+
+            let mut chunk = [0u8; CHUNK_SIZE];
+            match self.reader.read(&mut chunk) {
+                Ok(0) => self.eof = true,
+                Ok(read) => self.buffer.extend_from_slice(&chunk[..read]),
+                Err(error) if error.kind() == ErrorKind::Interrupted => {}
+                Err(error) => return Some(Err(error)),
+            }
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//! Boyer–Moore–Horspool String Matching
+//!
+//! DEV NOTES:
+//! - Compares the window right to left and shifts by the bad-character rule
+//!   applied to the window's last element
+//! - Shift table keyed by element value: a 256-entry array would be faster for
+//!   bytes, but a `HashMap` keeps the matcher generic over any `Hash + Eq` type
+//! - Preprocessing: O(m) time, O(σ) space where σ is the pattern alphabet
+//! - Matching: O(n / m) best case, O(n * m) worst case; sublinear on typical text
+
+use super::matcher::Matcher;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Boyer–Moore–Horspool matcher
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::string::horspool::Horspool;
+/// use dsa_in_rust::algorithms::searching::string::matcher::Matcher;
+///
+/// let matcher = Horspool::new(b"needle");
+/// assert_eq!(matcher.find(b"haystack with a needle in it"), Some(16));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Horspool<T> {
+    pattern: Vec<T>,
+    /// Distance from the last occurrence of each element (excluding the final
+    /// position) to the end of the pattern
+    shift: HashMap<T, usize>,
+}
+
+impl<T: Eq + Hash + Clone> Horspool<T> {
+    /// Builds a matcher for `pattern`
+    ///
+    /// # Performance
+    /// * Time Complexity: O(m)
+    /// * Space Complexity: O(σ)
+    pub fn new(pattern: &[T]) -> Self {
+        let m = pattern.len();
+        let mut shift = HashMap::new();
+        for (i, item) in pattern.iter().enumerate().take(m.saturating_sub(1)) {
+            shift.insert(item.clone(), m - 1 - i);
+        }
+        Self { pattern: pattern.to_vec(), shift }
+    }
+}
+
+impl<T: Eq + Hash> Matcher for Horspool<T> {
+    type Item = T;
+
+    fn pattern_len(&self) -> usize {
+        self.pattern.len()
+    }
+
+    fn find_at(&self, haystack: &[T], from: usize) -> Option<usize> {
+        let m = self.pattern.len();
+        if m == 0 {
+            return (from <= haystack.len()).then_some(from);
+        }
+
+        let mut start = from;
+        while start + m <= haystack.len() {
+            let window = &haystack[start..start + m];
+            if window.iter().rev().zip(self.pattern.iter().rev()).all(|(a, b)| a == b) {
+                return Some(start);
+            }
+            start += self.shift.get(&window[m - 1]).copied().unwrap_or(m);
+        }
+        None
+    }
+}
@@ -0,0 +1,115 @@
+//! Knuth–Morris–Pratt String Matching
+//!
+//! DEV NOTES:
+//! - The prefix function (failure table) is exposed for reuse, e.g. to find
+//!   periods or borders of a string
+//! - Never moves backwards in the haystack, so it suits streamed input
+//! - Preprocessing: O(m) time and space
+//! - Matching: O(n) time, O(1) extra space
+
+use super::matcher::Matcher;
+
+/// Computes the prefix function of `pattern`
+///
+/// `result[i]` is the length of the longest proper prefix of `pattern[..=i]`
+/// that is also a suffix of it.
+///
+/// # Arguments
+/// * `pattern` - Sequence to analyse
+///
+/// # Returns
+/// * `Vec<usize>` - Prefix function values, one per element
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::string::kmp::prefix_function;
+///
+/// assert_eq!(prefix_function(b"abacaba"), [0, 0, 1, 0, 1, 2, 3]);
+/// // Smallest period of "abcabcab" is 8 - 5 = 3
+/// assert_eq!(*prefix_function(b"abcabcab").last().unwrap(), 5);
+/// ```
+///
+/// # Performance
+/// * Time Complexity: O(m)
+/// * Space Complexity: O(m)
+pub fn prefix_function<T: Eq>(pattern: &[T]) -> Vec<usize> {
+    let mut prefix = vec![0; pattern.len()];
+    let mut border = 0;
+    for i in 1..pattern.len() {
+        while border > 0 && pattern[i] != pattern[border] {
+            border = prefix[border - 1];
+        }
+        if pattern[i] == pattern[border] {
+            border += 1;
+        }
+        prefix[i] = border;
+    }
+    prefix
+}
+
+/// Knuth–Morris–Pratt matcher
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::string::kmp::Kmp;
+/// use dsa_in_rust::algorithms::searching::string::matcher::Matcher;
+///
+/// let log = "GET /a 200\nGET /b 404\nGET /c 404\n";
+/// let kmp = Kmp::new(" 404".as_bytes());
+/// let lines: Vec<usize> = kmp.find_iter(log.as_bytes()).collect();
+/// assert_eq!(&log[lines[0] + 1..lines[0] + 4], "404");
+/// assert_eq!(lines.len(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Kmp<T> {
+    pattern: Vec<T>,
+    prefix: Vec<usize>,
+}
+
+impl<T: Eq + Clone> Kmp<T> {
+    /// Builds a matcher for `pattern`
+    ///
+    /// # Performance
+    /// * Time Complexity: O(m)
+    /// * Space Complexity: O(m)
+    pub fn new(pattern: &[T]) -> Self {
+        Self {
+            pattern: pattern.to_vec(),
+            prefix: prefix_function(pattern),
+        }
+    }
+
+    /// Returns the prefix function of the pattern
+    pub fn prefix(&self) -> &[usize] {
+        &self.prefix
+    }
+}
+
+impl<T: Eq> Matcher for Kmp<T> {
+    type Item = T;
+
+    fn pattern_len(&self) -> usize {
+        self.pattern.len()
+    }
+
+    fn find_at(&self, haystack: &[T], from: usize) -> Option<usize> {
+        let m = self.pattern.len();
+        if m == 0 {
+            return (from <= haystack.len()).then_some(from);
+        }
+
+        let mut matched = 0;
+        for (i, item) in haystack.iter().enumerate().skip(from) {
+            while matched > 0 && *item != self.pattern[matched] {
+                matched = self.prefix[matched - 1];
+            }
+            if *item == self.pattern[matched] {
+                matched += 1;
+            }
+            if matched == m {
+                return Some(i + 1 - m);
+            }
+        }
+        None
+    }
+}
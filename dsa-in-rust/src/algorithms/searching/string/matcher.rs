@@ -0,0 +1,110 @@
+//! Shared Interface for Substring Matchers
+//!
+//! DEV NOTES:
+//! - Every matcher is built once from its pattern(s) and can then scan any
+//!   number of haystacks
+//! - Haystacks are slices of any `Eq` element type; search text with
+//!   `text.as_bytes()` and the returned byte offsets are valid `str` indices
+//! - An empty pattern matches at every position `0..=haystack.len()`
+//! - `find_iter` skips past each match; `find_overlapping_iter` advances by one
+
+use std::error::Error;
+use std::fmt;
+
+/// Custom error type for building matchers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatternError {
+    /// A multi-pattern matcher was built from an empty pattern list
+    NoPatterns,
+    /// Patterns in a multi-pattern matcher must share one length
+    UnequalLengths { expected: usize, found: usize },
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PatternError::NoPatterns => write!(f, "At least one pattern is required"),
+            PatternError::UnequalLengths { expected, found } => {
+                write!(f, "Pattern length mismatch: expected {}, found {}", expected, found)
+            }
+        }
+    }
+}
+
+impl Error for PatternError {}
+
+/// A precompiled substring matcher
+pub trait Matcher {
+    /// Element type of patterns and haystacks
+    type Item: Eq;
+
+    /// Returns the length of the pattern(s)
+    fn pattern_len(&self) -> usize;
+
+    /// Finds the first match starting at or after `from`
+    ///
+    /// # Arguments
+    /// * `haystack` - Sequence to search
+    /// * `from` - First position a match may start at
+    ///
+    /// # Returns
+    /// * `Option<usize>` - Start position of the match, None if there is none
+    fn find_at(&self, haystack: &[Self::Item], from: usize) -> Option<usize>;
+
+    /// Finds the first match in `haystack`
+    fn find(&self, haystack: &[Self::Item]) -> Option<usize> {
+        self.find_at(haystack, 0)
+    }
+
+    /// Returns an iterator over the start positions of non-overlapping matches
+    ///
+    /// # Examples
+    /// ```
+    /// use dsa_in_rust::algorithms::searching::string::kmp::Kmp;
+    /// use dsa_in_rust::algorithms::searching::string::matcher::Matcher;
+    ///
+    /// let kmp = Kmp::new(b"aa");
+    /// assert_eq!(kmp.find_iter(b"aaaaa").collect::<Vec<_>>(), [0, 2]);
+    /// ```
+    fn find_iter<'m, 'h>(&'m self, haystack: &'h [Self::Item]) -> Matches<'m, 'h, Self> {
+        Matches { matcher: self, haystack, position: 0, overlapping: false }
+    }
+
+    /// Returns an iterator over the start positions of all matches, including overlapping ones
+    ///
+    /// # Examples
+    /// ```
+    /// use dsa_in_rust::algorithms::searching::string::kmp::Kmp;
+    /// use dsa_in_rust::algorithms::searching::string::matcher::Matcher;
+    ///
+    /// let kmp = Kmp::new(b"aa");
+    /// assert_eq!(kmp.find_overlapping_iter(b"aaaaa").collect::<Vec<_>>(), [0, 1, 2, 3]);
+    /// ```
+    fn find_overlapping_iter<'m, 'h>(&'m self, haystack: &'h [Self::Item]) -> Matches<'m, 'h, Self> {
+        Matches { matcher: self, haystack, position: 0, overlapping: true }
+    }
+}
+
+/// Iterator over match positions, created by `Matcher::find_iter` and
+/// `Matcher::find_overlapping_iter`
+#[derive(Debug)]
+pub struct Matches<'m, 'h, M: Matcher + ?Sized> {
+    matcher: &'m M,
+    haystack: &'h [M::Item],
+    position: usize,
+    overlapping: bool,
+}
+
+impl<M: Matcher + ?Sized> Iterator for Matches<'_, '_, M> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.position > self.haystack.len() {
+            return None;
+        }
+        let start = self.matcher.find_at(self.haystack, self.position)?;
+        let step = if self.overlapping { 1 } else { self.matcher.pattern_len().max(1) };
+        self.position = start + step;
+        Some(start)
+    }
+}
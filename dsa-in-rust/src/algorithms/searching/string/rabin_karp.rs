@@ -0,0 +1,161 @@
+//! Rabin–Karp String Matching
+//!
+//! DEV NOTES:
+//! - Polynomial rolling hash modulo the Mersenne prime 2^61 - 1
+//! - Elements are first mapped to `u64` through `Hash` with a fixed-key
+//!   hasher, so any `Hash + Eq` element type is supported
+//! - Several patterns of the same length are matched in one pass: window
+//!   hashes are looked up in a table of pattern hashes
+//! - Every hash hit is verified element by element, so there are no false positives
+//! - Matching: O(n + m * k) expected where k is the number of hash hits
+
+use super::matcher::{Matcher, PatternError};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+const MODULUS: u64 = (1 << 61) - 1;
+const BASE: u64 = 0x100_0000_01b3;
+
+/// Rabin–Karp matcher for one or more patterns of equal length
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::string::rabin_karp::RabinKarp;
+///
+/// let matcher = RabinKarp::with_patterns(&[b"ERR".as_slice(), b"WRN".as_slice()]).unwrap();
+/// let hits: Vec<(usize, usize)> = matcher.find_patterns_iter(b"OK ERR OK WRN").collect();
+/// assert_eq!(hits, [(3, 0), (10, 1)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RabinKarp<T> {
+    patterns: Vec<Vec<T>>,
+    pattern_len: usize,
+    /// Pattern indices grouped by hash
+    by_hash: HashMap<u64, Vec<usize>>,
+    /// BASE^(pattern_len - 1) mod MODULUS, used to remove the outgoing element
+    high_power: u64,
+}
+
+impl<T: Eq + Hash + Clone> RabinKarp<T> {
+    /// Builds a matcher for a single pattern
+    pub fn new(pattern: &[T]) -> Self {
+        Self::with_patterns(&[pattern]).expect("a single pattern is always valid")
+    }
+
+    /// Builds a matcher for several patterns of the same length
+    ///
+    /// # Arguments
+    /// * `patterns` - Patterns to search for simultaneously
+    ///
+    /// # Returns
+    /// * `Result<RabinKarp<T>, PatternError>` - Err if the list is empty or lengths differ
+    pub fn with_patterns(patterns: &[&[T]]) -> Result<Self, PatternError> {
+        let pattern_len = patterns.first().ok_or(PatternError::NoPatterns)?.len();
+        if let Some(p) = patterns.iter().find(|p| p.len() != pattern_len) {
+            return Err(PatternError::UnequalLengths { expected: pattern_len, found: p.len() });
+        }
+
+        let mut by_hash: HashMap<u64, Vec<usize>> = HashMap::new();
+        for (index, pattern) in patterns.iter().enumerate() {
+            by_hash.entry(window_hash(pattern)).or_default().push(index);
+        }
+
+        let high_power = (1..pattern_len).fold(1, |acc, _| mul_mod(acc, BASE));
+        Ok(Self {
+            patterns: patterns.iter().map(|p| p.to_vec()).collect(),
+            pattern_len,
+            by_hash,
+            high_power,
+        })
+    }
+}
+
+impl<T: Eq + Hash> RabinKarp<T> {
+    /// Finds the first match at or after `from`, with the index of the matching pattern
+    ///
+    /// # Returns
+    /// * `Option<(usize, usize)>` - `(position, pattern index)` of the match
+    pub fn find_pattern_at(&self, haystack: &[T], from: usize) -> Option<(usize, usize)> {
+        let m = self.pattern_len;
+        if m == 0 {
+            return (from <= haystack.len()).then_some((from, 0));
+        }
+        if from + m > haystack.len() {
+            return None;
+        }
+
+        let mut hash = window_hash(&haystack[from..from + m]);
+        let mut start = from;
+        loop {
+            if let Some(candidates) = self.by_hash.get(&hash) {
+                let window = &haystack[start..start + m];
+                if let Some(&index) = candidates.iter().find(|&&i| self.patterns[i] == window) {
+                    return Some((start, index));
+                }
+            }
+            if start + m == haystack.len() {
+                return None;
+            }
+            // Roll: drop haystack[start], append haystack[start + m]
+            let outgoing = mul_mod(element_hash(&haystack[start]), self.high_power);
+            hash = add_mod(mul_mod(sub_mod(hash, outgoing), BASE), element_hash(&haystack[start + m]));
+            start += 1;
+        }
+    }
+
+    /// Returns an iterator over all `(position, pattern index)` matches
+    ///
+    /// Since all patterns share one length, at most one distinct pattern can
+    /// match at any position; matches may overlap.
+    pub fn find_patterns_iter<'m, 'h>(&'m self, haystack: &'h [T]) -> impl Iterator<Item = (usize, usize)> + 'm
+    where
+        'h: 'm,
+    {
+        let mut position = 0;
+        std::iter::from_fn(move || {
+            if position > haystack.len() {
+                return None;
+            }
+            let (start, index) = self.find_pattern_at(haystack, position)?;
+            position = start + 1;
+            Some((start, index))
+        })
+    }
+}
+
+impl<T: Eq + Hash> Matcher for RabinKarp<T> {
+    type Item = T;
+
+    fn pattern_len(&self) -> usize {
+        self.pattern_len
+    }
+
+    fn find_at(&self, haystack: &[T], from: usize) -> Option<usize> {
+        self.find_pattern_at(haystack, from).map(|(start, _)| start)
+    }
+}
+
+/// Maps an element to a value in `[0, MODULUS)` using a fixed-key hasher
+fn element_hash<T: Hash>(item: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    item.hash(&mut hasher);
+    hasher.finish() % MODULUS
+}
+
+/// Polynomial hash of a whole window
+fn window_hash<T: Hash>(window: &[T]) -> u64 {
+    window.iter().fold(0, |acc, item| add_mod(mul_mod(acc, BASE), element_hash(item)))
+}
+
+fn mul_mod(a: u64, b: u64) -> u64 {
+    ((a as u128 * b as u128) % MODULUS as u128) as u64
+}
+
+fn add_mod(a: u64, b: u64) -> u64 {
+    (a + b) % MODULUS
+}
+
+fn sub_mod(a: u64, b: u64) -> u64 {
+    (a + MODULUS - b) % MODULUS
+}
@@ -0,0 +1,285 @@
+//! Suffix Array with LCP Array
+//!
+//! DEV NOTES:
+//! - Built by prefix doubling: after round k suffixes are ranked by their
+//!   first 2^k elements; each round is two stable counting sorts (radix sort
+//!   on the rank pairs), and rounds stop as soon as all ranks are distinct
+//! - Elements of any `T: Ord` are first mapped to dense ranks, so the
+//!   counting sorts only ever see integers below n
+//! - `lcp[i]` is the longest common prefix of the suffixes at `suffixes[i - 1]`
+//!   and `suffixes[i]` (Kasai et al.); `lcp[0]` is 0
+//! - Occurrences of a pattern are a contiguous block of the suffix array,
+//!   found with two binary searches
+//! - Like the other matchers, an empty pattern occurs at every position
+//!   `0..=n`, including the end of the text
+//!
+//! PERFORMANCE CONSIDERATIONS:
+//! - Construction: O(n log n) time, O(n) extra space
+//! - LCP array: O(n)
+//! - Count: O(m log n); locate adds O(occ log occ) to sort the positions
+
+use std::ops::Range;
+
+/// Suffix array over a sequence of `T`
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::string::suffix_array::SuffixArray;
+///
+/// let sa = SuffixArray::new(b"banana");
+/// assert_eq!(sa.suffixes(), [5, 3, 1, 0, 4, 2]);
+/// assert_eq!(sa.lcp(), [0, 1, 3, 0, 0, 2]);
+/// assert_eq!(sa.count(b"ana"), 2);
+/// assert_eq!(sa.locate(b"an"), [1, 3]);
+/// assert_eq!(sa.longest_repeated_substring(), Some(1..4));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SuffixArray<T> {
+    text: Vec<T>,
+    suffixes: Vec<usize>,
+    lcp: Vec<usize>,
+}
+
+/// A longest common substring, as returned by `longest_common_substring`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommonSubstring {
+    /// Start in the first sequence
+    pub first: usize,
+    /// Start in the second sequence
+    pub second: usize,
+    /// Length of the shared run
+    pub len: usize,
+}
+
+impl<T: Ord + Clone> SuffixArray<T> {
+    /// Builds the suffix array and LCP array of `text`
+    ///
+    /// # Performance
+    /// * Time Complexity: O(n log n)
+    /// * Space Complexity: O(n)
+    pub fn new(text: &[T]) -> Self {
+        let ranks = dense_ranks(&[text], 0).pop().expect("one part");
+        let suffixes = build_suffix_array(&ranks);
+        let lcp = kasai(&ranks, &suffixes);
+        Self { text: text.to_vec(), suffixes, lcp }
+    }
+}
+
+impl<T: Ord> SuffixArray<T> {
+    /// Returns the indexed text
+    pub fn text(&self) -> &[T] {
+        &self.text
+    }
+
+    /// Returns the length of the text
+    pub fn len(&self) -> usize {
+        self.text.len()
+    }
+
+    /// Returns true if the text is empty
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Returns suffix start positions in lexicographic order of the suffixes
+    pub fn suffixes(&self) -> &[usize] {
+        &self.suffixes
+    }
+
+    /// Returns the LCP array aligned with `suffixes`
+    pub fn lcp(&self) -> &[usize] {
+        &self.lcp
+    }
+
+    /// Finds the block of `suffixes` whose suffixes start with `pattern`
+    ///
+    /// # Returns
+    /// * `Range<usize>` - Indices into `suffixes()`; empty if there is no occurrence
+    ///
+    /// # Performance
+    /// * Time Complexity: O(m log n)
+    pub fn range(&self, pattern: &[T]) -> Range<usize> {
+        let prefix = |start: usize| &self.text[start..self.text.len().min(start + pattern.len())];
+        let low = self.suffixes.partition_point(|&start| prefix(start) < pattern);
+        let high = low + self.suffixes[low..].partition_point(|&start| prefix(start) == pattern);
+        low..high
+    }
+
+    /// Counts (possibly overlapping) occurrences of `pattern`
+    ///
+    /// # Performance
+    /// * Time Complexity: O(m log n)
+    pub fn count(&self, pattern: &[T]) -> usize {
+        if pattern.is_empty() {
+            return self.text.len() + 1;
+        }
+        self.range(pattern).len()
+    }
+
+    /// Returns true if `pattern` occurs in the text
+    pub fn contains(&self, pattern: &[T]) -> bool {
+        self.count(pattern) > 0
+    }
+
+    /// Returns every start position of `pattern`, in ascending order
+    ///
+    /// # Performance
+    /// * Time Complexity: O(m log n + occ log occ)
+    pub fn locate(&self, pattern: &[T]) -> Vec<usize> {
+        if pattern.is_empty() {
+            return (0..=self.text.len()).collect();
+        }
+        let mut positions = self.suffixes[self.range(pattern)].to_vec();
+        positions.sort_unstable();
+        positions
+    }
+
+    /// Finds the longest substring occurring at least twice (occurrences may overlap)
+    ///
+    /// # Returns
+    /// * `Option<Range<usize>>` - Position of one occurrence, the lexicographically
+    ///   smallest among ties; None if no element repeats
+    ///
+    /// # Performance
+    /// * Time Complexity: O(n)
+    pub fn longest_repeated_substring(&self) -> Option<Range<usize>> {
+        let (index, &len) = self.lcp.iter().enumerate().rev().max_by_key(|&(_, &len)| len)?;
+        (len > 0).then(|| self.suffixes[index]..self.suffixes[index] + len)
+    }
+}
+
+/// Finds the longest contiguous run shared by two sequences
+///
+/// Builds one suffix array over `first`, a unique separator and `second`; the
+/// answer is the largest LCP between neighbouring suffixes from different sides.
+///
+/// # Returns
+/// * `Option<CommonSubstring>` - Starts in both sequences and the length
+///   (lexicographically smallest among ties); None if nothing is shared
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::string::suffix_array::longest_common_substring;
+///
+/// let found = longest_common_substring(b"xabcdey", b"zzbcdez").unwrap();
+/// assert_eq!((found.first, found.second, found.len), (2, 2, 4));
+/// assert_eq!(longest_common_substring(b"abc", b"xyz"), None);
+/// ```
+///
+/// # Performance
+/// * Time Complexity: O((n + m) log(n + m))
+/// * Space Complexity: O(n + m)
+pub fn longest_common_substring<T: Ord>(first: &[T], second: &[T]) -> Option<CommonSubstring> {
+    // Rank 0 is reserved for the separator, which occurs once and so ends every common prefix
+    let mut parts = dense_ranks(&[first, second], 1);
+    let second_ranks = parts.pop().expect("two parts");
+    let mut ranks = parts.pop().expect("two parts");
+    ranks.push(0);
+    ranks.extend(second_ranks);
+
+    let suffixes = build_suffix_array(&ranks);
+    let lcp = kasai(&ranks, &suffixes);
+    let split = first.len();
+    let mut best: Option<CommonSubstring> = None;
+    for i in 1..suffixes.len() {
+        let (a, b) = (suffixes[i - 1], suffixes[i]);
+        if (a < split) == (b < split) || a == split || b == split || lcp[i] == 0 {
+            continue;
+        }
+        if best.is_none_or(|best| lcp[i] > best.len) {
+            let (first, second) = if a < split { (a, b) } else { (b, a) };
+            best = Some(CommonSubstring { first, second: second - split - 1, len: lcp[i] });
+        }
+    }
+    best
+}
+
+/// Maps the elements of all parts to dense ranks starting at `offset`
+fn dense_ranks<T: Ord>(parts: &[&[T]], offset: usize) -> Vec<Vec<usize>> {
+    let mut alphabet: Vec<&T> = parts.iter().flat_map(|part| part.iter()).collect();
+    alphabet.sort_unstable();
+    alphabet.dedup();
+    parts
+        .iter()
+        .map(|part| {
+            part.iter()
+                .map(|item| offset + alphabet.binary_search(&item).expect("item is in the alphabet"))
+                .collect()
+        })
+        .collect()
+}
+
+/// Sorts the suffixes of a sequence of small integers by prefix doubling
+fn build_suffix_array(ranks: &[usize]) -> Vec<usize> {
+    let n = ranks.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    let classes = n.max(ranks.iter().max().map_or(0, |&max| max + 1));
+    let mut rank = ranks.to_vec();
+    let mut suffixes = counting_sort((0..n).collect(), &rank, classes);
+    let mut next_rank = vec![0; n];
+    let mut width = 1;
+    loop {
+        // Order by the second half: suffixes without one come first, the rest
+        // follow the current order shifted back by `width`
+        let by_second: Vec<usize> = (n.saturating_sub(width)..n)
+            .chain(suffixes.iter().filter(|&&start| start >= width).map(|&start| start - width))
+            .collect();
+        suffixes = counting_sort(by_second, &rank, classes);
+
+        let key = |start: usize| (rank[start], rank.get(start + width).copied());
+        next_rank[suffixes[0]] = 0;
+        for i in 1..n {
+            let step = usize::from(key(suffixes[i - 1]) != key(suffixes[i]));
+            next_rank[suffixes[i]] = next_rank[suffixes[i - 1]] + step;
+        }
+        std::mem::swap(&mut rank, &mut next_rank);
+        if rank[suffixes[n - 1]] == n - 1 {
+            return suffixes;
+        }
+        width *= 2;
+    }
+}
+
+/// Stable counting sort of `items` by `key[item]`
+fn counting_sort(items: Vec<usize>, key: &[usize], classes: usize) -> Vec<usize> {
+    let mut starts = vec![0; classes + 1];
+    for &item in &items {
+        starts[key[item] + 1] += 1;
+    }
+    for class in 1..=classes {
+        starts[class] += starts[class - 1];
+    }
+    let mut sorted = vec![0; items.len()];
+    for item in items {
+        sorted[starts[key[item]]] = item;
+        starts[key[item]] += 1;
+    }
+    sorted
+}
+
+/// Kasai's algorithm: LCP of each suffix with its predecessor in suffix order
+fn kasai(text: &[usize], suffixes: &[usize]) -> Vec<usize> {
+    let n = text.len();
+    let mut inverse = vec![0; n];
+    for (index, &start) in suffixes.iter().enumerate() {
+        inverse[start] = index;
+    }
+    let mut lcp = vec![0; n];
+    let mut matched: usize = 0;
+    for start in 0..n {
+        if inverse[start] == 0 {
+            matched = 0;
+            continue;
+        }
+        let previous = suffixes[inverse[start] - 1];
+        while start + matched < n && previous + matched < n && text[start + matched] == text[previous + matched] {
+            matched += 1;
+        }
+        lcp[inverse[start]] = matched;
+        // Dropping the first element loses at most one matched element
+        matched = matched.saturating_sub(1);
+    }
+    lcp
+}
@@ -0,0 +1,251 @@
+//! Searches over Structured Inputs
+//!
+//! DEV NOTES:
+//! - Rotated sorted arrays: a sorted slice rotated by an unknown offset, e.g.
+//!   `[4, 5, 6, 1, 2, 3]`; duplicates are allowed
+//! - Sorted matrices: every row and every column is non-decreasing (a Young
+//!   tableau); rows are any `AsRef<[T]>` and must all have the same length
+//! - Peaks: an element not smaller than any of its neighbours; one always
+//!   exists in a non-empty input, and binary search finds one without scanning
+//!
+//! PERFORMANCE CONSIDERATIONS:
+//! - Rotated search and minimum: O(log n), degrading to O(n) only when
+//!   duplicates hide which half is sorted
+//! - Staircase search: O(rows + cols)
+//! - k-th smallest in a sorted matrix: O(min(k, rows) + k log rows)
+//! - Peak finding: O(log n) in 1D, O(rows log cols) in 2D
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Searches a rotated sorted slice for `target`
+///
+/// # Arguments
+/// * `arr` - Sorted slice rotated by any offset, duplicates allowed
+/// * `target` - Value to find
+///
+/// # Returns
+/// * `Option<usize>` - Index of an element equal to `target`, None if absent
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::structured_search::rotated_search;
+///
+/// let arr = [15, 18, 22, 3, 6, 9, 12];
+/// assert_eq!(rotated_search(&arr, &6), Some(4));
+/// assert_eq!(rotated_search(&arr, &7), None);
+/// ```
+pub fn rotated_search<T: Ord>(arr: &[T], target: &T) -> Option<usize> {
+    let (mut low, mut high) = (0, arr.len());
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let last = high - 1;
+        if arr[mid] == *target {
+            return Some(mid);
+        }
+
+        if arr[low] == arr[mid] && arr[mid] == arr[last] {
+            // Duplicates at both ends hide the sorted half; shrink from both sides
+            low += 1;
+            high -= 1;
+        } else if arr[low] <= arr[mid] {
+            // [low, mid] is sorted
+            if arr[low] <= *target && *target < arr[mid] {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        } else if arr[mid] < *target && *target <= arr[last] {
+            // (mid, last] is sorted and holds the target
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    None
+}
+
+/// Finds where the sorted order of a rotated sorted slice begins
+///
+/// # Arguments
+/// * `arr` - Sorted slice rotated by any offset, duplicates allowed
+///
+/// # Returns
+/// * `Option<usize>` - Index of the only element smaller than its predecessor,
+///   or 0 if there is none; None for an empty slice. Either way it holds a minimum.
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::structured_search::rotated_min;
+///
+/// assert_eq!(rotated_min(&[5, 6, 7, 1, 2, 3, 4]), Some(3));
+/// assert_eq!(rotated_min(&[2, 2, 2, 0, 1, 2]), Some(3));
+/// assert_eq!(rotated_min::<i32>(&[]), None);
+/// ```
+pub fn rotated_min<T: Ord>(arr: &[T]) -> Option<usize> {
+    if arr.is_empty() {
+        return None;
+    }
+    let (mut low, mut high) = (0, arr.len() - 1);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if arr[mid] > arr[high] {
+            low = mid + 1;
+        } else if arr[mid] < arr[high] {
+            high = mid;
+        } else if arr[high - 1] > arr[high] {
+            // Dropping `high` would lose the rotation point
+            return Some(high);
+        } else {
+            high -= 1;
+        }
+    }
+    Some(low)
+}
+
+/// Staircase search in a matrix whose rows and columns are sorted
+///
+/// Starts in the top-right corner and discards a row or a column per step.
+///
+/// # Arguments
+/// * `matrix` - Rows of equal length, sorted along rows and columns
+/// * `target` - Value to find
+///
+/// # Returns
+/// * `Option<(usize, usize)>` - `(row, column)` of an element equal to `target`
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::structured_search::staircase_search;
+///
+/// let matrix = [[1, 4, 7], [2, 5, 8], [3, 6, 9]];
+/// assert_eq!(staircase_search(&matrix, &6), Some((2, 1)));
+/// assert_eq!(staircase_search(&matrix, &10), None);
+/// ```
+pub fn staircase_search<T: Ord, R: AsRef<[T]>>(matrix: &[R], target: &T) -> Option<(usize, usize)> {
+    let cols = matrix.first()?.as_ref().len();
+    let (mut row, mut col) = (0, cols.checked_sub(1)?);
+    while row < matrix.len() {
+        let value = &matrix[row].as_ref()[col];
+        if value == target {
+            return Some((row, col));
+        }
+        if value > target {
+            col = col.checked_sub(1)?;
+        } else {
+            row += 1;
+        }
+    }
+    None
+}
+
+/// Finds the k-th smallest element (0-based) of a matrix whose rows and columns are sorted
+///
+/// Merges rows lazily with a min-heap, so only the first `k + 1` elements in
+/// sorted order are visited.
+///
+/// # Arguments
+/// * `matrix` - Rows of equal length, sorted along rows and columns
+/// * `k` - Rank to select; 0 is the minimum
+///
+/// # Returns
+/// * `Option<&T>` - The element of rank `k`, None if `k` is out of range
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::structured_search::kth_smallest;
+///
+/// let matrix = [[1, 5, 9], [10, 11, 13], [12, 13, 15]];
+/// assert_eq!(kth_smallest(&matrix, 7), Some(&13));
+/// assert_eq!(kth_smallest(&matrix, 9), None);
+/// ```
+pub fn kth_smallest<T: Ord, R: AsRef<[T]>>(matrix: &[R], k: usize) -> Option<&T> {
+    let cols = matrix.first()?.as_ref().len();
+    if k >= matrix.len() * cols {
+        return None;
+    }
+
+    // Columns are sorted too, so no row beyond the k-th can hold the answer
+    let mut heap: BinaryHeap<Reverse<(&T, usize, usize)>> =
+        matrix.iter().take(k + 1).enumerate().map(|(row, values)| Reverse((&values.as_ref()[0], row, 0))).collect();
+
+    for _ in 0..k {
+        let Reverse((_, row, col)) = heap.pop()?;
+        if col + 1 < cols {
+            heap.push(Reverse((&matrix[row].as_ref()[col + 1], row, col + 1)));
+        }
+    }
+    heap.pop().map(|Reverse((value, _, _))| value)
+}
+
+/// Finds a peak: an element not smaller than its neighbours
+///
+/// # Arguments
+/// * `arr` - Any slice
+///
+/// # Returns
+/// * `Option<usize>` - Index of a peak, None for an empty slice
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::structured_search::find_peak;
+///
+/// let arr = [1, 3, 20, 4, 1, 0];
+/// assert_eq!(find_peak(&arr), Some(2));
+/// ```
+pub fn find_peak<T: Ord>(arr: &[T]) -> Option<usize> {
+    if arr.is_empty() {
+        return None;
+    }
+    // Invariant: a peak exists in [low, high], since the slope points inwards
+    let (mut low, mut high) = (0, arr.len() - 1);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if arr[mid] < arr[mid + 1] {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    Some(low)
+}
+
+/// Finds a 2D peak: an element not smaller than its up to four neighbours
+///
+/// Bisects on columns: the maximum of the middle column is a peak unless a
+/// horizontal neighbour is larger, in which case a peak exists on that side.
+///
+/// # Arguments
+/// * `matrix` - Rows of equal length
+///
+/// # Returns
+/// * `Option<(usize, usize)>` - `(row, column)` of a peak, None for an empty matrix
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::structured_search::find_peak_2d;
+///
+/// let matrix = [[10, 8, 10, 10], [14, 13, 12, 11], [15, 9, 11, 21], [16, 17, 19, 20]];
+/// let (row, col) = find_peak_2d(&matrix).unwrap();
+/// assert!(matrix[row][col] >= matrix[row][col.saturating_sub(1)]);
+/// ```
+pub fn find_peak_2d<T: Ord, R: AsRef<[T]>>(matrix: &[R]) -> Option<(usize, usize)> {
+    let cols = matrix.first()?.as_ref().len();
+    if cols == 0 {
+        return None;
+    }
+    let at = |row: usize, col: usize| &matrix[row].as_ref()[col];
+
+    let (mut low, mut high) = (0, cols - 1);
+    loop {
+        let mid = low + (high - low) / 2;
+        let row = (0..matrix.len()).max_by(|&a, &b| at(a, mid).cmp(at(b, mid)))?;
+        if mid > low && at(row, mid - 1) > at(row, mid) {
+            high = mid - 1;
+        } else if mid < high && at(row, mid + 1) > at(row, mid) {
+            low = mid + 1;
+        } else {
+            return Some((row, mid));
+        }
+    }
+}
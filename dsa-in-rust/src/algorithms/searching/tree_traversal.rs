@@ -0,0 +1,147 @@
+//! Traversal Iterators for Array-Encoded Binary Trees
+//!
+//! DEV NOTES:
+//! - Layout: root at index 0, children of node i at 2i+1 and 2i+2 (heap layout)
+//! - All iterators are lazy and iterative; none recurse
+//! - Each yields `(index, &element)` so callers can map results back to the slice
+//! - Level order of this layout is plain index order, so `LevelOrder` needs no queue
+//!
+//! PERFORMANCE CONSIDERATIONS:
+//! - Full traversal: O(n) time
+//! - Stack-based iterators: O(log n) space (tree height)
+//! - Level order: O(1) space
+//!
+//! # Examples
+//! ```
+//! use dsa_in_rust::algorithms::searching::tree_traversal::{in_order, post_order, pre_order};
+//!
+//! //      1
+//! //     / \
+//! //    2   3
+//! //   / \
+//! //  4   5
+//! let tree = [1, 2, 3, 4, 5];
+//! let values = |it: &mut dyn Iterator<Item = (usize, &i32)>| it.map(|(_, &x)| x).collect::<Vec<_>>();
+//! assert_eq!(values(&mut pre_order(&tree)), [1, 2, 4, 5, 3]);
+//! assert_eq!(values(&mut in_order(&tree)), [4, 2, 5, 1, 3]);
+//! assert_eq!(values(&mut post_order(&tree)), [4, 5, 2, 3, 1]);
+//! ```
+
+/// Pre-order (node, left, right) traversal iterator
+#[derive(Debug, Clone)]
+pub struct PreOrder<'a, T> {
+    arr: &'a [T],
+    stack: Vec<usize>,
+}
+
+/// In-order (left, node, right) traversal iterator
+#[derive(Debug, Clone)]
+pub struct InOrder<'a, T> {
+    arr: &'a [T],
+    stack: Vec<usize>,
+    current: usize,
+}
+
+/// Post-order (left, right, node) traversal iterator
+#[derive(Debug, Clone)]
+pub struct PostOrder<'a, T> {
+    arr: &'a [T],
+    /// Nodes to visit, flagged once their children have been pushed
+    stack: Vec<(usize, bool)>,
+}
+
+/// Level-order (breadth-first) traversal iterator
+#[derive(Debug, Clone)]
+pub struct LevelOrder<'a, T> {
+    arr: &'a [T],
+    next: usize,
+}
+
+/// Returns a pre-order iterator over an array-encoded binary tree
+pub fn pre_order<T>(arr: &[T]) -> PreOrder<'_, T> {
+    let stack = if arr.is_empty() { Vec::new() } else { vec![0] };
+    PreOrder { arr, stack }
+}
+
+/// Returns an in-order iterator over an array-encoded binary tree
+pub fn in_order<T>(arr: &[T]) -> InOrder<'_, T> {
+    InOrder { arr, stack: Vec::new(), current: 0 }
+}
+
+/// Returns a post-order iterator over an array-encoded binary tree
+pub fn post_order<T>(arr: &[T]) -> PostOrder<'_, T> {
+    let stack = if arr.is_empty() { Vec::new() } else { vec![(0, false)] };
+    PostOrder { arr, stack }
+}
+
+/// Returns a level-order iterator over an array-encoded binary tree
+pub fn level_order<T>(arr: &[T]) -> LevelOrder<'_, T> {
+    LevelOrder { arr, next: 0 }
+}
+
+impl<'a, T> Iterator for PreOrder<'a, T> {
+    type Item = (usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        // Push right first so the left subtree is visited first
+        for child in [2 * node + 2, 2 * node + 1] {
+            if child < self.arr.len() {
+                self.stack.push(child);
+            }
+        }
+        Some((node, &self.arr[node]))
+    }
+}
+
+impl<'a, T> Iterator for InOrder<'a, T> {
+    type Item = (usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Descend along left children, then emit the deepest pending node
+        while self.current < self.arr.len() {
+            self.stack.push(self.current);
+            self.current = 2 * self.current + 1;
+        }
+        let node = self.stack.pop()?;
+        self.current = 2 * node + 2;
+        Some((node, &self.arr[node]))
+    }
+}
+
+impl<'a, T> Iterator for PostOrder<'a, T> {
+    type Item = (usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (node, expanded) = self.stack.pop()?;
+            if expanded {
+                return Some((node, &self.arr[node]));
+            }
+            self.stack.push((node, true));
+            for child in [2 * node + 2, 2 * node + 1] {
+                if child < self.arr.len() {
+                    self.stack.push((child, false));
+                }
+            }
+        }
+    }
+}
+
+impl<'a, T> Iterator for LevelOrder<'a, T> {
+    type Item = (usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.next;
+        let item = self.arr.get(node)?;
+        self.next += 1;
+        Some((node, item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.arr.len().saturating_sub(self.next);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for LevelOrder<'_, T> {}
@@ -0,0 +1,168 @@
+//! Ternary and Golden-Section Search for Unimodal Functions
+//!
+//! DEV NOTES:
+//! - Both searches locate the optimum of a function that strictly improves up
+//!   to a single optimum and strictly worsens after it; a flat region is only
+//!   allowed at the optimum itself
+//! - `Goal` selects minimization or maximization, so callers never negate
+//!   their objective
+//! - Results carry the number of function evaluations, since objectives used
+//!   for tuning are usually expensive
+//! - Golden-section search reuses one interior point per iteration, so it needs
+//!   about 1.44 * log2(width / tolerance) evaluations; ternary search needs
+//!   about 3.4 * log2(width) because it re-evaluates both thirds
+//! - For a monotone yes/no question rather than an optimum, use
+//!   `binary_search::partition_point`
+//!
+//! PERFORMANCE CONSIDERATIONS:
+//! - Ternary: O(log n) evaluations over n integers, O(1) space
+//! - Golden section: O(log(width / tolerance)) evaluations, O(1) space
+
+use std::ops::RangeInclusive;
+
+/// 1 / phi, the fraction of the bracket kept per golden-section step
+const INV_PHI: f64 = 0.618_033_988_749_894_9;
+
+/// Upper bound on golden-section iterations, reached only with a zero tolerance
+const MAX_GOLDEN_ITERATIONS: usize = 200;
+
+/// Direction of optimization
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Goal {
+    Minimize,
+    Maximize,
+}
+
+impl Goal {
+    /// Returns true if `a` is strictly better than `b`
+    fn better<V: PartialOrd>(self, a: &V, b: &V) -> bool {
+        match self {
+            Goal::Minimize => a < b,
+            Goal::Maximize => a > b,
+        }
+    }
+}
+
+/// Location and value of an optimum
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Optimum<T, V> {
+    /// Argument at which the optimum was found
+    pub argument: T,
+    /// Function value at `argument`
+    pub value: V,
+    /// Number of function evaluations performed
+    pub evaluations: usize,
+}
+
+/// Ternary search for the optimum of a unimodal function over integers
+///
+/// # Arguments
+/// * `range` - Inclusive integer domain
+/// * `goal` - Whether to minimize or maximize
+/// * `f` - Unimodal objective
+///
+/// # Returns
+/// * `Option<Optimum<i64, V>>` - The optimum, None if `range` is empty
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::unimodal_search::{ternary_search, Goal};
+///
+/// // Thread count with the best throughput model
+/// let throughput = |threads: i64| 1000 * threads - 3 * threads * threads;
+/// let best = ternary_search(1..=1000, Goal::Maximize, throughput).unwrap();
+/// assert_eq!(best.argument, 167);
+/// ```
+pub fn ternary_search<V, F>(range: RangeInclusive<i64>, goal: Goal, mut f: F) -> Option<Optimum<i64, V>>
+where
+    V: PartialOrd,
+    F: FnMut(i64) -> V,
+{
+    if range.is_empty() {
+        return None;
+    }
+    let (mut low, mut high) = (*range.start() as i128, *range.end() as i128);
+    let mut evaluations = 0;
+
+    while high - low > 2 {
+        let third = (high - low) / 3;
+        let (left, right) = (low + third, high - third);
+        let (left_value, right_value) = (f(left as i64), f(right as i64));
+        evaluations += 2;
+        if goal.better(&left_value, &right_value) {
+            high = right - 1;
+        } else if goal.better(&right_value, &left_value) {
+            low = left + 1;
+        } else {
+            low = left;
+            high = right;
+        }
+    }
+
+    // At most three candidates remain
+    let mut best: Option<(i64, V)> = None;
+    for argument in low..=high {
+        let argument = argument as i64;
+        let value = f(argument);
+        evaluations += 1;
+        if best.as_ref().is_none_or(|(_, best)| goal.better(&value, best)) {
+            best = Some((argument, value));
+        }
+    }
+    best.map(|(argument, value)| Optimum { argument, value, evaluations })
+}
+
+/// Golden-section search for the optimum of a unimodal function over an interval
+///
+/// # Arguments
+/// * `range` - Inclusive interval to search
+/// * `tolerance` - Stop once the bracket is at most this wide
+/// * `goal` - Whether to minimize or maximize
+/// * `f` - Unimodal objective
+///
+/// # Returns
+/// * `Optimum<f64, f64>` - Midpoint of the final bracket and its value
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::searching::unimodal_search::{golden_section_search, Goal};
+///
+/// // Learning rate minimizing a validation loss model
+/// let loss = |rate: f64| (rate.ln() - 0.01f64.ln()).powi(2) + 0.5;
+/// let best = golden_section_search(1e-4..=1.0, 1e-9, Goal::Minimize, loss);
+/// assert!((best.argument - 0.01).abs() < 1e-6);
+/// ```
+pub fn golden_section_search<F>(range: RangeInclusive<f64>, tolerance: f64, goal: Goal, mut f: F) -> Optimum<f64, f64>
+where
+    F: FnMut(f64) -> f64,
+{
+    let (mut low, mut high) = (*range.start(), *range.end());
+    let mut left = high - INV_PHI * (high - low);
+    let mut right = low + INV_PHI * (high - low);
+    let (mut left_value, mut right_value) = (f(left), f(right));
+    let mut evaluations = 2;
+
+    for _ in 0..MAX_GOLDEN_ITERATIONS {
+        if high - low <= tolerance {
+            break;
+        }
+        if goal.better(&left_value, &right_value) {
+            // Optimum in [low, right]: the old left point becomes the new right point
+            high = right;
+            right = left;
+            right_value = left_value;
+            left = high - INV_PHI * (high - low);
+            left_value = f(left);
+        } else {
+            low = left;
+            left = right;
+            left_value = right_value;
+            right = low + INV_PHI * (high - low);
+            right_value = f(right);
+        }
+        evaluations += 1;
+    }
+
+    let argument = low + (high - low) / 2.0;
+    Optimum { argument, value: f(argument), evaluations: evaluations + 1 }
+}
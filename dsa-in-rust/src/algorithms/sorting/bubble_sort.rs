@@ -1,5 +1,7 @@
 // src/algorithms/sorting/bubble_sort.rs
 
+use std::cmp::Ordering;
+
 /// Optimized Bubble Sort implementation for generic types
 ///
 /// # Type Parameters
@@ -24,6 +26,28 @@
 /// - Stable: Yes
 /// - Adaptive: Yes (optimized for nearly sorted arrays)
 pub fn bubble_sort<T: Ord>(arr: &mut [T]) {
+    bubble_sort_by(arr, T::cmp);
+}
+
+/// Bubble Sort with a custom comparator
+///
+/// # Arguments
+/// * `arr` - A mutable slice to be sorted
+/// * `compare` - Comparator returning the `Ordering` of two elements
+///
+/// # Example
+///
+/// ```
+/// use dsa_in_rust::algorithms::sorting::bubble_sort::bubble_sort_by;
+///
+/// let mut data = [1, 5, 3, 4, 2];
+/// bubble_sort_by(&mut data, |a, b| b.cmp(a));
+/// assert_eq!(data, [5, 4, 3, 2, 1]);
+/// ```
+pub fn bubble_sort_by<T, F>(arr: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
     if arr.len() <= 1 {
         return;
     }
@@ -34,7 +58,7 @@ pub fn bubble_sort<T: Ord>(arr: &mut [T]) {
     loop {
         new_n = 0;
         for i in 1..n {
-            if arr[i - 1] > arr[i] {
+            if compare(&arr[i - 1], &arr[i]) == Ordering::Greater {
                 arr.swap(i - 1, i);
                 new_n = i;
             }
@@ -0,0 +1,87 @@
+// src/algorithms/sorting/counting_sort.rs
+
+//! Counting Sort Implementation
+//!
+//! DEV NOTES:
+//! - Counts occurrences over the key range [min, max] only
+//! - Stable: builds an index permutation and applies it in place
+//! - Best when the key range k is comparable to or smaller than n
+
+use super::radix_sort::{apply_permutation, RadixKey};
+
+/// Counting Sort for types with an order-preserving integer key
+///
+/// # Arguments
+/// * `arr` - A mutable slice of type T to be sorted
+///
+/// # Example
+///
+/// ```
+/// use dsa_in_rust::algorithms::sorting::counting_sort::counting_sort;
+///
+/// let mut data = [4, -2, 2, 8, 3, 3, -1];
+/// counting_sort(&mut data);
+/// assert_eq!(data, [-2, -1, 2, 3, 3, 4, 8]);
+/// ```
+///
+/// # Performance
+/// - Time Complexity: O(n + k) where k is the key range
+/// - Space Complexity: O(n + k)
+/// - Stable: Yes
+///
+/// # Panics
+/// Panics if the key range does not fit in memory; check the range first for
+/// sparse data or use `radix_sort` instead.
+pub fn counting_sort<T: RadixKey>(arr: &mut [T]) {
+    counting_sort_by_key(arr, T::radix_key);
+}
+
+/// Counting Sort using a key extraction function
+///
+/// # Arguments
+/// * `arr` - A mutable slice to be sorted
+/// * `key` - Function mapping each element to its `u64` sort key
+///
+/// # Example
+///
+/// ```
+/// use dsa_in_rust::algorithms::sorting::counting_sort::counting_sort_by_key;
+///
+/// let mut grades = [('b', 2), ('a', 1), ('c', 2), ('d', 0)];
+/// counting_sort_by_key(&mut grades, |g| g.1);
+/// assert_eq!(grades, [('d', 0), ('a', 1), ('b', 2), ('c', 2)]);
+/// ```
+pub fn counting_sort_by_key<T, F>(arr: &mut [T], key: F)
+where
+    F: Fn(&T) -> u64,
+{
+    if arr.len() <= 1 {
+        return;
+    }
+
+    let keys: Vec<u64> = arr.iter().map(&key).collect();
+    let min = *keys.iter().min().unwrap();
+    let max = *keys.iter().max().unwrap();
+    let range = usize::try_from(max - min).expect("key range too large for counting sort");
+
+    let mut counts = vec![0usize; range + 1];
+    for &k in &keys {
+        counts[(k - min) as usize] += 1;
+    }
+
+    let mut offset = 0;
+    for count in counts.iter_mut() {
+        let c = *count;
+        *count = offset;
+        offset += c;
+    }
+
+    let mut order = vec![0usize; arr.len()];
+    for (idx, &k) in keys.iter().enumerate() {
+        let slot = &mut counts[(k - min) as usize];
+        order[*slot] = idx;
+        *slot += 1;
+    }
+
+    apply_permutation(arr, order);
+}
@@ -0,0 +1,58 @@
+// src/algorithms/sorting/insertion_sort.rs
+
+use std::cmp::Ordering;
+
+/// Insertion Sort implementation for generic types
+///
+/// # Type Parameters
+/// * `T` - Type that implements Ord trait
+///
+/// # Arguments
+/// * `arr` - A mutable slice of type T to be sorted
+///
+/// # Example
+///
+/// ```
+/// use dsa_in_rust::algorithms::sorting::insertion_sort::insertion_sort;
+///
+/// let mut data = [64, 34, 25, 12, 22, 11, 90];
+/// insertion_sort(&mut data);
+/// assert_eq!(data, [11, 12, 22, 25, 34, 64, 90]);
+/// ```
+///
+/// # Performance
+/// - Time Complexity: O(n²) worst/average case, O(n) best case
+/// - Space Complexity: O(1)
+/// - Stable: Yes
+/// - Adaptive: Yes (O(n + k) where k is the number of inversions)
+pub fn insertion_sort<T: Ord>(arr: &mut [T]) {
+    insertion_sort_by(arr, T::cmp);
+}
+
+/// Insertion Sort with a custom comparator
+///
+/// # Arguments
+/// * `arr` - A mutable slice to be sorted
+/// * `compare` - Comparator returning the `Ordering` of two elements
+///
+/// # Example
+///
+/// ```
+/// use dsa_in_rust::algorithms::sorting::insertion_sort::insertion_sort_by;
+///
+/// let mut data = [1, 5, 3, 4, 2];
+/// insertion_sort_by(&mut data, |a, b| b.cmp(a));
+/// assert_eq!(data, [5, 4, 3, 2, 1]);
+/// ```
+pub fn insertion_sort_by<T, F>(arr: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    for i in 1..arr.len() {
+        let mut j = i;
+        while j > 0 && compare(&arr[j - 1], &arr[j]) == Ordering::Greater {
+            arr.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+}
@@ -0,0 +1,162 @@
+// src/algorithms/sorting/intro_sort.rs
+
+//! Introsort Implementation
+//!
+//! DEV NOTES:
+//! - Quicksort with median-of-three pivot selection
+//! - Falls back to heapsort once recursion depth exceeds 2 * log2(n)
+//! - Partitions smaller than `INSERTION_THRESHOLD` finish with insertion sort
+//! - Recurses into the smaller partition only, so stack depth is O(log n)
+
+use super::insertion_sort::insertion_sort_by;
+use std::cmp::Ordering;
+
+/// Partitions at or below this length are handed to insertion sort
+const INSERTION_THRESHOLD: usize = 16;
+
+/// Introsort implementation for generic types
+///
+/// # Type Parameters
+/// * `T` - Type that implements Ord trait
+///
+/// # Arguments
+/// * `arr` - A mutable slice of type T to be sorted
+///
+/// # Example
+///
+/// ```
+/// use dsa_in_rust::algorithms::sorting::intro_sort::intro_sort;
+///
+/// let mut data = [64, 34, 25, 12, 22, 11, 90];
+/// intro_sort(&mut data);
+/// assert_eq!(data, [11, 12, 22, 25, 34, 64, 90]);
+/// ```
+///
+/// # Performance
+/// - Time Complexity: O(n log n) worst/average case
+/// - Space Complexity: O(log n)
+/// - Stable: No
+pub fn intro_sort<T: Ord>(arr: &mut [T]) {
+    intro_sort_by(arr, T::cmp);
+}
+
+/// Introsort with a custom comparator
+///
+/// # Arguments
+/// * `arr` - A mutable slice to be sorted
+/// * `compare` - Comparator returning the `Ordering` of two elements
+///
+/// # Example
+///
+/// ```
+/// use dsa_in_rust::algorithms::sorting::intro_sort::intro_sort_by;
+///
+/// let mut data = ["pear", "fig", "banana"];
+/// intro_sort_by(&mut data, |a, b| a.len().cmp(&b.len()));
+/// assert_eq!(data, ["fig", "pear", "banana"]);
+/// ```
+pub fn intro_sort_by<T, F>(arr: &mut [T], mut compare: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if arr.len() <= 1 {
+        return;
+    }
+    let depth_limit = 2 * (usize::BITS - arr.len().leading_zeros()) as usize;
+    intro_sort_range(arr, depth_limit, &mut compare);
+}
+
+fn intro_sort_range<T, F>(mut arr: &mut [T], mut depth_limit: usize, compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    loop {
+        if arr.len() <= INSERTION_THRESHOLD {
+            insertion_sort_by(arr, &mut *compare);
+            return;
+        }
+        if depth_limit == 0 {
+            heap_sort_by(arr, compare);
+            return;
+        }
+        depth_limit -= 1;
+
+        let pivot = partition(arr, compare);
+        let (left, right) = arr.split_at_mut(pivot);
+        let right = &mut right[1..];
+
+        // Recurse into the smaller half, loop on the larger one
+        if left.len() < right.len() {
+            intro_sort_range(left, depth_limit, compare);
+            arr = right;
+        } else {
+            intro_sort_range(right, depth_limit, compare);
+            arr = left;
+        }
+    }
+}
+
+/// Lomuto partition around a median-of-three pivot; returns the pivot's final index
+fn partition<T, F>(arr: &mut [T], compare: &mut F) -> usize
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let last = arr.len() - 1;
+    let mid = arr.len() / 2;
+
+    // Order arr[0], arr[mid], arr[last] so the median lands in the middle
+    if compare(&arr[mid], &arr[0]) == Ordering::Less {
+        arr.swap(mid, 0);
+    }
+    if compare(&arr[last], &arr[0]) == Ordering::Less {
+        arr.swap(last, 0);
+    }
+    if compare(&arr[last], &arr[mid]) == Ordering::Less {
+        arr.swap(last, mid);
+    }
+    arr.swap(mid, last);
+
+    let mut store = 0;
+    for i in 0..last {
+        if compare(&arr[i], &arr[last]) == Ordering::Less {
+            arr.swap(i, store);
+            store += 1;
+        }
+    }
+    arr.swap(store, last);
+    store
+}
+
+/// Heapsort used as the worst-case fallback
+fn heap_sort_by<T, F>(arr: &mut [T], compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    for start in (0..arr.len() / 2).rev() {
+        sift_down(arr, start, arr.len(), compare);
+    }
+    for end in (1..arr.len()).rev() {
+        arr.swap(0, end);
+        sift_down(arr, 0, end, compare);
+    }
+}
+
+fn sift_down<T, F>(arr: &mut [T], mut root: usize, end: usize, compare: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    loop {
+        let mut child = 2 * root + 1;
+        if child >= end {
+            return;
+        }
+        if child + 1 < end && compare(&arr[child], &arr[child + 1]) == Ordering::Less {
+            child += 1;
+        }
+        if compare(&arr[root], &arr[child]) != Ordering::Less {
+            return;
+        }
+        arr.swap(root, child);
+        root = child;
+    }
+}
@@ -0,0 +1,227 @@
+// src/algorithms/sorting/natural_sort.rs
+
+//! Natural Sort Order for Mixed Alphanumeric Strings
+//!
+//! DEV NOTES:
+//! - Digit runs compare by numeric value, so `log9 < log10`
+//! - Numbers of any length are supported: runs are compared by significant
+//!   digit count first, then digit by digit, never parsed into an integer;
+//!   the runs are walked in place, so comparisons never allocate
+//! - Decimal digits from any script in `DIGIT_ZEROS` form digit runs
+//!   (e.g. Arabic-Indic, Devanagari, fullwidth)
+//! - A digit run sorts before any non-digit character
+//! - Ties are broken by the first leading-zero difference (`1 < 01`) or, when
+//!   case folding, the first case difference (`A < a`); strings still tied
+//!   (e.g. the same number in different scripts) fall back to code point
+//!   order, so only identical strings compare `Equal`
+//!
+//! All comparators take `&S` where `S: AsRef<str>`, so they plug directly into
+//! every `_by` sorter in this module.
+
+use super::intro_sort::intro_sort_by;
+use std::cmp::Ordering;
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// Code points of `0` for each supported block of ten consecutive decimal digits
+const DIGIT_ZEROS: [u32; 22] = [
+    0x0030, // ASCII
+    0x0660, // Arabic-Indic
+    0x06F0, // Extended Arabic-Indic
+    0x07C0, // NKo
+    0x0966, // Devanagari
+    0x09E6, // Bengali
+    0x0A66, // Gurmukhi
+    0x0AE6, // Gujarati
+    0x0B66, // Oriya
+    0x0BE6, // Tamil
+    0x0C66, // Telugu
+    0x0CE6, // Kannada
+    0x0D66, // Malayalam
+    0x0DE6, // Sinhala Lith
+    0x0E50, // Thai
+    0x0ED0, // Lao
+    0x0F20, // Tibetan
+    0x1040, // Myanmar
+    0x17E0, // Khmer
+    0x1810, // Mongolian
+    0xA8D0, // Saurashtra
+    0xFF10, // Fullwidth
+];
+
+/// Configurable natural-order comparator
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::sorting::natural_sort::NaturalOrder;
+/// use std::cmp::Ordering;
+///
+/// let order = NaturalOrder::new().ignore_case(true);
+/// assert_eq!(order.compare("File10", "file9"), Ordering::Greater);
+/// assert_eq!(order.compare("file", "File"), Ordering::Greater); // case tie-break
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NaturalOrder {
+    ignore_case: bool,
+}
+
+impl NaturalOrder {
+    /// Creates a case-sensitive natural-order comparator
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enables or disables Unicode case folding for non-digit characters
+    pub fn ignore_case(mut self, ignore_case: bool) -> Self {
+        self.ignore_case = ignore_case;
+        self
+    }
+
+    /// Compares two strings in natural order
+    ///
+    /// # Arguments
+    /// * `a` - Left-hand string
+    /// * `b` - Right-hand string
+    ///
+    /// # Returns
+    /// * `Ordering` - Total order; `Equal` only for identical strings
+    ///
+    /// # Performance
+    /// * Time Complexity: O(|a| + |b|)
+    /// * Space Complexity: O(1)
+    pub fn compare<S: AsRef<str> + ?Sized>(&self, a: &S, b: &S) -> Ordering {
+        let (a, b) = (a.as_ref(), b.as_ref());
+        let mut left = a.chars().peekable();
+        let mut right = b.chars().peekable();
+        let mut tie_break = Ordering::Equal;
+
+        loop {
+            let (l, r) = match (left.peek().copied(), right.peek().copied()) {
+                (None, None) => return tie_break.then_with(|| a.cmp(b)),
+                (None, Some(_)) => return Ordering::Less,
+                (Some(_), None) => return Ordering::Greater,
+                (Some(l), Some(r)) => (l, r),
+            };
+
+            match (digit_value(l), digit_value(r)) {
+                (Some(_), Some(_)) => {
+                    let (ord, zeros) = compare_digit_runs(&mut left, &mut right);
+                    if ord != Ordering::Equal {
+                        return ord;
+                    }
+                    tie_break = tie_break.then(zeros);
+                }
+                (Some(_), None) => return Ordering::Less,
+                (None, Some(_)) => return Ordering::Greater,
+                (None, None) => {
+                    left.next();
+                    right.next();
+                    if self.ignore_case {
+                        let ord = l.to_lowercase().cmp(r.to_lowercase());
+                        if ord != Ordering::Equal {
+                            return ord;
+                        }
+                        tie_break = tie_break.then(l.cmp(&r));
+                    } else if l != r {
+                        return l.cmp(&r);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Compares two strings in case-sensitive natural order
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::sorting::insertion_sort::insertion_sort_by;
+/// use dsa_in_rust::algorithms::sorting::natural_sort::natural_cmp;
+///
+/// let mut files = ["log10", "log9", "log1"];
+/// insertion_sort_by(&mut files, natural_cmp);
+/// assert_eq!(files, ["log1", "log9", "log10"]);
+/// ```
+pub fn natural_cmp<S: AsRef<str> + ?Sized>(a: &S, b: &S) -> Ordering {
+    NaturalOrder::new().compare(a, b)
+}
+
+/// Compares two strings in natural order with Unicode case folding
+pub fn natural_cmp_ignore_case<S: AsRef<str> + ?Sized>(a: &S, b: &S) -> Ordering {
+    NaturalOrder::new().ignore_case(true).compare(a, b)
+}
+
+/// Sorts strings in case-sensitive natural order
+///
+/// # Arguments
+/// * `arr` - A mutable slice of string-like values
+///
+/// # Example
+///
+/// ```
+/// use dsa_in_rust::algorithms::sorting::natural_sort::natural_sort;
+///
+/// let mut files = vec!["img12.png", "img10.png", "img2.png", "img1.png"];
+/// natural_sort(&mut files);
+/// assert_eq!(files, ["img1.png", "img2.png", "img10.png", "img12.png"]);
+/// ```
+///
+/// # Performance
+/// - Time Complexity: O(n log n) comparisons, each O(length of the strings)
+/// - Space Complexity: O(log n)
+pub fn natural_sort<S: AsRef<str>>(arr: &mut [S]) {
+    intro_sort_by(arr, natural_cmp);
+}
+
+/// Sorts strings using the given natural-order configuration
+///
+/// # Arguments
+/// * `arr` - A mutable slice of string-like values
+/// * `order` - Comparator configuration, e.g. with case folding enabled
+pub fn natural_sort_with<S: AsRef<str>>(arr: &mut [S], order: NaturalOrder) {
+    intro_sort_by(arr, |a, b| order.compare(a, b));
+}
+
+/// Returns the decimal value of `c` if it is a digit in a supported script
+fn digit_value(c: char) -> Option<u32> {
+    let code = c as u32;
+    DIGIT_ZEROS
+        .iter()
+        .find(|&&zero| (zero..zero + 10).contains(&code))
+        .map(|&zero| code - zero)
+}
+
+/// Consumes one digit run from each side and compares them numerically
+///
+/// Returns the numeric ordering and, separately, the leading-zero tie-break
+/// (fewer leading zeros first). Runs are compared in place: significant
+/// digits are counted on a cloned iterator, then walked in step.
+fn compare_digit_runs(left: &mut Peekable<Chars>, right: &mut Peekable<Chars>) -> (Ordering, Ordering) {
+    let zeros = skip_zeros(left).cmp(&skip_zeros(right));
+    let (left_len, right_len) = (digit_run_len(left), digit_run_len(right));
+
+    let mut ord = left_len.cmp(&right_len);
+    if ord == Ordering::Equal {
+        for (l, r) in left.by_ref().zip(right.by_ref()).take(left_len) {
+            ord = ord.then_with(|| digit_value(l).cmp(&digit_value(r)));
+        }
+    } else {
+        left.by_ref().take(left_len).for_each(drop);
+        right.by_ref().take(right_len).for_each(drop);
+    }
+    (ord, zeros)
+}
+
+/// Consumes the leading zeros of a digit run and returns how many there were
+fn skip_zeros(chars: &mut Peekable<Chars>) -> usize {
+    let mut zeros = 0;
+    while chars.next_if(|&c| digit_value(c) == Some(0)).is_some() {
+        zeros += 1;
+    }
+    zeros
+}
+
+/// Counts the digits at the front of `chars` without consuming them
+fn digit_run_len(chars: &Peekable<Chars>) -> usize {
+    chars.clone().map_while(digit_value).count()
+}
@@ -0,0 +1,145 @@
+// src/algorithms/sorting/radix_sort.rs
+
+//! LSD Radix Sort Implementation
+//!
+//! DEV NOTES:
+//! - Keys are mapped to order-preserving `u64` values via `RadixKey`
+//! - Sorts an index permutation one byte at a time, then applies it in place
+//! - Passes whose byte is identical for every key are skipped
+//! - Works on any `T` without requiring `Clone` or `Copy`
+
+/// Maps a value to an unsigned key whose natural order matches the value's order
+///
+/// Signed integers flip their sign bit so that negative values sort first.
+pub trait RadixKey {
+    /// Returns the order-preserving unsigned key for this value
+    fn radix_key(&self) -> u64;
+}
+
+macro_rules! impl_radix_key_unsigned {
+    ($($t:ty),*) => {
+        $(impl RadixKey for $t {
+            fn radix_key(&self) -> u64 {
+                *self as u64
+            }
+        })*
+    };
+}
+
+macro_rules! impl_radix_key_signed {
+    ($($t:ty => $u:ty),*) => {
+        $(impl RadixKey for $t {
+            fn radix_key(&self) -> u64 {
+                (*self as $u ^ (1 << (<$u>::BITS - 1))) as u64
+            }
+        })*
+    };
+}
+
+impl_radix_key_unsigned!(u8, u16, u32, u64, usize);
+impl_radix_key_signed!(i8 => u8, i16 => u16, i32 => u32, i64 => u64, isize => usize);
+
+impl RadixKey for char {
+    fn radix_key(&self) -> u64 {
+        *self as u64
+    }
+}
+
+impl RadixKey for bool {
+    fn radix_key(&self) -> u64 {
+        *self as u64
+    }
+}
+
+/// LSD Radix Sort for types with an order-preserving integer key
+///
+/// # Arguments
+/// * `arr` - A mutable slice of type T to be sorted
+///
+/// # Example
+///
+/// ```
+/// use dsa_in_rust::algorithms::sorting::radix_sort::radix_sort;
+///
+/// let mut data = [170, -45, 75, -90, 802, 24, 2, 66];
+/// radix_sort(&mut data);
+/// assert_eq!(data, [-90, -45, 2, 24, 66, 75, 170, 802]);
+/// ```
+///
+/// # Performance
+/// - Time Complexity: O(d * (n + 256)) where d is the number of key bytes
+/// - Space Complexity: O(n)
+/// - Stable: Yes
+pub fn radix_sort<T: RadixKey>(arr: &mut [T]) {
+    radix_sort_by_key(arr, T::radix_key);
+}
+
+/// LSD Radix Sort using a key extraction function
+///
+/// # Arguments
+/// * `arr` - A mutable slice to be sorted
+/// * `key` - Function mapping each element to its `u64` sort key
+///
+/// # Example
+///
+/// ```
+/// use dsa_in_rust::algorithms::sorting::radix_sort::radix_sort_by_key;
+///
+/// let mut words = ["ccc", "a", "bb"];
+/// radix_sort_by_key(&mut words, |w| w.len() as u64);
+/// assert_eq!(words, ["a", "bb", "ccc"]);
+/// ```
+pub fn radix_sort_by_key<T, F>(arr: &mut [T], key: F)
+where
+    F: Fn(&T) -> u64,
+{
+    if arr.len() <= 1 {
+        return;
+    }
+
+    let keys: Vec<u64> = arr.iter().map(&key).collect();
+    let mut order: Vec<usize> = (0..arr.len()).collect();
+    let mut buffer = vec![0usize; arr.len()];
+
+    for shift in (0..u64::BITS).step_by(8) {
+        let mut counts = [0usize; 256];
+        for &k in &keys {
+            counts[((k >> shift) & 0xFF) as usize] += 1;
+        }
+        // Every key shares this byte, nothing to reorder
+        if counts.contains(&keys.len()) {
+            continue;
+        }
+
+        let mut offset = 0;
+        for count in counts.iter_mut() {
+            let c = *count;
+            *count = offset;
+            offset += c;
+        }
+        for &idx in &order {
+            let digit = ((keys[idx] >> shift) & 0xFF) as usize;
+            buffer[counts[digit]] = idx;
+            counts[digit] += 1;
+        }
+        std::mem::swap(&mut order, &mut buffer);
+    }
+
+    apply_permutation(arr, order);
+}
+
+/// Rearranges `arr` so that position `i` holds the element previously at `order[i]`
+///
+/// Follows each permutation cycle with swaps, so no element is cloned.
+pub(crate) fn apply_permutation<T>(arr: &mut [T], mut order: Vec<usize>) {
+    for start in 0..order.len() {
+        let mut current = start;
+        while order[current] != start {
+            let next = order[current];
+            arr.swap(current, next);
+            order[current] = current;
+            current = next;
+        }
+        order[current] = current;
+    }
+}
@@ -0,0 +1,232 @@
+// src/algorithms/sorting/smart_sort.rs
+
+//! Auto-dispatching Hybrid Sort
+//!
+//! DEV NOTES:
+//! - One O(n) pass counts ascending runs and detects strictly descending input
+//! - A fixed-size sample estimates the duplicate ratio
+//! - Integer-like keys (via `SortKey`) expose their exact range in one more pass
+//! - Nearly sorted input tries a budgeted insertion sort before falling back
+//! - The decision and the measurements behind it are returned as a `SortReport`
+//!
+//! DISPATCH ORDER:
+//! 1. Already sorted -> nothing to do
+//! 2. Strictly descending -> reverse in place
+//! 3. Tiny (<= `SMALL_THRESHOLD`) -> insertion sort
+//! 4. Few runs -> insertion sort, unless it exceeds its move budget
+//! 5. Small key range or many duplicates over a modest range -> counting sort
+//! 6. Keyed input of at least `RADIX_THRESHOLD` elements -> radix sort
+//! 7. Everything else -> introsort
+
+use super::counting_sort::counting_sort_by_key;
+use super::insertion_sort::insertion_sort;
+use super::intro_sort::intro_sort;
+use super::radix_sort::{radix_sort_by_key, RadixKey};
+use std::cmp::Ordering;
+
+/// Inputs at or below this length always use insertion sort
+pub const SMALL_THRESHOLD: usize = 32;
+
+/// Minimum length at which radix sort beats introsort on keyed input
+pub const RADIX_THRESHOLD: usize = 256;
+
+/// Number of elements sampled to estimate the duplicate ratio
+const SAMPLE_SIZE: usize = 64;
+
+/// Types that `smart_sort` can profile
+///
+/// Integer-like types return an order-preserving key so that counting and
+/// radix sort become candidates. Any other `Ord` type can opt in with an
+/// empty impl and will be dispatched among the comparison sorts.
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::sorting::smart_sort::SortKey;
+///
+/// #[derive(PartialEq, Eq, PartialOrd, Ord)]
+/// struct Version(u32, u32);
+///
+/// impl SortKey for Version {}
+/// ```
+pub trait SortKey: Ord {
+    /// Returns an order-preserving `u64` key, or `None` for comparison-only types
+    fn sort_key(&self) -> Option<u64> {
+        None
+    }
+}
+
+macro_rules! impl_sort_key_radix {
+    ($($t:ty),*) => {
+        $(impl SortKey for $t {
+            fn sort_key(&self) -> Option<u64> {
+                Some(self.radix_key())
+            }
+        })*
+    };
+}
+
+impl_sort_key_radix!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, char, bool);
+
+impl SortKey for String {}
+impl SortKey for &str {}
+
+/// Sorting strategy chosen by `smart_sort`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortAlgorithm {
+    /// Input was already sorted
+    None,
+    /// Input was strictly descending and was reversed
+    Reverse,
+    Insertion,
+    Counting,
+    Radix,
+    Introsort,
+}
+
+/// Profile of the input and the algorithm `smart_sort` dispatched to
+#[derive(Debug, Clone, PartialEq)]
+pub struct SortReport {
+    /// Algorithm that produced the final order
+    pub algorithm: SortAlgorithm,
+    /// Number of elements sorted
+    pub len: usize,
+    /// Number of maximal non-descending runs in the input
+    pub runs: usize,
+    /// Fraction of sampled elements equal to a neighbour in sorted order
+    pub duplicate_ratio: f64,
+    /// `max - min` of the keys, when the type provides `SortKey::sort_key`
+    pub key_range: Option<u64>,
+    /// Whether the chosen algorithm preserves the order of equal elements
+    pub stable: bool,
+}
+
+/// Profiles the input and sorts it with the best-suited algorithm in the crate
+///
+/// # Arguments
+/// * `arr` - A mutable slice to be sorted
+///
+/// # Returns
+/// * `SortReport` - The measurements taken and the algorithm chosen
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::algorithms::sorting::smart_sort::{smart_sort, SortAlgorithm};
+///
+/// let mut data: Vec<i32> = (0..1000).map(|i| (i * 7919) % 10).collect();
+/// let report = smart_sort(&mut data);
+/// assert!(data.windows(2).all(|w| w[0] <= w[1]));
+/// assert_eq!(report.algorithm, SortAlgorithm::Counting);
+/// assert_eq!(report.key_range, Some(9));
+/// ```
+///
+/// # Performance
+/// * Profiling: O(n) time, O(1) extra space beyond the sample
+/// * Sorting: that of the chosen algorithm, O(n log n) worst case
+pub fn smart_sort<T: SortKey>(arr: &mut [T]) -> SortReport {
+    let len = arr.len();
+    let (runs, strictly_descending) = scan_runs(arr);
+
+    let mut report = SortReport {
+        algorithm: SortAlgorithm::None,
+        len,
+        runs,
+        duplicate_ratio: 0.0,
+        key_range: None,
+        stable: true,
+    };
+
+    if runs <= 1 {
+        return report;
+    }
+    if strictly_descending {
+        arr.reverse();
+        report.algorithm = SortAlgorithm::Reverse;
+        return report;
+    }
+    if len <= SMALL_THRESHOLD {
+        insertion_sort(arr);
+        report.algorithm = SortAlgorithm::Insertion;
+        return report;
+    }
+
+    report.duplicate_ratio = sample_duplicate_ratio(arr);
+    report.key_range = key_range(arr);
+
+    if runs <= len / SMALL_THRESHOLD && budgeted_insertion_sort(arr, 4 * len) {
+        report.algorithm = SortAlgorithm::Insertion;
+        return report;
+    }
+
+    report.algorithm = match report.key_range {
+        Some(range) if range < 2 * len as u64 => SortAlgorithm::Counting,
+        Some(range) if report.duplicate_ratio >= 0.5 && range < 8 * len as u64 => {
+            SortAlgorithm::Counting
+        }
+        Some(_) if len >= RADIX_THRESHOLD => SortAlgorithm::Radix,
+        _ => SortAlgorithm::Introsort,
+    };
+
+    match report.algorithm {
+        SortAlgorithm::Counting => counting_sort_by_key(arr, |x| x.sort_key().unwrap()),
+        SortAlgorithm::Radix => radix_sort_by_key(arr, |x| x.sort_key().unwrap()),
+        _ => {
+            intro_sort(arr);
+            report.stable = false;
+        }
+    }
+    report
+}
+
+/// Counts non-descending runs and checks for a strictly descending sequence
+fn scan_runs<T: Ord>(arr: &[T]) -> (usize, bool) {
+    if arr.is_empty() {
+        return (0, false);
+    }
+    let mut runs = 1;
+    let mut strictly_descending = true;
+    for pair in arr.windows(2) {
+        match pair[0].cmp(&pair[1]) {
+            Ordering::Greater => runs += 1,
+            _ => strictly_descending = false,
+        }
+    }
+    (runs, strictly_descending)
+}
+
+/// Estimates the duplicate ratio from evenly spaced sample positions
+fn sample_duplicate_ratio<T: Ord>(arr: &[T]) -> f64 {
+    let step = (arr.len() / SAMPLE_SIZE).max(1);
+    let mut sample: Vec<&T> = arr.iter().step_by(step).take(SAMPLE_SIZE).collect();
+    insertion_sort(&mut sample);
+    let duplicates = sample.windows(2).filter(|w| w[0] == w[1]).count();
+    duplicates as f64 / sample.len() as f64
+}
+
+/// Returns `max - min` of the keys, or `None` if the type is comparison-only
+fn key_range<T: SortKey>(arr: &[T]) -> Option<u64> {
+    let mut keys = arr.iter().map(SortKey::sort_key);
+    let first = keys.next()??;
+    let (min, max) = keys.try_fold((first, first), |(min, max), k| {
+        k.map(|k| (min.min(k), max.max(k)))
+    })?;
+    Some(max - min)
+}
+
+/// Insertion sort that gives up once it has performed `budget` element moves
+///
+/// Returns `true` if the slice ended up fully sorted. On `false` the slice is
+/// a permutation of the input and can be handed to another algorithm.
+fn budgeted_insertion_sort<T: Ord>(arr: &mut [T], mut budget: usize) -> bool {
+    for i in 1..arr.len() {
+        let mut j = i;
+        while j > 0 && arr[j - 1] > arr[j] {
+            if budget == 0 {
+                return false;
+            }
+            arr.swap(j - 1, j);
+            budget -= 1;
+            j -= 1;
+        }
+    }
+    true
+}
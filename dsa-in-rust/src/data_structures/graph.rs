@@ -5,33 +5,79 @@
 //! - Optimized for sparse graphs (E << V²)
 //! - Thread-safe operations with validation checks
 //! - Memory efficient: O(V + E) space complexity
+//! - `Graph<N, E>` carries a payload of type `N` per vertex (names, labels)
+//!   and of type `E` per edge (weights, capacities); both default to `()`, so
+//!   plain `Graph` is the unweighted `Graph<(), ()>` and costs nothing extra
+//! - Edge payloads are stored once per edge in an edge list that the
+//!   adjacency lists point into
+//! - Vertices can be found by payload through an optional label index
+//!   (`build_label_index`); mutations keep it up to date, except
+//!   `node_weight_mut`, which drops it (lookups then fall back to a scan)
+//! - Directed by default; an undirected graph (`GraphKind::Undirected`) lists
+//!   each edge in both endpoints' adjacency lists but stores and counts it once
+//! - Vertices stay dense (`0..node_count()`): `remove_node` moves the last
+//!   vertex into the freed index and reports this as a `NodeRemapping`;
+//!   `remove_edge` likewise moves the last edge of the edge list into the gap
 //! 
 //! PERFORMANCE CONSIDERATIONS:
 //! - Edge Addition: O(1)
 //! - Edge Lookup: O(deg(v))
+//! - Edge Removal: O(deg(src) + deg(dest))
+//! - Node Addition: O(1) amortized; Node Removal: O(V + E)
 //! - Memory Usage: O(V + E)
 //! - Thread Safety: Yes
 //! 
 //! # Examples
 //! ```
-//! use dsa_in_rust::data_structures::graph::Graph;
+//! use dsa_in_rust::data_structures::graph::{Graph, GraphKind};
 //! 
 //! let mut graph = Graph::new(5);
 //! graph.add_edge(0, 1).unwrap();
 //! graph.add_edge(1, 2).unwrap();
 //! assert!(graph.has_path(0, 2).unwrap());
+//!
+//! // Weighted: a small road network with travel times
+//! let mut roads: Graph<(), u32> = Graph::weighted(3);
+//! roads.add_weighted_edge(0, 1, 7).unwrap();
+//! roads.add_weighted_edge(1, 2, 3).unwrap();
+//! assert_eq!(roads.edge_weight(0, 1), Some(&7));
+//!
+//! // Payloads: named stations, looked up by name
+//! let mut metro: Graph<&str, u32> = Graph::from_nodes(["Central", "Harbour", "Airport"], GraphKind::Undirected);
+//! metro.build_label_index();
+//! let (central, airport) = (metro.node_index(&"Central").unwrap(), metro.node_index(&"Airport").unwrap());
+//! metro.add_weighted_edge(central, airport, 25).unwrap();
+//! *metro.edge_weight_mut(airport, central).unwrap() += 5;
+//! assert_eq!(metro.edge_weight(central, airport), Some(&30));
+//! assert_eq!(metro.node_weight(airport), Some(&"Airport"));
+//!
+//! // Undirected: edges can be followed both ways
+//! let mut links = Graph::undirected(3);
+//! links.add_edge(0, 1).unwrap();
+//! assert!(links.has_edge(1, 0));
+//! assert_eq!(links.edge_count(), 1);
 //! ```
 
-use std::collections::{HashSet, VecDeque};
+use super::graph_view::{GraphView, WeightedGraphView};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::error::Error;
 use std::fmt;
+use std::hash::Hash;
+use std::iter::{Copied, FusedIterator, Map, Zip};
+use std::slice::Iter;
 
 /// Custom error type for graph operations
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum GraphError {
     InvalidNode(usize),
     EdgeExists(usize, usize),
     NoPath(usize, usize),
+    NegativeWeight(usize, usize),
+    NegativeCycle(Vec<usize>),
+    Cycle(Vec<usize>),
+    DirectedGraph,
+    EdgeNotFound(usize, usize),
+    SourceIsSink(usize),
 }
 
 impl fmt::Display for GraphError {
@@ -40,18 +86,120 @@ impl fmt::Display for GraphError {
             GraphError::InvalidNode(node) => write!(f, "Invalid node index: {}", node),
             GraphError::EdgeExists(src, dest) => write!(f, "Edge already exists: {} -> {}", src, dest),
             GraphError::NoPath(src, dest) => write!(f, "No path exists: {} -> {}", src, dest),
+            GraphError::NegativeWeight(src, dest) => write!(f, "Negative edge weight: {} -> {}", src, dest),
+            GraphError::NegativeCycle(cycle) => write_cycle(f, "Negative cycle", cycle),
+            GraphError::Cycle(cycle) => write_cycle(f, "Cycle detected", cycle),
+            GraphError::DirectedGraph => write!(f, "Operation requires an undirected graph"),
+            GraphError::EdgeNotFound(src, dest) => write!(f, "Edge not found: {} -> {}", src, dest),
+            GraphError::SourceIsSink(node) => write!(f, "Source and sink are the same node: {}", node),
         }
     }
 }
 
+/// Writes a cycle as a closed walk, e.g. `1 -> 3 -> 2 -> 1`
+fn write_cycle(f: &mut fmt::Formatter, label: &str, cycle: &[usize]) -> fmt::Result {
+    write!(f, "{}: ", label)?;
+    for node in cycle {
+        write!(f, "{} -> ", node)?;
+    }
+    write!(f, "{}", cycle.first().map_or(String::new(), |node| node.to_string()))
+}
+
 impl Error for GraphError {}
 
+/// Whether edges have a direction
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GraphKind {
+    /// `add_edge(u, v)` only allows moving from `u` to `v`
+    #[default]
+    Directed,
+    /// `add_edge(u, v)` connects `u` and `v` both ways
+    Undirected,
+}
+
+/// How vertex indices changed after `Graph::remove_node`
+///
+/// The removed index is reused by the vertex that used to be last, so at most
+/// one surviving vertex changes its index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodeRemapping {
+    removed: usize,
+    moved_from: Option<usize>,
+    removed_edges: usize,
+}
+
+impl NodeRemapping {
+    /// Returns the index of the removed vertex
+    pub fn removed(&self) -> usize {
+        self.removed
+    }
+
+    /// Returns the old index of the vertex now stored at `removed()`, if any moved
+    pub fn moved_from(&self) -> Option<usize> {
+        self.moved_from
+    }
+
+    /// Returns the number of edges removed along with the vertex
+    pub fn removed_edges(&self) -> usize {
+        self.removed_edges
+    }
+
+    /// Translates an index from before the removal to the current one
+    ///
+    /// # Returns
+    /// * `Option<usize>` - The new index; None for the removed vertex
+    pub fn new_index(&self, old: usize) -> Option<usize> {
+        if old == self.removed {
+            None
+        } else if Some(old) == self.moved_from {
+            Some(self.removed)
+        } else {
+            Some(old)
+        }
+    }
+}
+
 /// Graph representation using adjacency list
+///
+/// `N` is the node payload type and `E` the edge weight type; the defaults
+/// `()` give an unweighted graph without node data, so `Graph` on its own
+/// means `Graph<(), ()>`.
 #[derive(Debug, Clone)]
-pub struct Graph {
+pub struct Graph<N = (), E = ()> {
+    /// Payload of each vertex
+    node_weights: Vec<N>,
     adjacency_list: Vec<Vec<usize>>,
-    node_count: usize,
-    edge_count: usize,
+    /// `edge_ids[v][i]` indexes `edge_list` for the edge to `adjacency_list[v][i]`
+    edge_ids: Vec<Vec<usize>>,
+    /// Every edge once, as `(src, dest, weight)` in insertion order
+    edge_list: Vec<(usize, usize, E)>,
+    kind: GraphKind,
+    /// Payload -> vertices map, present after `build_label_index`
+    label_index: Option<LabelIndex<N>>,
+}
+
+/// Map from node payloads to the vertices carrying them
+///
+/// The graph's general methods cannot hash `N`, so the map carries its own
+/// update functions, created where `N: Hash + Eq + Clone` is known. Labels
+/// may repeat, so each maps to every vertex that carries it.
+#[derive(Debug, Clone)]
+struct LabelIndex<N> {
+    map: HashMap<N, Vec<usize>>,
+    insert_entry: fn(&mut HashMap<N, Vec<usize>>, &N, usize),
+    remove_entry: fn(&mut HashMap<N, Vec<usize>>, &N, usize) -> bool,
+}
+
+impl<N> LabelIndex<N> {
+    /// Records that `node` carries `label`
+    fn insert(&mut self, label: &N, node: usize) {
+        (self.insert_entry)(&mut self.map, label, node)
+    }
+
+    /// Forgets that `node` carries `label`; returns whether it was recorded
+    fn remove(&mut self, label: &N, node: usize) -> bool {
+        (self.remove_entry)(&mut self.map, label, node)
+    }
 }
 
 impl Graph {
@@ -67,13 +215,22 @@ impl Graph {
     /// * Time Complexity: O(n)
     /// * Space Complexity: O(n)
     pub fn new(nodes: usize) -> Self {
-        Self {
-            adjacency_list: vec![Vec::with_capacity(nodes); nodes],
-            node_count: nodes,
-            edge_count: 0,
-        }
+        Self::weighted(nodes)
+    }
+
+    /// Creates a new undirected graph with specified number of nodes
+    /// 
+    /// # Arguments
+    /// * `nodes` - Number of nodes in the graph
+    /// 
+    /// # Returns
+    /// * `Graph` - New undirected graph instance
+    pub fn undirected(nodes: usize) -> Self {
+        Self::with_kind(nodes, GraphKind::Undirected)
     }
+}
 
+impl<N> Graph<N, ()> {
     /// Adds a directed edge from src to dest
     /// 
     /// # Arguments
@@ -87,8 +244,157 @@ impl Graph {
     /// * Time Complexity: O(1) amortized
     /// * Space Complexity: O(1)
     pub fn add_edge(&mut self, src: usize, dest: usize) -> Result<(), GraphError> {
+        self.add_weighted_edge(src, dest, ())
+    }
+}
+
+impl<N: Default, E> Graph<N, E> {
+    /// Creates a new graph with specified number of nodes and edge weights of type `E`
+    /// 
+    /// Every node starts with the default payload.
+    /// 
+    /// # Arguments
+    /// * `nodes` - Number of nodes in the graph
+    /// 
+    /// # Returns
+    /// * `Graph<N, E>` - New graph instance without edges
+    /// 
+    /// # Performance
+    /// * Time Complexity: O(n)
+    /// * Space Complexity: O(n)
+    pub fn weighted(nodes: usize) -> Self {
+        Self::with_kind(nodes, GraphKind::Directed)
+    }
+
+    /// Creates a new graph of the given kind with edge weights of type `E`
+    /// 
+    /// # Arguments
+    /// * `nodes` - Number of nodes in the graph
+    /// * `kind` - Directed or undirected
+    /// 
+    /// # Returns
+    /// * `Graph<N, E>` - New graph instance without edges
+    /// 
+    /// # Examples
+    /// ```
+    /// use dsa_in_rust::data_structures::graph::{Graph, GraphKind};
+    /// 
+    /// let mut cables: Graph<(), u32> = Graph::with_kind(3, GraphKind::Undirected);
+    /// cables.add_weighted_edge(0, 2, 40).unwrap();
+    /// assert_eq!(cables.edge_weight(2, 0), Some(&40));
+    /// assert!(cables.add_weighted_edge(2, 0, 10).is_err());
+    /// ```
+    pub fn with_kind(nodes: usize, kind: GraphKind) -> Self {
+        Self::from_nodes((0..nodes).map(|_| N::default()), kind)
+    }
+
+    /// Appends a new vertex with the default payload and no edges
+    /// 
+    /// # Returns
+    /// * `usize` - Index of the new vertex (the previous `node_count()`)
+    /// 
+    /// # Performance
+    /// * Time Complexity: O(1) amortized
+    pub fn add_node(&mut self) -> usize {
+        self.add_weighted_node(N::default())
+    }
+}
+
+impl<N, E> Graph<N, E> {
+    /// Creates a graph without edges whose vertices carry the given payloads
+    /// 
+    /// # Arguments
+    /// * `nodes` - Payload of each vertex, in index order
+    /// * `kind` - Directed or undirected
+    /// 
+    /// # Returns
+    /// * `Graph<N, E>` - New graph instance without edges
+    /// 
+    /// # Performance
+    /// * Time Complexity: O(n)
+    /// * Space Complexity: O(n)
+    pub fn from_nodes(nodes: impl IntoIterator<Item = N>, kind: GraphKind) -> Self {
+        let node_weights: Vec<N> = nodes.into_iter().collect();
+        let count = node_weights.len();
+        Self {
+            node_weights,
+            adjacency_list: vec![Vec::new(); count],
+            edge_ids: vec![Vec::new(); count],
+            edge_list: Vec::new(),
+            kind,
+            label_index: None,
+        }
+    }
+
+    /// Appends a new vertex carrying `weight` and no edges
+    /// 
+    /// # Returns
+    /// * `usize` - Index of the new vertex (the previous `node_count()`)
+    /// 
+    /// # Performance
+    /// * Time Complexity: O(1) amortized
+    pub fn add_weighted_node(&mut self, weight: N) -> usize {
+        let node = self.node_count();
+        if let Some(index) = &mut self.label_index {
+            index.insert(&weight, node);
+        }
+        self.node_weights.push(weight);
+        self.adjacency_list.push(Vec::new());
+        self.edge_ids.push(Vec::new());
+        node
+    }
+
+    /// Returns the payload of `node`, None if it does not exist
+    pub fn node_weight(&self, node: usize) -> Option<&N> {
+        self.node_weights.get(node)
+    }
+
+    /// Returns the payload of `node` for modification, None if it does not exist
+    /// 
+    /// Payloads may be changed arbitrarily through the reference, so this
+    /// drops the label index; use `set_node_weight` to keep it.
+    pub fn node_weight_mut(&mut self, node: usize) -> Option<&mut N> {
+        self.label_index = None;
+        self.node_weights.get_mut(node)
+    }
+
+    /// Replaces the payload of `node`, keeping the label index up to date
+    /// 
+    /// # Returns
+    /// * `Result<N, GraphError>` - The previous payload; Err(InvalidNode) if
+    ///   `node` does not exist
+    pub fn set_node_weight(&mut self, node: usize, weight: N) -> Result<N, GraphError> {
+        let slot = self.node_weights.get_mut(node).ok_or(GraphError::InvalidNode(node))?;
+        if let Some(index) = &mut self.label_index {
+            index.remove(slot, node);
+            index.insert(&weight, node);
+        }
+        Ok(std::mem::replace(slot, weight))
+    }
+
+    /// Returns the payloads of all vertices in index order
+    pub fn node_weights(&self) -> &[N] {
+        &self.node_weights
+    }
+
+    /// Adds an edge from src to dest carrying `weight`
+    /// 
+    /// In an undirected graph the edge also leads from dest to src.
+    /// 
+    /// # Arguments
+    /// * `src` - Source node index
+    /// * `dest` - Destination node index
+    /// * `weight` - Edge weight
+    /// 
+    /// # Returns
+    /// * `Result<(), GraphError>` - Ok if edge added, Err otherwise
+    /// 
+    /// # Performance
+    /// * Time Complexity: O(deg(src))
+    /// * Space Complexity: O(1)
+    pub fn add_weighted_edge(&mut self, src: usize, dest: usize, weight: E) -> Result<(), GraphError> {
         // Validate nodes
-        if src >= self.node_count || dest >= self.node_count {
+        if src >= self.node_count() || dest >= self.node_count() {
             return Err(GraphError::InvalidNode(src.max(dest)));
         }
 
@@ -98,11 +404,213 @@ impl Graph {
         }
 
         // Add edge
+        let id = self.edge_list.len();
+        self.edge_list.push((src, dest, weight));
         self.adjacency_list[src].push(dest);
-        self.edge_count += 1;
+        self.edge_ids[src].push(id);
+        if self.kind == GraphKind::Undirected && src != dest {
+            self.adjacency_list[dest].push(src);
+            self.edge_ids[dest].push(id);
+        }
         Ok(())
     }
 
+    /// Removes the edge from src to dest (in an undirected graph, either direction)
+    /// 
+    /// # Arguments
+    /// * `src` - Source node index
+    /// * `dest` - Destination node index
+    /// 
+    /// # Returns
+    /// * `Result<E, GraphError>` - The weight of the removed edge;
+    ///   Err(EdgeNotFound) if there is no such edge, Err(InvalidNode) for bad nodes
+    /// 
+    /// # Examples
+    /// ```
+    /// use dsa_in_rust::data_structures::graph::{Graph, GraphError};
+    /// 
+    /// let mut roads: Graph<(), u32> = Graph::weighted(3);
+    /// roads.add_weighted_edge(0, 1, 7).unwrap();
+    /// roads.add_weighted_edge(1, 2, 3).unwrap();
+    /// assert_eq!(roads.remove_edge(0, 1), Ok(7));
+    /// assert_eq!(roads.remove_edge(0, 1), Err(GraphError::EdgeNotFound(0, 1)));
+    /// assert_eq!(roads.edge_count(), 1);
+    /// ```
+    /// 
+    /// # Performance
+    /// * Time Complexity: O(deg(src) + deg(dest))
+    /// * Space Complexity: O(1)
+    pub fn remove_edge(&mut self, src: usize, dest: usize) -> Result<E, GraphError> {
+        if src >= self.node_count() || dest >= self.node_count() {
+            return Err(GraphError::InvalidNode(src.max(dest)));
+        }
+        let index = self.adjacency_list[src]
+            .iter()
+            .position(|&next| next == dest)
+            .ok_or(GraphError::EdgeNotFound(src, dest))?;
+        Ok(self.remove_edge_id(self.edge_ids[src][index]))
+    }
+
+    /// Removes a vertex and every edge touching it
+    /// 
+    /// The last vertex takes over the removed index, so indices stay dense.
+    /// 
+    /// # Arguments
+    /// * `node` - Index of the vertex to remove
+    /// 
+    /// # Returns
+    /// * `Result<(N, NodeRemapping), GraphError>` - The removed payload, and
+    ///   which vertex moved and how many edges were removed; Err(InvalidNode)
+    ///   if `node` does not exist
+    /// 
+    /// # Examples
+    /// ```
+    /// use dsa_in_rust::data_structures::graph::Graph;
+    /// 
+    /// let mut graph = Graph::new(4);
+    /// graph.add_edge(0, 1).unwrap();
+    /// graph.add_edge(1, 3).unwrap();
+    /// graph.add_edge(3, 2).unwrap();
+    /// 
+    /// let (_, remapping) = graph.remove_node(1).unwrap();
+    /// assert_eq!(remapping.moved_from(), Some(3));
+    /// assert_eq!(remapping.removed_edges(), 2);
+    /// assert_eq!(remapping.new_index(3), Some(1));
+    /// assert_eq!(graph.node_count(), 3);
+    /// assert!(graph.has_edge(1, 2));  // formerly 3 -> 2
+    /// ```
+    /// 
+    /// # Performance
+    /// * Time Complexity: O(V + E)
+    /// * Space Complexity: O(deg(node))
+    pub fn remove_node(&mut self, node: usize) -> Result<(N, NodeRemapping), GraphError> {
+        if node >= self.node_count() {
+            return Err(GraphError::InvalidNode(node));
+        }
+        // Descending ids: swap-removing one never moves another edge still to be removed
+        let mut incident: Vec<usize> = (0..self.edge_list.len())
+            .filter(|&id| self.edge_list[id].0 == node || self.edge_list[id].1 == node)
+            .collect();
+        incident.reverse();
+        for &id in &incident {
+            self.remove_edge_id(id);
+        }
+
+        let last = self.node_count() - 1;
+        if let Some(index) = &mut self.label_index {
+            index.remove(&self.node_weights[node], node);
+        }
+        let weight = self.node_weights.swap_remove(node);
+        self.adjacency_list.swap_remove(node);
+        self.edge_ids.swap_remove(node);
+        if node != last {
+            if let Some(index) = &mut self.label_index {
+                if index.remove(&self.node_weights[node], last) {
+                    index.insert(&self.node_weights[node], node);
+                }
+            }
+            let rename = |vertex: &mut usize| {
+                if *vertex == last {
+                    *vertex = node;
+                }
+            };
+            self.adjacency_list.iter_mut().flatten().for_each(rename);
+            for (src, dest, _) in &mut self.edge_list {
+                rename(src);
+                rename(dest);
+            }
+        }
+        let remapping = NodeRemapping { removed: node, moved_from: (node != last).then_some(last), removed_edges: incident.len() };
+        Ok((weight, remapping))
+    }
+
+    /// Removes an edge by id, keeping `edge_list` dense by moving the last edge into its slot
+    fn remove_edge_id(&mut self, id: usize) -> E {
+        let (src, dest, _) = self.edge_list[id];
+        self.unlink(src, id);
+        if self.kind == GraphKind::Undirected && src != dest {
+            self.unlink(dest, id);
+        }
+
+        let (_, _, weight) = self.edge_list.swap_remove(id);
+        if let Some(&(moved_src, moved_dest, _)) = self.edge_list.get(id) {
+            let moved = self.edge_list.len();
+            let mirrored = self.kind == GraphKind::Undirected && moved_src != moved_dest;
+            for endpoint in [Some(moved_src), mirrored.then_some(moved_dest)].into_iter().flatten() {
+                for edge_id in &mut self.edge_ids[endpoint] {
+                    if *edge_id == moved {
+                        *edge_id = id;
+                    }
+                }
+            }
+        }
+        weight
+    }
+
+    /// Drops edge `id` from the adjacency list of `node`, keeping neighbour order
+    fn unlink(&mut self, node: usize, id: usize) {
+        let index = self.edge_ids[node].iter().position(|&edge_id| edge_id == id).expect("edge is listed at its endpoint");
+        self.adjacency_list[node].remove(index);
+        self.edge_ids[node].remove(index);
+    }
+
+    /// Returns the weight of the edge from `from` to `to`, if present
+    /// 
+    /// # Performance
+    /// * Time Complexity: O(deg(from))
+    pub fn edge_weight(&self, from: usize, to: usize) -> Option<&E> {
+        let index = self.adjacency_list.get(from)?.iter().position(|&next| next == to)?;
+        Some(&self.edge_list[self.edge_ids[from][index]].2)
+    }
+
+    /// Returns the weight of the edge from `from` to `to` for modification, if present
+    /// 
+    /// In an undirected graph the change is seen from both endpoints.
+    /// 
+    /// # Performance
+    /// * Time Complexity: O(deg(from))
+    pub fn edge_weight_mut(&mut self, from: usize, to: usize) -> Option<&mut E> {
+        let index = self.adjacency_list.get(from)?.iter().position(|&next| next == to)?;
+        Some(&mut self.edge_list[self.edge_ids[from][index]].2)
+    }
+
+    /// Returns the outgoing edges of `node` as `(neighbour, &weight)` pairs
+    /// 
+    /// # Panics
+    /// Panics if `node >= node_count()`
+    pub fn edges(&self, node: usize) -> Edges<'_, E> {
+        Edges {
+            neighbors: self.adjacency_list[node].iter().zip(self.edge_ids[node].iter()),
+            edge_list: &self.edge_list,
+        }
+    }
+
+    /// Returns every edge once as `(src, dest, &weight)`, in insertion order
+    /// (except that `remove_edge` moves the last edge into the removed one's place)
+    /// 
+    /// # Examples
+    /// ```
+    /// use dsa_in_rust::data_structures::graph::Graph;
+    /// 
+    /// let mut graph = Graph::undirected(3);
+    /// graph.add_edge(2, 0).unwrap();
+    /// graph.add_edge(0, 1).unwrap();
+    /// assert_eq!(graph.all_edges().collect::<Vec<_>>(), [(2, 0, &()), (0, 1, &())]);
+    /// ```
+    pub fn all_edges(&self) -> impl ExactSizeIterator<Item = (usize, usize, &E)> + '_ {
+        self.edge_list.iter().map(|(src, dest, weight)| (*src, *dest, weight))
+    }
+
+    /// Returns whether the graph is directed or undirected
+    pub fn kind(&self) -> GraphKind {
+        self.kind
+    }
+
+    /// Returns true for a directed graph
+    pub fn is_directed(&self) -> bool {
+        self.kind == GraphKind::Directed
+    }
+
     /// Checks if a path exists between src and dest
     /// 
     /// # Arguments
@@ -117,7 +625,7 @@ impl Graph {
     /// * Space Complexity: O(V)
     pub fn has_path(&self, src: usize, dest: usize) -> Result<bool, GraphError> {
         // Validate nodes
-        if src >= self.node_count || dest >= self.node_count {
+        if src >= self.node_count() || dest >= self.node_count() {
             return Err(GraphError::InvalidNode(src.max(dest)));
         }
 
@@ -142,12 +650,12 @@ impl Graph {
 
     /// Returns the number of nodes in the graph
     pub fn node_count(&self) -> usize {
-        self.node_count
+        self.adjacency_list.len()
     }
 
-    /// Returns the number of edges in the graph
+    /// Returns the number of edges in the graph; an undirected edge counts once
     pub fn edge_count(&self) -> usize {
-        self.edge_count
+        self.edge_list.len()
     }
     
     /// Checks if an edge exists between two nodes
@@ -171,11 +679,116 @@ impl Graph {
     /// graph.add_edge(0, 1).unwrap();
     /// assert!(graph.has_edge(0, 1));
     /// assert!(!graph.has_edge(1, 0));  // Directed graph
+    /// 
+    /// let mut undirected = Graph::undirected(5);
+    /// undirected.add_edge(0, 1).unwrap();
+    /// assert!(undirected.has_edge(1, 0));
     /// ```
     pub fn has_edge(&self, from: usize, to: usize) -> bool {
-        if from >= self.node_count || to >= self.node_count {
+        if from >= self.node_count() || to >= self.node_count() {
             return false;
         }
         self.adjacency_list[from].contains(&to)
     }
 }
+
+impl<N: Hash + Eq + Clone, E> Graph<N, E> {
+    /// Builds the label index so `node_index` runs in O(1)
+    /// 
+    /// Labels may repeat; `node_index` then returns the lowest vertex that
+    /// carries the label, exactly as it does without the index.
+    /// 
+    /// # Performance
+    /// * Time Complexity: O(V)
+    /// * Space Complexity: O(V)
+    pub fn build_label_index(&mut self) {
+        let mut map: HashMap<N, Vec<usize>> = HashMap::new();
+        for (node, label) in self.node_weights.iter().enumerate() {
+            map.entry(label.clone()).or_default().push(node);
+        }
+        self.label_index = Some(LabelIndex {
+            map,
+            insert_entry: |map, label, node| {
+                map.entry(label.clone()).or_default().push(node);
+            },
+            remove_entry: |map, label, node| {
+                let Some(nodes) = map.get_mut(label) else { return false };
+                let Some(position) = nodes.iter().position(|&other| other == node) else { return false };
+                nodes.swap_remove(position);
+                if nodes.is_empty() {
+                    map.remove(label);
+                }
+                true
+            },
+        });
+    }
+
+    /// Returns true if the label index is built and up to date
+    pub fn has_label_index(&self) -> bool {
+        self.label_index.is_some()
+    }
+
+    /// Finds the vertex carrying `label`
+    /// 
+    /// # Returns
+    /// * `Option<usize>` - Its index (the lowest one if several vertices carry
+    ///   `label`), None if no vertex carries `label`
+    /// 
+    /// # Performance
+    /// * Time Complexity: O(1) with the label index for unique labels (O(k)
+    ///   for a label carried by k vertices), O(V) without
+    pub fn node_index(&self, label: &N) -> Option<usize> {
+        match &self.label_index {
+            Some(index) => index.map.get(label).and_then(|nodes| nodes.iter().min().copied()),
+            None => self.node_weights.iter().position(|weight| weight == label),
+        }
+    }
+}
+
+/// Iterator over the outgoing edges of a vertex as `(neighbour, &weight)` pairs
+#[derive(Debug, Clone)]
+pub struct Edges<'a, E> {
+    neighbors: Zip<Iter<'a, usize>, Iter<'a, usize>>,
+    edge_list: &'a [(usize, usize, E)],
+}
+
+impl<'a, E> Iterator for Edges<'a, E> {
+    type Item = (usize, &'a E);
+
+    fn next(&mut self) -> Option<(usize, &'a E)> {
+        let (&next, &id) = self.neighbors.next()?;
+        Some((next, &self.edge_list[id].2))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.neighbors.size_hint()
+    }
+}
+
+impl<E> ExactSizeIterator for Edges<'_, E> {}
+
+impl<E> FusedIterator for Edges<'_, E> {}
+
+impl<N, E> GraphView for Graph<N, E> {
+    type Neighbors<'a> = Copied<Iter<'a, usize>> where N: 'a, E: 'a;
+
+    fn node_count(&self) -> usize {
+        self.adjacency_list.len()
+    }
+
+    fn neighbors(&self, node: usize) -> Self::Neighbors<'_> {
+        self.adjacency_list[node].iter().copied()
+    }
+}
+
+impl<N, E: Copy> WeightedGraphView for Graph<N, E> {
+    type Weight = E;
+    type WeightedNeighbors<'a> = Map<Edges<'a, E>, fn((usize, &E)) -> (usize, E)>
+    where
+        N: 'a,
+        E: 'a;
+
+    fn weighted_neighbors(&self, node: usize) -> Self::WeightedNeighbors<'_> {
+        self.edges(node).map(|(next, &weight)| (next, weight))
+    }
+}
@@ -0,0 +1,148 @@
+//! Read-only Graph Abstraction
+//!
+//! DEV NOTES:
+//! - `GraphView` is the minimal interface traversals need: a vertex count and
+//!   an iterator over each vertex's out-neighbours
+//! - Implemented for adjacency lists (`Vec<Vec<usize>>`, `[Vec<usize>]`),
+//!   `Graph`, and closures via `FnGraph`
+//! - Vertices are dense indices `0..node_count()`
+//! - Neighbour entries outside that range are ignored by traversals; only the
+//!   vertices passed in by the caller are validated
+//! - `WeightedGraphView` adds edge weights on top, for cost-aware searches
+//!   such as A*; implemented by `Graph<N, W>` and `GridGraph`
+//!
+//! # Examples
+//! ```
+//! use dsa_in_rust::algorithms::searching::dfs_search::DfsSearch;
+//! use dsa_in_rust::data_structures::graph::Graph;
+//! use dsa_in_rust::data_structures::graph_view::FnGraph;
+//!
+//! let mut graph = Graph::new(3);
+//! graph.add_edge(0, 1).unwrap();
+//! graph.add_edge(1, 2).unwrap();
+//! assert_eq!(DfsSearch::new(&graph).search(0, 2).unwrap(), Some(vec![0, 1, 2]));
+//!
+//! // Implicit graph: i -> i + 1 and i -> 2i, without storing any edges
+//! let implicit = FnGraph::new(100, |i: usize| [i + 1, 2 * i]);
+//! assert!(DfsSearch::new(&implicit).search(1, 64).unwrap().is_some());
+//! ```
+
+use super::graph::GraphError;
+use std::iter::Copied;
+use std::slice::Iter;
+
+/// Read-only view of a directed graph with vertices `0..node_count()`
+pub trait GraphView {
+    /// Iterator over the out-neighbours of a vertex
+    type Neighbors<'a>: Iterator<Item = usize>
+    where
+        Self: 'a;
+
+    /// Returns the number of vertices
+    fn node_count(&self) -> usize;
+
+    /// Returns the out-neighbours of `node`
+    ///
+    /// # Panics
+    /// May panic if `node >= node_count()`; use `check_node` first
+    fn neighbors(&self, node: usize) -> Self::Neighbors<'_>;
+
+    /// Checks that `node` is a valid vertex index
+    ///
+    /// # Returns
+    /// * `Result<(), GraphError>` - Err(InvalidNode) if out of range
+    fn check_node(&self, node: usize) -> Result<(), GraphError> {
+        if node < self.node_count() {
+            Ok(())
+        } else {
+            Err(GraphError::InvalidNode(node))
+        }
+    }
+
+    /// Checks if an edge exists between two nodes
+    ///
+    /// # Performance
+    /// * Time Complexity: O(deg(from))
+    fn contains_edge(&self, from: usize, to: usize) -> bool {
+        from < self.node_count() && self.neighbors(from).any(|next| next == to)
+    }
+}
+
+impl GraphView for [Vec<usize>] {
+    type Neighbors<'a> = Copied<Iter<'a, usize>>;
+
+    fn node_count(&self) -> usize {
+        self.len()
+    }
+
+    fn neighbors(&self, node: usize) -> Self::Neighbors<'_> {
+        self[node].iter().copied()
+    }
+}
+
+impl GraphView for Vec<Vec<usize>> {
+    type Neighbors<'a> = Copied<Iter<'a, usize>>;
+
+    fn node_count(&self) -> usize {
+        self.len()
+    }
+
+    fn neighbors(&self, node: usize) -> Self::Neighbors<'_> {
+        self[node].iter().copied()
+    }
+}
+
+/// Graph defined by a neighbour function instead of stored edges
+///
+/// Useful for implicit graphs (grids, state spaces) that would be wasteful to
+/// materialize. The closure is called every time a vertex's neighbours are
+/// needed, so it should be cheap and deterministic.
+#[derive(Debug, Clone, Copy)]
+pub struct FnGraph<F> {
+    node_count: usize,
+    neighbors: F,
+}
+
+impl<F> FnGraph<F> {
+    /// Creates a graph with `node_count` vertices whose edges come from `neighbors`
+    ///
+    /// # Arguments
+    /// * `node_count` - Number of vertices
+    /// * `neighbors` - Function returning the out-neighbours of a vertex
+    pub fn new(node_count: usize, neighbors: F) -> Self {
+        Self { node_count, neighbors }
+    }
+}
+
+impl<F, I> GraphView for FnGraph<F>
+where
+    F: Fn(usize) -> I,
+    I: IntoIterator<Item = usize>,
+{
+    type Neighbors<'a> = I::IntoIter where Self: 'a;
+
+    fn node_count(&self) -> usize {
+        self.node_count
+    }
+
+    fn neighbors(&self, node: usize) -> Self::Neighbors<'_> {
+        (self.neighbors)(node).into_iter()
+    }
+}
+
+/// Read-only view of a graph whose edges carry weights
+pub trait WeightedGraphView: GraphView {
+    /// Edge weight type
+    type Weight;
+
+    /// Iterator over `(neighbour, weight)` pairs of a vertex
+    type WeightedNeighbors<'a>: Iterator<Item = (usize, Self::Weight)>
+    where
+        Self: 'a;
+
+    /// Returns the out-edges of `node` with their weights
+    ///
+    /// # Panics
+    /// May panic if `node >= node_count()`; use `check_node` first
+    fn weighted_neighbors(&self, node: usize) -> Self::WeightedNeighbors<'_>;
+}
@@ -0,0 +1,106 @@
+//! Union-Find (Disjoint Set Union)
+//!
+//! DEV NOTES:
+//! - Tracks a partition of `0..len()` into disjoint sets under merging
+//! - Union by size keeps trees shallow; `find` halves paths as it walks, so
+//!   later lookups get cheaper
+//! - `find` is iterative and needs `&mut self` for path halving
+//! - Used by Kruskal's minimum spanning tree algorithm
+//!
+//! PERFORMANCE CONSIDERATIONS:
+//! - `find` / `union`: O(α(n)) amortized, effectively constant
+//! - Memory: two `usize` per element
+
+/// Disjoint sets over the elements `0..len()`
+///
+/// # Examples
+/// ```
+/// use dsa_in_rust::data_structures::union_find::UnionFind;
+///
+/// let mut sets = UnionFind::new(5);
+/// assert!(sets.union(0, 1));
+/// assert!(sets.union(3, 4));
+/// assert!(!sets.union(1, 0));
+/// assert!(sets.connected(0, 1));
+/// assert!(!sets.connected(1, 3));
+/// assert_eq!(sets.set_count(), 3);
+/// assert_eq!(sets.set_size(4), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+    /// Set size, valid at roots only
+    sizes: Vec<usize>,
+    set_count: usize,
+}
+
+impl UnionFind {
+    /// Creates `len` singleton sets
+    ///
+    /// # Performance
+    /// * Time Complexity: O(n)
+    /// * Space Complexity: O(n)
+    pub fn new(len: usize) -> Self {
+        Self { parents: (0..len).collect(), sizes: vec![1; len], set_count: len }
+    }
+
+    /// Returns the number of elements
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    /// Returns true if there are no elements
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Returns the number of disjoint sets
+    pub fn set_count(&self) -> usize {
+        self.set_count
+    }
+
+    /// Returns the representative of the set containing `element`
+    ///
+    /// # Panics
+    /// Panics if `element >= len()`
+    pub fn find(&mut self, mut element: usize) -> usize {
+        while self.parents[element] != element {
+            let grandparent = self.parents[self.parents[element]];
+            self.parents[element] = grandparent;
+            element = grandparent;
+        }
+        element
+    }
+
+    /// Merges the sets containing `a` and `b`
+    ///
+    /// # Returns
+    /// * `bool` - True if they were in different sets
+    ///
+    /// # Panics
+    /// Panics if either element is out of range
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.sizes[a] < self.sizes[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parents[b] = a;
+        self.sizes[a] += self.sizes[b];
+        self.set_count -= 1;
+        true
+    }
+
+    /// Returns true if `a` and `b` are in the same set
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Returns the size of the set containing `element`
+    pub fn set_size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.sizes[root]
+    }
+}
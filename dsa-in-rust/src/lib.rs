@@ -5,25 +5,104 @@
 
 // Module declarations with documentation
 pub mod algorithms {
-    //! Contains implementations of various sorting and searching algorithms
+    //! Contains implementations of various sorting, searching and graph algorithms
     
     pub mod sorting {
         //! Sorting algorithm implementations including:
         //! - Bubble Sort
-        //! - Quick Sort
-        //! - Merge Sort
+        //! - Insertion Sort
+        //! - Introsort
+        //! - Counting Sort
+        //! - Radix Sort
+        //! - Smart Sort (profiles the input and dispatches to one of the above)
+        //! - Natural Sort (alphanumeric ordering for file names and labels)
         pub mod bubble_sort;
+        pub mod insertion_sort;
+        pub mod intro_sort;
+        pub mod counting_sort;
+        pub mod radix_sort;
+        pub mod smart_sort;
+        pub mod natural_sort;
     }
 
     pub mod searching {
         //! Searching algorithm implementations including:
+        //! - Depth First Search and Breadth First Search
+        //! - Pre-/In-/Post-/Level-order iterators over array-encoded trees
+        //! - String pattern matching (KMP, Boyer–Moore–Horspool, Rabin–Karp, Aho–Corasick) and suffix arrays
+        //! - Binary Search (lower/upper bound, equal range, exponential search, search on the answer)
+        //! - Interpolation, Jump and Fibonacci Search (with probe counters)
+        //! - Ternary and Golden-Section Search for unimodal functions
+        //! - Rotated sorted arrays, sorted matrices and peak finding
+        //! - Edit distances (Levenshtein, Damerau–Levenshtein, Hamming) and BK-tree fuzzy lookup
+        //! - Regular expressions (Thompson NFA, subset construction, Hopcroft minimization)
        pub mod dfs_search;
+        pub mod binary_search;
+        pub mod numeric_search;
+        pub mod bfs_search;
+        pub mod tree_traversal;
+        pub mod unimodal_search;
+        pub mod structured_search;
+        pub mod edit_distance;
+        pub mod bk_tree;
+
+        pub mod string {
+            //! Substring search over `&str` (via `as_bytes`), `&[u8]` or any `&[T: Eq]`:
+            //! - Knuth–Morris–Pratt (with the prefix function exposed)
+            //! - Boyer–Moore–Horspool
+            //! - Rabin–Karp (multiple patterns of equal length)
+            //! - Aho–Corasick (many byte patterns at once, with streaming and DFA modes)
+            //! - Suffix array with LCP array (indexed counting/locating, repeats, common substrings)
+            //!
+            //! KMP, Horspool and Rabin–Karp implement `matcher::Matcher`.
+            pub mod matcher;
+            pub mod kmp;
+            pub mod horspool;
+            pub mod rabin_karp;
+            pub mod aho_corasick;
+            pub mod suffix_array;
+        }
+
+        pub mod regex {
+            //! Regular expression engine built from textbook automata:
+            //! - `parser`: pattern syntax to AST
+            //! - `nfa`: Thompson construction and linear-time simulation
+            //! - `dfa`: subset construction and Hopcroft minimization
+            //! - `engine`: the `Regex` type tying them together
+            pub mod parser;
+            pub mod nfa;
+            pub mod dfa;
+            pub mod engine;
+        }
+    }
+
+    pub mod graph {
+        //! Algorithms on `data_structures::graph::Graph`, added as methods:
+        //! - Dijkstra shortest paths over weighted graphs
+        //! - Bellman–Ford and SPFA with negative cycle extraction
+        //! - All-pairs shortest paths (Floyd–Warshall, Johnson)
+        //! - A* with pluggable heuristics (also over `GridGraph`)
+        //! - Topological sorting (Kahn, DFS, lexicographically smallest) with cycle reporting
+        //! - Strongly connected components (Tarjan, Kosaraju) and the condensation DAG
+        //! - Minimum spanning trees and forests (Kruskal, Prim) on undirected graphs
+        //! - Maximum flow and minimum cut (Edmonds–Karp, Dinic)
+        pub mod shortest_path;
+        pub mod bellman_ford;
+        pub mod all_pairs;
+        pub mod astar;
+        pub mod topological;
+        pub mod scc;
+        pub mod spanning_tree;
+        pub mod max_flow;
     }
 }
 
 pub mod data_structures {
     //! Custom implementations of fundamental data structures
     pub mod graph;
+    pub mod graph_view;
+    pub mod grid_graph;
+    pub mod union_find;
 }
 
 pub mod utils {
@@ -0,0 +1,257 @@
+//! Integration tests for Aho–Corasick Multi-Pattern Matching
+//!
+//! TEST CATEGORIES:
+//! - Match Semantics: Leftmost-first, leftmost-longest and overlapping
+//! - Reference Agreement: Exhaustive comparison with a naive matcher
+//! - Case Folding: ASCII case-insensitive mode
+//! - Streaming: `Read` input split into arbitrary chunks
+//! - DFA Mode: Identical results with the dense transition table
+//! - Edge Cases: Empty patterns, duplicates, empty haystacks
+//!
+//! DEV NOTES:
+//! - Every configuration is checked in both failure-link and DFA modes
+
+use dsa_in_rust::algorithms::searching::string::aho_corasick::{AhoCorasick, AhoCorasickBuilder, Match, MatchKind};
+use dsa_in_rust::algorithms::searching::string::matcher::PatternError;
+use std::io::{self, Read};
+
+const KINDS: [MatchKind; 3] = [MatchKind::LeftmostFirst, MatchKind::LeftmostLongest, MatchKind::Overlapping];
+
+/// Naive reference implementation of all three match kinds
+fn naive(patterns: &[&str], haystack: &str, kind: MatchKind, fold: bool) -> Vec<Match> {
+    let eq = |a: &[u8], b: &[u8]| if fold { a.eq_ignore_ascii_case(b) } else { a == b };
+    let hay = haystack.as_bytes();
+    let matches_at = |start: usize| -> Vec<Match> {
+        patterns
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| start + p.len() <= hay.len() && eq(&hay[start..start + p.len()], p.as_bytes()))
+            .map(|(pattern, p)| Match { pattern, start, end: start + p.len() })
+            .collect()
+    };
+
+    if kind == MatchKind::Overlapping {
+        let mut all: Vec<Match> = (0..=hay.len()).flat_map(matches_at).collect();
+        all.sort_by_key(|m| (m.end, std::cmp::Reverse(m.end - m.start), m.pattern));
+        return all;
+    }
+
+    let mut result = Vec::new();
+    let mut position = 0;
+    while position <= hay.len() {
+        let Some((start, candidates)) =
+            (position..=hay.len()).map(|s| (s, matches_at(s))).find(|(_, c)| !c.is_empty())
+        else {
+            break;
+        };
+        let chosen = match kind {
+            MatchKind::LeftmostLongest => *candidates.iter().max_by_key(|m| (m.end, std::cmp::Reverse(m.pattern))).unwrap(),
+            _ => candidates[0],
+        };
+        debug_assert_eq!(chosen.start, start);
+        result.push(chosen);
+        position = if chosen.end == chosen.start { chosen.end + 1 } else { chosen.end };
+    }
+    result
+}
+
+fn build(patterns: &[&str], kind: MatchKind, fold: bool, dfa: bool) -> AhoCorasick {
+    AhoCorasickBuilder::new()
+        .match_kind(kind)
+        .ascii_case_insensitive(fold)
+        .dfa(dfa)
+        .build(patterns)
+        .unwrap()
+}
+
+/// Reader that hands out at most `chunk` bytes per call
+struct Trickle<'a> {
+    data: &'a [u8],
+    chunk: usize,
+}
+
+impl Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.chunk.min(buf.len()).min(self.data.len());
+        buf[..n].copy_from_slice(&self.data[..n]);
+        self.data = &self.data[n..];
+        Ok(n)
+    }
+}
+
+/// Tests the three semantics on the classic example
+///
+/// # Test Case
+/// - Patterns: "Sam", "Samwise" in "Samwise"
+/// - Expected: First picks "Sam", longest picks "Samwise", overlapping reports both
+#[test]
+fn test_match_kinds() {
+    let patterns = ["Sam", "Samwise"];
+    let first = build(&patterns, MatchKind::LeftmostFirst, false, false);
+    assert_eq!(first.find("Samwise"), Some(Match { pattern: 0, start: 0, end: 3 }));
+
+    let longest = build(&patterns, MatchKind::LeftmostLongest, false, false);
+    assert_eq!(longest.find("Samwise"), Some(Match { pattern: 1, start: 0, end: 7 }));
+
+    let overlapping = build(&patterns, MatchKind::Overlapping, false, false);
+    let found: Vec<usize> = overlapping.find_iter("Samwise").map(|m| m.pattern).collect();
+    assert_eq!(found, [0, 1]);
+}
+
+/// Tests that an earlier start beats an earlier end
+///
+/// # Test Case
+/// - Patterns: "bcd", "abcdef" in "abcdef"
+/// - Expected: Leftmost kinds report "abcdef" even though "bcd" ends first
+#[test]
+fn test_leftmost_prefers_earlier_start() {
+    for dfa in [false, true] {
+        let automaton = build(&["bcd", "abcdef"], MatchKind::LeftmostFirst, false, dfa);
+        assert_eq!(automaton.find("xabcdef"), Some(Match { pattern: 1, start: 1, end: 7 }));
+        assert_eq!(automaton.find("xabcdex"), Some(Match { pattern: 0, start: 2, end: 5 }));
+    }
+}
+
+/// Tests agreement with the naive matcher over many pattern sets
+///
+/// # Test Case
+/// - Patterns: Overlapping prefixes and suffixes over a small alphabet
+/// - Expected: Identical matches for every kind, mode and haystack
+#[test]
+fn test_agree_with_naive() {
+    let pattern_sets: [&[&str]; 5] = [
+        &["he", "she", "his", "hers"],
+        &["a", "ab", "abc", "bc", "c"],
+        &["abab", "bab", "ba", "aab"],
+        &["aaa", "aa", "a"],
+        &["ab", "ab", "b"],
+    ];
+    let haystacks = ["ushershishe", "abcabcab", "aabababbabaab", "aaaaaa", "", "xyz"];
+    for patterns in pattern_sets {
+        for kind in KINDS {
+            for dfa in [false, true] {
+                let automaton = build(patterns, kind, false, dfa);
+                for haystack in haystacks {
+                    let found: Vec<Match> = automaton.find_iter(haystack).collect();
+                    assert_eq!(found, naive(patterns, haystack, kind, false), "{:?} {:?} {}", patterns, kind, haystack);
+                }
+            }
+        }
+    }
+}
+
+/// Tests ASCII case-insensitive matching
+///
+/// # Test Case
+/// - Patterns: Mixed-case keywords; haystack in other casings
+/// - Expected: Matches regardless of case; non-letters unaffected
+#[test]
+fn test_ascii_case_insensitive() {
+    let patterns = ["Error", "WARN", "disk-FULL"];
+    let haystack = "error: DISK-full, warn, ErRoR";
+    for kind in KINDS {
+        for dfa in [false, true] {
+            let automaton = build(&patterns, kind, true, dfa);
+            let found: Vec<Match> = automaton.find_iter(haystack).collect();
+            assert_eq!(found, naive(&patterns, haystack, kind, true));
+            assert_eq!(found.len(), 4);
+        }
+    }
+    let sensitive = build(&patterns, MatchKind::LeftmostFirst, false, false);
+    assert_eq!(sensitive.find_iter(haystack).count(), 0);
+}
+
+/// Tests streaming input split into tiny chunks
+///
+/// # Test Case
+/// - Input: Text fed 1, 2, 3 and 7 bytes at a time
+/// - Expected: Same matches and absolute offsets as in-memory search
+#[test]
+fn test_streaming_matches_in_memory() {
+    let patterns = ["abcdefgh", "cde", "def", "efghij", "h", ""];
+    let haystack = "xxabcdefghijxxcdefabcdefghzz".repeat(3);
+    for kind in KINDS {
+        for dfa in [false, true] {
+            let automaton = build(&patterns, kind, false, dfa);
+            let expected: Vec<Match> = automaton.find_iter(&haystack).collect();
+            for chunk in [1, 2, 3, 7] {
+                let reader = Trickle { data: haystack.as_bytes(), chunk };
+                let streamed: Vec<Match> = automaton.stream_find_iter(reader).map(Result::unwrap).collect();
+                assert_eq!(streamed, expected, "{:?} dfa={} chunk={}", kind, dfa, chunk);
+            }
+        }
+    }
+}
+
+/// Tests streaming a large input
+///
+/// # Test Case
+/// - Input: 1 MB with a keyword every 1000 bytes
+/// - Expected: Every keyword found at its absolute offset
+#[test]
+fn test_streaming_large_input() {
+    let mut data = vec![b'.'; 1_000_000];
+    for start in (500..data.len() - 10).step_by(1000) {
+        data[start..start + 6].copy_from_slice(b"needle");
+    }
+    let automaton = AhoCorasickBuilder::new().dfa(true).build(["needle", "haystack"]).unwrap();
+    let starts: Vec<usize> = automaton.stream_find_iter(data.as_slice()).map(|m| m.unwrap().start).collect();
+    assert_eq!(starts.len(), 1000);
+    assert!(starts.iter().all(|&s| s % 1000 == 500));
+}
+
+/// Tests read errors being surfaced
+///
+/// # Test Case
+/// - Input: Reader that always fails
+/// - Expected: The iterator yields the error
+#[test]
+fn test_streaming_error() {
+    struct Broken;
+    impl Read for Broken {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other("disk on fire"))
+        }
+    }
+    let automaton = AhoCorasick::new(["x"]).unwrap();
+    let first = automaton.stream_find_iter(Broken).next().unwrap();
+    assert_eq!(first.unwrap_err().to_string(), "disk on fire");
+}
+
+/// Tests scanning for thousands of keywords
+///
+/// # Test Case
+/// - Patterns: 5000 distinct numbered keywords
+/// - Expected: DFA and failure-link modes agree and find the planted keywords
+#[test]
+fn test_many_patterns() {
+    let patterns: Vec<String> = (0..5000).map(|i| format!("kw{}x", i)).collect();
+    let haystack = "noise kw42x more kw4999x noise kw5000x kw0x";
+    let nfa = AhoCorasick::new(&patterns).unwrap();
+    let dfa = AhoCorasickBuilder::new().dfa(true).build(&patterns).unwrap();
+    assert!(dfa.is_dfa() && !nfa.is_dfa());
+    assert_eq!(nfa.pattern_count(), 5000);
+    let found: Vec<usize> = nfa.find_iter(haystack).map(|m| m.pattern).collect();
+    assert_eq!(found, [42, 4999, 0]);
+    assert_eq!(dfa.find_iter(haystack).collect::<Vec<_>>(), nfa.find_iter(haystack).collect::<Vec<_>>());
+}
+
+/// Tests edge cases
+///
+/// # Test Case
+/// - Input: No patterns; empty pattern; empty haystack; non-UTF-8 bytes
+/// - Expected: Error, matches at every position, sensible results
+#[test]
+fn test_edge_cases() {
+    let none: [&str; 0] = [];
+    assert_eq!(AhoCorasick::new(none).unwrap_err(), PatternError::NoPatterns);
+
+    let empty = AhoCorasick::new([""]).unwrap();
+    let starts: Vec<usize> = empty.find_iter("abc").map(|m| m.start).collect();
+    assert_eq!(starts, [0, 1, 2, 3]);
+    assert!(empty.is_match(""));
+
+    let bytes = AhoCorasick::new([[0xff_u8, 0x00]]).unwrap();
+    assert_eq!(bytes.find([1u8, 0xff, 0x00, 2]).map(|m| m.start), Some(1));
+    assert!(!bytes.is_match([0xffu8]));
+}
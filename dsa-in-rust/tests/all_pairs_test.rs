@@ -0,0 +1,136 @@
+//! Integration tests for All-Pairs Shortest Paths
+//! 
+//! TEST CATEGORIES:
+//! - Agreement: Floyd–Warshall, Johnson and Bellman–Ford from every source
+//! - Paths: Next-hop paths are real paths with the reported length
+//! - Negative Cycles: Both algorithms report a concrete negative cycle
+//! - Queries: Rows, self distances, unreachable and invalid vertices
+//! 
+//! DEV NOTES:
+//! - Graphs with negative edges but no negative cycles are generated from
+//!   random potentials: `w(u, v) = base + p(u) - p(v)` with `base >= 0` makes
+//!   every cycle weigh the sum of its bases
+
+use dsa_in_rust::algorithms::graph::all_pairs::DistanceMatrix;
+use dsa_in_rust::data_structures::graph::{Graph, GraphError};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+fn potential_graph(rng: &mut StdRng, nodes: usize, edges: usize) -> Graph<(), i64> {
+    let potentials: Vec<i64> = (0..nodes).map(|_| rng.gen_range(-50..=50)).collect();
+    let mut graph = Graph::weighted(nodes);
+    for _ in 0..edges {
+        let (src, dest) = (rng.gen_range(0..nodes), rng.gen_range(0..nodes));
+        let weight = rng.gen_range(0..=10) + potentials[src] - potentials[dest];
+        let _ = graph.add_weighted_edge(src, dest, weight);
+    }
+    graph
+}
+
+fn assert_paths(graph: &Graph<(), i64>, matrix: &DistanceMatrix<i64>) {
+    let n = graph.node_count();
+    for from in 0..n {
+        for to in 0..n {
+            match matrix.distance(from, to) {
+                Some(distance) => {
+                    let path = matrix.path(from, to).unwrap();
+                    assert_eq!((path[0], *path.last().unwrap()), (from, to));
+                    let length: i64 = path.windows(2).map(|p| *graph.edge_weight(p[0], p[1]).unwrap()).sum();
+                    assert_eq!(length, distance);
+                }
+                None => assert_eq!(matrix.path(from, to), Err(GraphError::NoPath(from, to))),
+            }
+        }
+    }
+}
+
+/// Tests agreement between the algorithms
+/// 
+/// # Test Case
+/// - Input: 150 random graphs with negative edges and no negative cycles
+/// - Expected: Floyd–Warshall and Johnson rows equal Bellman–Ford from each source
+#[test]
+fn test_agreement() {
+    let mut rng = StdRng::seed_from_u64(43);
+    for _ in 0..150 {
+        let nodes = rng.gen_range(1..20);
+        let edges = rng.gen_range(0..nodes * 3);
+        let graph = potential_graph(&mut rng, nodes, edges);
+        let floyd = graph.floyd_warshall().unwrap();
+        let johnson = graph.johnson().unwrap();
+        for source in 0..nodes {
+            let expected = graph.bellman_ford(source).unwrap();
+            assert_eq!(floyd.row(source), expected.distances());
+            assert_eq!(johnson.row(source), expected.distances());
+        }
+        assert_paths(&graph, &floyd);
+        assert_paths(&graph, &johnson);
+    }
+}
+
+/// Tests negative cycle reporting
+/// 
+/// # Test Case
+/// - Input: A graph whose only negative cycle is 1 -> 2 -> 3 -> 1, away from vertex 0
+/// - Expected: NegativeCycle([1, 2, 3]) from both algorithms
+#[test]
+fn test_negative_cycle() {
+    let mut graph: Graph<(), i32> = Graph::weighted(5);
+    for (src, dest, weight) in [(0, 4, 1), (4, 0, 1), (1, 2, 2), (2, 3, -4), (3, 1, 1), (0, 1, 7)] {
+        graph.add_weighted_edge(src, dest, weight).unwrap();
+    }
+    assert_eq!(graph.floyd_warshall(), Err(GraphError::NegativeCycle(vec![1, 2, 3])));
+    assert_eq!(graph.johnson(), Err(GraphError::NegativeCycle(vec![1, 2, 3])));
+}
+
+/// Tests matrix queries
+/// 
+/// # Test Case
+/// - Input: Two disconnected pairs of vertices and out-of-range queries
+/// - Expected: Zero self distances, None for unreachable pairs, InvalidNode errors
+#[test]
+fn test_queries() {
+    let mut graph: Graph<(), u32> = Graph::weighted(4);
+    graph.add_weighted_edge(0, 1, 3).unwrap();
+    graph.add_weighted_edge(2, 3, 4).unwrap();
+    graph.add_weighted_edge(3, 3, 1).unwrap();
+    for matrix in [graph.floyd_warshall().unwrap(), graph.johnson().unwrap()] {
+        assert_eq!(matrix.node_count(), 4);
+        assert_eq!(matrix.row(0), [Some(0), Some(3), None, None]);
+        assert_eq!(matrix.distance(3, 3), Some(0));
+        assert_eq!(matrix.next_hop(3, 3), None);
+        assert_eq!(matrix.path(3, 3).unwrap(), [3]);
+        assert_eq!(matrix.next_hop(1, 0), None);
+        assert_eq!(matrix.distance(0, 9), None);
+        assert_eq!(matrix.path(9, 0), Err(GraphError::InvalidNode(9)));
+        assert_eq!(matrix.path(0, 2), Err(GraphError::NoPath(0, 2)));
+    }
+
+    let empty: Graph<(), i64> = Graph::weighted(0);
+    assert_eq!(empty.floyd_warshall().unwrap().node_count(), 0);
+    assert_eq!(empty.johnson().unwrap().node_count(), 0);
+}
+
+/// Tests a dense graph
+/// 
+/// # Test Case
+/// - Input: A complete 60-vertex graph with random non-negative weights
+/// - Expected: Both algorithms agree with Dijkstra from every source
+#[test]
+fn test_dense() {
+    let mut rng = StdRng::seed_from_u64(3);
+    let n = 60;
+    let mut graph: Graph<(), u64> = Graph::weighted(n);
+    for from in 0..n {
+        for to in (0..n).filter(|&to| to != from) {
+            graph.add_weighted_edge(from, to, rng.gen_range(1..1000)).unwrap();
+        }
+    }
+    let floyd = graph.floyd_warshall().unwrap();
+    let johnson = graph.johnson().unwrap();
+    for source in 0..n {
+        let expected = graph.dijkstra(source).unwrap();
+        assert_eq!(floyd.row(source), expected.distances());
+        assert_eq!(johnson.row(source), expected.distances());
+    }
+}
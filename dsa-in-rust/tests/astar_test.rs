@@ -0,0 +1,253 @@
+//! Integration tests for A* Search and the Grid Graph
+//! 
+//! TEST CATEGORIES:
+//! - Optimality: A* costs against Dijkstra on random grids and graphs
+//! - Heuristics: Zero, grid and inconsistent-but-admissible heuristics
+//! - Tie-Breaking: Every policy finds the same cost
+//! - Grid Rules: Obstacles, per-cell costs, diagonal steps, corner cutting
+//! - Error Handling: Unreachable goals and invalid vertices
+//! 
+//! DEV NOTES:
+//! - Grids are compared against a `Graph<(), u64>` materialized from the grid's
+//!   own weighted neighbours, so both searches see exactly the same edges
+
+use dsa_in_rust::algorithms::graph::astar::{AStar, TieBreak};
+use dsa_in_rust::algorithms::searching::dfs_search::DfsSearch;
+use dsa_in_rust::data_structures::graph::{Graph, GraphError};
+use dsa_in_rust::data_structures::graph_view::{GraphView, WeightedGraphView};
+use dsa_in_rust::data_structures::grid_graph::{Connectivity, GridGraph};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const TIE_BREAKS: [TieBreak; 4] = [TieBreak::LargerCost, TieBreak::SmallerCost, TieBreak::Fifo, TieBreak::Lifo];
+
+fn random_grid(rng: &mut StdRng, width: usize, height: usize, connectivity: Connectivity) -> GridGraph {
+    let mut grid = GridGraph::new(width, height, connectivity).step_weights(10, 14);
+    for y in 0..height {
+        for x in 0..width {
+            if rng.gen_bool(0.25) {
+                grid.set_blocked(x, y);
+            } else {
+                grid.set_cost(x, y, rng.gen_range(1..5));
+            }
+        }
+    }
+    grid
+}
+
+fn materialize<G: WeightedGraphView<Weight = u64>>(view: &G) -> Graph<(), u64> {
+    let mut graph = Graph::weighted(view.node_count());
+    for node in 0..view.node_count() {
+        for (next, weight) in view.weighted_neighbors(node) {
+            graph.add_weighted_edge(node, next, weight).unwrap();
+        }
+    }
+    graph
+}
+
+fn path_cost<G: WeightedGraphView<Weight = u64>>(view: &G, path: &[usize]) -> u64 {
+    path.windows(2)
+        .map(|pair| view.weighted_neighbors(pair[0]).find(|&(next, _)| next == pair[1]).expect("path uses real edges").1)
+        .sum()
+}
+
+/// Tests A* on random grids against Dijkstra
+/// 
+/// # Test Case
+/// - Input: Random 4- and 8-connected grids with obstacles and costs 1..5
+/// - Expected: Same cost with the grid heuristic and every tie-break; valid paths
+#[test]
+fn test_grids_against_dijkstra() {
+    let mut rng = StdRng::seed_from_u64(44);
+    for connectivity in [Connectivity::Four, Connectivity::Eight] {
+        for _ in 0..60 {
+            let grid = random_grid(&mut rng, 15, 12, connectivity);
+            let graph = materialize(&grid);
+            let start = rng.gen_range(0..grid.node_count());
+            let goal = rng.gen_range(0..grid.node_count());
+            let expected = graph.dijkstra(start).unwrap().distance(goal);
+            for tie_break in TIE_BREAKS {
+                match AStar::new(&grid).tie_break(tie_break).search(start, goal, grid.heuristic(goal)) {
+                    Ok(found) => {
+                        assert_eq!(Some(found.cost), expected);
+                        assert_eq!((found.path[0], *found.path.last().unwrap()), (start, goal));
+                        assert_eq!(path_cost(&grid, &found.path), found.cost);
+                    }
+                    Err(error) => {
+                        assert_eq!(error, GraphError::NoPath(start, goal));
+                        assert_eq!(expected, None);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Tests inconsistent heuristics
+/// 
+/// # Test Case
+/// - Input: Random graphs with h(v) drawn uniformly from [0, true distance to goal]
+/// - Expected: Optimal costs anyway, with some vertices reopened along the way
+#[test]
+fn test_inconsistent_heuristic() {
+    let mut rng = StdRng::seed_from_u64(9);
+    let mut reopened = 0;
+    for _ in 0..200 {
+        let n = 30;
+        let mut graph: Graph<(), u64> = Graph::weighted(n);
+        let mut reverse: Graph<(), u64> = Graph::weighted(n);
+        for _ in 0..90 {
+            let (src, dest, weight) = (rng.gen_range(0..n), rng.gen_range(0..n), rng.gen_range(1..20));
+            if graph.add_weighted_edge(src, dest, weight).is_ok() {
+                reverse.add_weighted_edge(dest, src, weight).unwrap();
+            }
+        }
+        let goal = rng.gen_range(0..n);
+        let to_goal = reverse.dijkstra(goal).unwrap();
+        let heuristic: Vec<u64> = (0..n).map(|v| to_goal.distance(v).map_or(0, |d| rng.gen_range(0..=d))).collect();
+
+        let expected = graph.dijkstra(0).unwrap().distance(goal);
+        let found = AStar::new(&graph).search(0, goal, |v| heuristic[v]);
+        assert_eq!(found.as_ref().ok().map(|f| f.cost), expected);
+        if let Ok(found) = found {
+            reopened += found.stats.reopened;
+        }
+    }
+    assert!(reopened > 0);
+}
+
+/// Tests that an informed heuristic saves work
+/// 
+/// # Test Case
+/// - Input: Open 100 x 100 grid, corner to corner
+/// - Expected: Manhattan heuristic expands far fewer vertices than a zero heuristic
+#[test]
+fn test_heuristic_reduces_expansions() {
+    let grid = GridGraph::new(100, 100, Connectivity::Four);
+    let (start, goal) = (0, grid.node(99, 99).unwrap());
+    let blind = AStar::new(&grid).search(start, goal, |_| 0).unwrap();
+    let informed = AStar::new(&grid).search(start, goal, grid.heuristic(goal)).unwrap();
+    assert_eq!(blind.cost, 198);
+    assert_eq!(informed.cost, 198);
+    assert_eq!(blind.stats.expanded, 100 * 100);
+    assert_eq!(informed.stats.expanded, 199);
+    assert!(informed.stats.generated <= 2 * 199);
+
+    let shallow = AStar::new(&grid).tie_break(TieBreak::SmallerCost).search(start, goal, grid.heuristic(goal)).unwrap();
+    assert_eq!(shallow.cost, 198);
+    assert!(shallow.stats.expanded > informed.stats.expanded);
+}
+
+/// Tests diagonal steps and corner cutting
+/// 
+/// # Test Case
+/// - Input: Octile step weights; a diagonal squeeze between two obstacles
+/// - Expected: 14 per diagonal and 10 per straight step; squeeze allowed only with corner cutting
+#[test]
+fn test_diagonals() {
+    let grid = GridGraph::new(6, 6, Connectivity::Eight).step_weights(10, 14);
+    let goal = grid.node(3, 5).unwrap();
+    let found = AStar::new(&grid).search(0, goal, grid.heuristic(goal)).unwrap();
+    assert_eq!(found.cost, 3 * 14 + 2 * 10);
+    assert_eq!(grid.heuristic(goal)(0), found.cost);
+
+    let mut squeeze = GridGraph::new(2, 2, Connectivity::Eight);
+    squeeze.set_blocked(1, 0);
+    squeeze.set_blocked(0, 1);
+    let goal = squeeze.node(1, 1).unwrap();
+    assert_eq!(AStar::new(&squeeze).search(0, goal, |_| 0), Err(GraphError::NoPath(0, goal)));
+    let cutting = squeeze.clone().corner_cutting(true);
+    assert_eq!(AStar::new(&cutting).search(0, goal, |_| 0).unwrap().path, [0, goal]);
+}
+
+/// Tests that the grid heuristic never overestimates
+/// 
+/// # Test Case
+/// - Input: Step weights where diagonals are cheaper, equal or dearer than straight steps
+/// - Expected: Heuristic at most the Dijkstra distance from every cell; (0, 1) to (2, 1) costs 2 with weights (10, 1)
+#[test]
+fn test_heuristic_admissible() {
+    let grid = GridGraph::new(3, 3, Connectivity::Eight).step_weights(10, 1);
+    let (start, goal) = (grid.node(0, 1).unwrap(), grid.node(2, 1).unwrap());
+    let found = AStar::new(&grid).search(start, goal, grid.heuristic(goal)).unwrap();
+    assert_eq!(found.cost, 2);
+    assert_eq!(grid.heuristic(goal)(start), 2);
+
+    for connectivity in [Connectivity::Four, Connectivity::Eight] {
+        for (straight, diagonal) in [(10, 1), (10, 7), (10, 10), (10, 14), (1, 10)] {
+            let mut grid = GridGraph::new(5, 4, connectivity).step_weights(straight, diagonal);
+            grid.set_cost(2, 1, 3);
+            let graph = materialize(&grid);
+            for goal in 0..graph.node_count() {
+                let heuristic = grid.heuristic(goal);
+                for node in 0..graph.node_count() {
+                    let distance = graph.dijkstra(node).unwrap().distance(goal).unwrap();
+                    assert!(heuristic(node) <= distance, "{:?} ({}, {}): {} -> {}", connectivity, straight, diagonal, node, goal);
+                }
+            }
+        }
+    }
+}
+
+/// Tests per-cell costs
+/// 
+/// # Test Case
+/// - Input: A swamp column that is cheaper to walk around
+/// - Expected: The detour, with cost equal to the sum of entered cells
+#[test]
+fn test_cell_costs() {
+    let mut grid = GridGraph::new(3, 3, Connectivity::Four);
+    for y in 0..2 {
+        grid.set_cost(1, y, 10);
+    }
+    assert_eq!(grid.cost(1, 0), Some(10));
+    let goal = grid.node(2, 0).unwrap();
+    let found = AStar::new(&grid).search(0, goal, grid.heuristic(goal)).unwrap();
+    assert_eq!(found.cost, 6);
+    let cells: Vec<(usize, usize)> = found.path.iter().map(|&node| grid.position(node)).collect();
+    assert_eq!(cells, [(0, 0), (0, 1), (0, 2), (1, 2), (2, 2), (2, 1), (2, 0)]);
+}
+
+/// Tests errors and the unweighted view
+/// 
+/// # Test Case
+/// - Input: An enclosed goal, out-of-range vertices, a blocked start
+/// - Expected: NoPath / InvalidNode; DFS over the grid agrees on reachability
+#[test]
+fn test_errors_and_view() {
+    let mut grid = GridGraph::new(4, 4, Connectivity::Eight);
+    for (x, y) in [(2, 3), (2, 2), (3, 2)] {
+        grid.set_blocked(x, y);
+    }
+    let goal = grid.node(3, 3).unwrap();
+    assert_eq!(AStar::new(&grid).search(0, goal, grid.heuristic(goal)), Err(GraphError::NoPath(0, goal)));
+    assert_eq!(AStar::new(&grid).search(0, 16, |_| 0), Err(GraphError::InvalidNode(16)));
+    assert_eq!(DfsSearch::new(&grid).search(0, goal).unwrap(), None);
+    assert!(DfsSearch::new(&grid).search(0, grid.node(3, 1).unwrap()).unwrap().is_some());
+
+    assert!(grid.is_blocked(2, 2));
+    assert_eq!(grid.neighbors(grid.node(2, 2).unwrap()).count(), 0);
+    assert_eq!(grid.node(4, 0), None);
+    assert_eq!((grid.width(), grid.height(), grid.connectivity()), (4, 4, Connectivity::Eight));
+}
+
+/// Tests A* on a stored graph
+/// 
+/// # Test Case
+/// - Input: Random weighted graphs with a zero heuristic
+/// - Expected: Dijkstra's distances
+#[test]
+fn test_graph_zero_heuristic() {
+    let mut rng = StdRng::seed_from_u64(2);
+    for _ in 0..100 {
+        let mut graph: Graph<(), u32> = Graph::weighted(20);
+        for _ in 0..60 {
+            let _ = graph.add_weighted_edge(rng.gen_range(0..20), rng.gen_range(0..20), rng.gen_range(0..10));
+        }
+        let expected = graph.dijkstra(0).unwrap();
+        for goal in 0..20 {
+            let found = AStar::new(&graph).search(0, goal, |_| 0).ok().map(|found| found.cost);
+            assert_eq!(found, expected.distance(goal));
+        }
+    }
+}
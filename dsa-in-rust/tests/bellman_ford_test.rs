@@ -0,0 +1,202 @@
+//! Integration tests for Bellman–Ford and SPFA
+//! 
+//! TEST CATEGORIES:
+//! - Agreement: Both algorithms against Dijkstra on non-negative graphs
+//! - Negative Weights: Distances against Floyd–Warshall on random graphs
+//! - Negative Cycles: Reported cycles are real, simple and negative
+//! - Applications: Currency arbitrage detection
+//! 
+//! DEV NOTES:
+//! - The Floyd–Warshall reference marks vertex v as on a negative cycle when
+//!   its distance to itself drops below zero
+
+mod common;
+
+use common::random_graph;
+use dsa_in_rust::data_structures::graph::{Graph, GraphError, GraphKind};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashSet;
+
+/// All-pairs distances; `None` for unreachable pairs
+fn floyd_warshall(graph: &Graph<(), i64>) -> Vec<Vec<Option<i64>>> {
+    let n = graph.node_count();
+    let mut dist = vec![vec![None; n]; n];
+    for (i, row) in dist.iter_mut().enumerate() {
+        row[i] = Some(0);
+        for (j, &weight) in graph.edges(i) {
+            row[j] = Some(row[j].map_or(weight, |current: i64| current.min(weight)));
+        }
+    }
+    for k in 0..n {
+        let through = dist[k].clone();
+        for row in dist.iter_mut() {
+            let Some(a) = row[k] else { continue };
+            for (cell, b) in row.iter_mut().zip(&through) {
+                if let Some(b) = b {
+                    if cell.is_none_or(|current| a + b < current) {
+                        *cell = Some(a + b);
+                    }
+                }
+            }
+        }
+    }
+    dist
+}
+
+fn assert_negative_cycle(graph: &Graph<(), i64>, cycle: &[usize]) {
+    assert!(!cycle.is_empty());
+    assert_eq!(cycle.iter().collect::<HashSet<_>>().len(), cycle.len(), "cycle {:?} repeats a vertex", cycle);
+    assert_eq!(cycle[0], *cycle.iter().min().unwrap());
+    let weight: i64 = (0..cycle.len())
+        .map(|i| *graph.edge_weight(cycle[i], cycle[(i + 1) % cycle.len()]).expect("cycle uses real edges"))
+        .sum();
+    assert!(weight < 0, "cycle {:?} has weight {}", cycle, weight);
+}
+
+/// Tests agreement with Dijkstra
+/// 
+/// # Test Case
+/// - Input: Random graphs with non-negative weights
+/// - Expected: Bellman–Ford, SPFA and Dijkstra report the same distances
+#[test]
+fn test_against_dijkstra() {
+    let mut rng = StdRng::seed_from_u64(42);
+    for _ in 0..100 {
+        let graph = random_graph(&mut rng, 25, 80, GraphKind::Directed, |rng| rng.gen_range(0..=30i64));
+        for source in 0..25 {
+            let expected = graph.dijkstra(source).unwrap();
+            assert_eq!(graph.bellman_ford(source).unwrap().distances(), expected.distances());
+            assert_eq!(graph.spfa(source).unwrap().distances(), expected.distances());
+        }
+    }
+}
+
+/// Tests random graphs with negative weights
+/// 
+/// # Test Case
+/// - Input: 300 random graphs with weights in -5..=20
+/// - Expected: Distances match Floyd–Warshall, or a valid negative cycle is
+///   reported exactly when one is reachable from the source
+#[test]
+fn test_negative_weights() {
+    let mut rng = StdRng::seed_from_u64(7);
+    for _ in 0..300 {
+        let nodes = rng.gen_range(1..15);
+        let graph = random_graph(&mut rng, nodes, nodes * 2, GraphKind::Directed, |rng| rng.gen_range(-5..=20i64));
+        let dist = floyd_warshall(&graph);
+        let on_cycle: Vec<bool> = (0..nodes).map(|v| dist[v][v].is_some_and(|d| d < 0)).collect();
+
+        for (source, reference) in dist.iter().enumerate() {
+            let cycle_reachable = (0..nodes).any(|v| on_cycle[v] && reference[v].is_some());
+            for result in [graph.bellman_ford(source), graph.spfa(source)] {
+                match result {
+                    Ok(paths) => {
+                        assert!(!cycle_reachable);
+                        assert_eq!(paths.distances(), reference);
+                        for target in (0..nodes).filter(|&t| reference[t].is_some()) {
+                            let path = paths.path_to(target).unwrap();
+                            let length: i64 = path.windows(2).map(|p| *graph.edge_weight(p[0], p[1]).unwrap()).sum();
+                            assert_eq!(Some(length), reference[target]);
+                        }
+                    }
+                    Err(GraphError::NegativeCycle(cycle)) => {
+                        assert!(cycle_reachable);
+                        assert_negative_cycle(&graph, &cycle);
+                    }
+                    Err(other) => panic!("unexpected error {:?}", other),
+                }
+            }
+        }
+
+        match graph.negative_cycle() {
+            Some(cycle) => assert_negative_cycle(&graph, &cycle),
+            None => assert!(!on_cycle.contains(&true)),
+        }
+    }
+}
+
+/// Tests self-loops and two-vertex cycles
+/// 
+/// # Test Case
+/// - Input: A negative self-loop; a zero-weight cycle
+/// - Expected: The self-loop is a one-vertex cycle; zero cycles are not negative
+#[test]
+fn test_small_cycles() {
+    let mut graph: Graph<(), i32> = Graph::weighted(3);
+    graph.add_weighted_edge(0, 1, 2).unwrap();
+    graph.add_weighted_edge(1, 0, -2).unwrap();
+    assert!(graph.bellman_ford(0).is_ok());
+    assert!(graph.spfa(0).is_ok());
+    assert_eq!(graph.negative_cycle(), None);
+
+    graph.add_weighted_edge(2, 2, -1).unwrap();
+    assert!(graph.bellman_ford(0).is_ok());
+    assert_eq!(graph.bellman_ford(2), Err(GraphError::NegativeCycle(vec![2])));
+    assert_eq!(graph.spfa(2), Err(GraphError::NegativeCycle(vec![2])));
+    assert_eq!(graph.negative_cycle(), Some(vec![2]));
+}
+
+/// Tests arbitrage detection
+/// 
+/// # Test Case
+/// - Input: Exchange rates with weights -ln(rate) scaled to integers
+/// - Expected: The profitable loop USD -> EUR -> JPY -> USD
+#[test]
+fn test_arbitrage() {
+    let currencies = ["USD", "EUR", "GBP", "JPY"];
+    let rates = [
+        (0, 1, 0.92), (1, 0, 1.08), (0, 2, 0.79), (2, 0, 1.26),
+        (1, 3, 163.0), (3, 0, 0.0069), (2, 3, 188.0), (3, 2, 0.0053),
+    ];
+    let mut graph: Graph<(), i64> = Graph::weighted(currencies.len());
+    for (from, to, rate) in rates {
+        let weight = (-f64::ln(rate) * 1e9).round() as i64;
+        graph.add_weighted_edge(from, to, weight).unwrap();
+    }
+    // 0.92 * 163 * 0.0069 = 1.0347 > 1
+    let cycle = graph.negative_cycle().unwrap();
+    let names: Vec<&str> = cycle.iter().map(|&c| currencies[c]).collect();
+    assert_eq!(names, ["USD", "EUR", "JPY"]);
+    assert!(matches!(graph.bellman_ford(2), Err(GraphError::NegativeCycle(_))));
+}
+
+/// Tests error display and invalid sources
+/// 
+/// # Test Case
+/// - Input: A NegativeCycle error; an out-of-range source
+/// - Expected: The cycle printed as a closed walk; InvalidNode
+#[test]
+fn test_errors() {
+    let error = GraphError::NegativeCycle(vec![1, 3, 2]);
+    assert_eq!(error.to_string(), "Negative cycle: 1 -> 3 -> 2 -> 1");
+    let graph: Graph<(), i32> = Graph::weighted(2);
+    assert_eq!(graph.bellman_ford(2).unwrap_err(), GraphError::InvalidNode(2));
+    assert_eq!(graph.spfa(5).unwrap_err(), GraphError::InvalidNode(5));
+}
+
+/// Tests a long negative chain
+/// 
+/// # Test Case
+/// - Input: A 2,000-vertex path of -1 edges closed by an edge of +1,999, then +1,998
+/// - Expected: No negative cycle while the cycle weighs zero; the whole cycle once it weighs -1
+#[test]
+fn test_long_chain() {
+    let n = 2_000;
+    let mut graph: Graph<(), i64> = Graph::weighted(n);
+    for i in 0..n - 1 {
+        graph.add_weighted_edge(i, i + 1, -1).unwrap();
+    }
+    graph.add_weighted_edge(n - 1, 0, n as i64 - 1).unwrap();
+    assert_eq!(graph.spfa(0).unwrap().distance(n - 1), Some(-(n as i64 - 1)));
+    assert_eq!(graph.negative_cycle(), None);
+
+    let mut tighter: Graph<(), i64> = Graph::weighted(n);
+    for i in 0..n - 1 {
+        tighter.add_weighted_edge(i, i + 1, -1).unwrap();
+    }
+    tighter.add_weighted_edge(n - 1, 0, n as i64 - 2).unwrap();
+    let cycle = tighter.negative_cycle().unwrap();
+    assert_eq!(cycle, (0..n).collect::<Vec<_>>());
+    assert!(matches!(tighter.spfa(0), Err(GraphError::NegativeCycle(c)) if c.len() == n));
+}
@@ -0,0 +1,47 @@
+//! Integration tests for Breadth First Search over array-encoded trees
+//! 
+//! TEST CATEGORIES:
+//! - Basic Search: Present and absent values
+//! - Ordering: Shallowest match wins over deeper ones
+//! - Predicates: Generic element types and closures
+
+use dsa_in_rust::algorithms::searching::bfs_search::{breadth_first_search, breadth_first_search_by};
+use dsa_in_rust::algorithms::searching::dfs_search::depth_first_search;
+
+/// Tests basic value search
+/// 
+/// # Test Case
+/// - Tree: [1, 2, 3, 4, 5]
+/// - Search: Present, absent and empty
+/// - Expected: Index of the value or None
+#[test]
+fn test_basic_search() {
+    let arr = [1, 2, 3, 4, 5];
+    assert_eq!(breadth_first_search(&arr, &4), Some(3));
+    assert_eq!(breadth_first_search(&arr, &9), None);
+    assert_eq!(breadth_first_search::<i32>(&[], &1), None);
+}
+
+/// Tests that BFS prefers the shallower match where DFS does not
+/// 
+/// # Test Case
+/// - Tree: 9 at depth 1 (index 2) and depth 2 (index 3)
+/// - Expected: BFS finds index 2, DFS finds index 3
+#[test]
+fn test_shallowest_match() {
+    let arr = [0, 1, 9, 9];
+    assert_eq!(breadth_first_search(&arr, &9), Some(2));
+    assert_eq!(depth_first_search(&arr, &9), Some(3));
+}
+
+/// Tests predicate search over string elements
+/// 
+/// # Test Case
+/// - Tree: Words
+/// - Search: First word longer than four characters
+/// - Expected: Index 1
+#[test]
+fn test_predicate_search() {
+    let arr = ["root", "branch", "leaf", "twig"];
+    assert_eq!(breadth_first_search_by(&arr, |w| w.len() > 4), Some(1));
+}
@@ -0,0 +1,136 @@
+//! Integration tests for the Binary Search family
+//! 
+//! TEST CATEGORIES:
+//! - Bounds: lower_bound, upper_bound and equal_range with duplicates
+//! - Insertion Points: Misses agree with `slice::binary_search`
+//! - Key Search: binary_search_by_key on records
+//! - Exponential Search: Slices, unbounded sequences and iterators
+//! 
+//! DEV NOTES:
+//! - Cross-checks against std on data from `generate_sorted_integers`
+//! - Matches must report the first equal element
+
+use dsa_in_rust::algorithms::searching::binary_search::{
+    binary_search, binary_search_by_key, equal_range, exponential_search, exponential_search_by,
+    exponential_search_iter, lower_bound, upper_bound,
+};
+use dsa_in_rust::utils::helpers::generate_sorted_integers;
+use std::cmp::Ordering;
+
+/// Tests bounds on an array with a run of duplicates
+/// 
+/// # Test Case
+/// - Input: [1, 2, 2, 2, 5], targets 0..=6
+/// - Expected: Bounds bracket the run of equal elements
+#[test]
+fn test_bounds_with_duplicates() {
+    let arr = [1, 2, 2, 2, 5];
+    assert_eq!(lower_bound(&arr, &2), 1);
+    assert_eq!(upper_bound(&arr, &2), 4);
+    assert_eq!(equal_range(&arr, &2), 1..4);
+    assert_eq!(equal_range(&arr, &0), 0..0);
+    assert_eq!(equal_range(&arr, &6), 5..5);
+}
+
+/// Tests searches on an empty slice
+/// 
+/// # Test Case
+/// - Input: []
+/// - Expected: Every search reports insertion point 0
+#[test]
+fn test_empty_slice() {
+    let arr: [i32; 0] = [];
+    assert_eq!(lower_bound(&arr, &1), 0);
+    assert_eq!(upper_bound(&arr, &1), 0);
+    assert_eq!(binary_search(&arr, &1), Err(0));
+    assert_eq!(exponential_search(&arr, &1), Err(0));
+}
+
+/// Tests that results agree with std semantics
+/// 
+/// # Test Case
+/// - Input: Even numbers 2..=2000, every target in 0..=2001
+/// - Expected: Same Ok/Err and index as `slice::binary_search` (unique keys)
+#[test]
+fn test_agrees_with_std() {
+    let arr: Vec<i32> = generate_sorted_integers(1_000).iter().map(|x| x * 2).collect();
+    for target in 0..=2_001 {
+        let expected = arr.binary_search(&target);
+        assert_eq!(binary_search(&arr, &target), expected);
+        assert_eq!(exponential_search(&arr, &target), expected);
+        assert_eq!(lower_bound(&arr, &target), arr.partition_point(|&x| x < target));
+        assert_eq!(upper_bound(&arr, &target), arr.partition_point(|&x| x <= target));
+    }
+}
+
+/// Tests that matches report the first equal element
+/// 
+/// # Test Case
+/// - Input: 100 copies of 7 between 1 and 9
+/// - Expected: Ok(1) from every search
+#[test]
+fn test_first_match_reported() {
+    let mut arr = vec![1];
+    arr.extend([7; 100]);
+    arr.push(9);
+    assert_eq!(binary_search(&arr, &7), Ok(1));
+    assert_eq!(exponential_search(&arr, &7), Ok(1));
+}
+
+/// Tests search by extracted key
+/// 
+/// # Test Case
+/// - Input: Records sorted by age
+/// - Expected: First record with the age, or insertion point
+#[test]
+fn test_binary_search_by_key() {
+    let people = [("ann", 19), ("bob", 25), ("cid", 25), ("dee", 31)];
+    assert_eq!(binary_search_by_key(&people, &25, |p| p.1), Ok(1));
+    assert_eq!(binary_search_by_key(&people, &18, |p| p.1), Err(0));
+    assert_eq!(binary_search_by_key(&people, &40, |p| p.1), Err(4));
+}
+
+/// Tests exponential search over an unbounded sequence
+/// 
+/// # Test Case
+/// - Input: Perfect squares with no upper bound
+/// - Expected: Exact index for squares, insertion point otherwise
+#[test]
+fn test_exponential_search_unbounded() {
+    let square_at = |target: u64| exponential_search_by(|i| Some((i as u64 * i as u64).cmp(&target)));
+    assert_eq!(square_at(0), Ok(0));
+    assert_eq!(square_at(144), Ok(12));
+    assert_eq!(square_at(145), Err(13));
+}
+
+/// Tests exponential search when every index compares Less
+/// 
+/// # Test Case
+/// - Input: A probe that is Less everywhere, and one that is Equal only at usize::MAX
+/// - Expected: No overflow; Err(usize::MAX) and Ok(usize::MAX) after O(log usize::MAX) probes
+#[test]
+fn test_exponential_search_saturates() {
+    let mut probes = 0;
+    let result = exponential_search_by(|_| {
+        probes += 1;
+        Some(Ordering::Less)
+    });
+    assert_eq!(result, Err(usize::MAX));
+    assert!(probes <= 2 * usize::BITS as usize + 2);
+
+    let last = exponential_search_by(|i| Some(if i == usize::MAX { Ordering::Equal } else { Ordering::Less }));
+    assert_eq!(last, Ok(usize::MAX));
+}
+
+/// Tests exponential search over iterators
+/// 
+/// # Test Case
+/// - Input: Infinite and finite sorted iterators
+/// - Expected: Correct positions; finite misses past the end return len
+#[test]
+fn test_exponential_search_iter() {
+    assert_eq!(exponential_search_iter((0..).step_by(3), &300), Ok(100));
+    assert_eq!(exponential_search_iter((0..).step_by(3), &301), Err(101));
+    assert_eq!(exponential_search_iter(vec![1, 3, 5], &9), Err(3));
+    assert_eq!(exponential_search_iter(Vec::<i32>::new(), &1), Err(0));
+}
@@ -9,7 +9,7 @@
 //! - Edge cases (same elements, alternating elements)
 //! - Stability test (preserving order of equal elements)
 
-use dsa_in_rust::algorithms::sorting::bubble_sort::bubble_sort;
+use dsa_in_rust::algorithms::sorting::bubble_sort::{bubble_sort, bubble_sort_by};
 use dsa_in_rust::utils::helpers::generate_sorted_integers;
 
 /// Tests bubble sort with a standard unsorted array
@@ -197,3 +197,15 @@ fn test_stability() {
     assert_eq!(items[1].index, 0);
     assert_eq!(items[2].index, 1);
 }
+
+/// Tests bubble sort with a custom comparator
+///
+/// # Test Case
+/// - Input: [3, 1, 4, 1, 5] sorted in descending order
+/// - Expected: [5, 4, 3, 1, 1]
+#[test]
+fn test_sort_by_descending() {
+    let mut arr = [3, 1, 4, 1, 5];
+    bubble_sort_by(&mut arr, |a, b| b.cmp(a));
+    assert_eq!(arr, [5, 4, 3, 1, 1]);
+}
@@ -0,0 +1,36 @@
+//! Shared helpers for the graph integration tests
+//! 
+//! DEV NOTES:
+//! - Lives in `tests/common/mod.rs` so Cargo does not build it as a test
+//!   binary of its own; test files pull it in with `mod common;`
+
+use dsa_in_rust::data_structures::graph::{Graph, GraphKind};
+use rand::rngs::StdRng;
+use rand::Rng;
+
+/// Builds a random graph by drawing `edges` endpoint pairs uniformly
+/// 
+/// Self-loops are kept and duplicate pairs are skipped, so the graph may end
+/// up with fewer than `edges` edges.
+/// 
+/// # Arguments
+/// * `rng` - Seeded generator, so failures are reproducible
+/// * `nodes` - Number of vertices
+/// * `edges` - Number of endpoint pairs to draw
+/// * `kind` - Directed or undirected
+/// * `weight` - Draws the weight of each edge, e.g. `|rng| rng.gen_range(0..=20)`
+pub fn random_graph<E>(
+    rng: &mut StdRng,
+    nodes: usize,
+    edges: usize,
+    kind: GraphKind,
+    mut weight: impl FnMut(&mut StdRng) -> E,
+) -> Graph<(), E> {
+    let mut graph = Graph::with_kind(nodes, kind);
+    for _ in 0..edges {
+        let (src, dest) = (rng.gen_range(0..nodes), rng.gen_range(0..nodes));
+        let weight = weight(rng);
+        let _ = graph.add_weighted_edge(src, dest, weight);
+    }
+    graph
+}
@@ -0,0 +1,75 @@
+//! Integration tests for the Counting Sort algorithm
+//! 
+//! Test cases cover:
+//! - Basic cases (empty, single element)
+//! - Negative numbers and small key ranges
+//! - Key extraction and stability
+
+use dsa_in_rust::algorithms::sorting::counting_sort::{counting_sort, counting_sort_by_key};
+use dsa_in_rust::utils::helpers::{generate_sorted_integers, shuffle_integers};
+
+/// Tests counting sort with empty and single element arrays
+/// 
+/// # Test Case
+/// - Input: [] and [42]
+/// - Expected: Unchanged
+#[test]
+fn test_trivial_arrays() {
+    let mut empty: [u8; 0] = [];
+    counting_sort(&mut empty);
+    assert_eq!(empty, []);
+
+    let mut single = [42];
+    counting_sort(&mut single);
+    assert_eq!(single, [42]);
+}
+
+/// Tests counting sort with negative numbers
+/// 
+/// # Test Case
+/// - Input: [-3, 1, -4, 1, -5, 9, -2, 6, -5, 3]
+/// - Expected: [-5, -5, -4, -3, -2, 1, 1, 3, 6, 9]
+#[test]
+fn test_negative_numbers() {
+    let mut arr = [-3, 1, -4, 1, -5, 9, -2, 6, -5, 3];
+    counting_sort(&mut arr);
+    assert_eq!(arr, [-5, -5, -4, -3, -2, 1, 1, 3, 6, 9]);
+}
+
+/// Tests counting sort with extreme values of a narrow type
+/// 
+/// # Test Case
+/// - Input: i8 values spanning the full range
+/// - Expected: [-128, -1, 0, 1, 127]
+#[test]
+fn test_full_i8_range() {
+    let mut arr: [i8; 5] = [127, -1, 0, -128, 1];
+    counting_sort(&mut arr);
+    assert_eq!(arr, [-128, -1, 0, 1, 127]);
+}
+
+/// Tests counting sort with a shuffled array
+/// 
+/// # Test Case
+/// - Input: 5,000 shuffled integers
+/// - Expected: Integers 1..=5,000 in order
+#[test]
+fn test_shuffled_array() {
+    let expected = generate_sorted_integers(5_000);
+    let mut arr = expected.clone();
+    shuffle_integers(&mut arr);
+    counting_sort(&mut arr);
+    assert_eq!(arr, expected);
+}
+
+/// Tests counting sort for stability with a key function
+///
+/// # Test Case
+/// - Input: Records keyed on a small integer field
+/// - Expected: Records with equal keys keep their original order
+#[test]
+fn test_stability_by_key() {
+    let mut arr = vec![("x", 2u8), ("y", 1), ("z", 2), ("w", 1)];
+    counting_sort_by_key(&mut arr, |r| r.1 as u64);
+    assert_eq!(arr, vec![("y", 1), ("w", 1), ("x", 2), ("z", 2)]);
+}
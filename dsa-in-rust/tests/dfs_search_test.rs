@@ -8,6 +8,11 @@
 //! - Single Node: Minimal graph cases
 //! - Disconnected Components: Multiple separate subgraphs
 //! - Complex Paths: Multiple possible paths
+//! - Array Trees: Pre-order search over implicit binary trees
+//! - Deep Graphs: Million-vertex paths without stack overflow
+//! - Depth Limits: Depth-limited search and iterative deepening
+//! - Path Enumeration: All simple paths and DAG path counting
+//! - Graph Views: `Graph` and closure-backed graphs
 //! 
 //! DEV NOTES:
 //! - Each test validates specific graph topology
@@ -15,7 +20,9 @@
 //! - Path correctness is verified
 //! - Performance implications documented
 
-use dsa_in_rust::algorithms::searching::dfs_search::DfsSearch;
+use dsa_in_rust::algorithms::searching::dfs_search::{depth_first_search, depth_first_search_by, DfsSearch};
+use dsa_in_rust::data_structures::graph::{Graph, GraphError};
+use dsa_in_rust::data_structures::graph_view::FnGraph;
 
 /// Tests basic path finding in a simple directed graph
 /// 
@@ -32,7 +39,7 @@ fn test_simple_path() {
         vec![]      // 3 (end)
     ];
     let dfs = DfsSearch::new(&graph);
-    assert_eq!(dfs.search(0, 3), Some(vec![0, 1, 2, 3]));
+    assert_eq!(dfs.search(0, 3).unwrap(), Some(vec![0, 1, 2, 3]));
 }
 
 /// Tests path finding in a graph with cycles
@@ -50,7 +57,7 @@ fn test_cycle() {
         vec![]
     ];
     let dfs = DfsSearch::new(&graph);
-    assert_eq!(dfs.search(0, 3), Some(vec![0, 1, 2, 3]));
+    assert_eq!(dfs.search(0, 3).unwrap(), Some(vec![0, 1, 2, 3]));
 }
 
 /// Tests search in empty graph
@@ -58,12 +65,12 @@ fn test_cycle() {
 /// # Test Case
 /// - Graph: Empty graph
 /// - Search: Any nodes
-/// - Expected: None
+/// - Expected: InvalidNode error
 #[test]
 fn test_empty_graph() {
     let graph: Vec<Vec<usize>> = vec![];
     let dfs = DfsSearch::new(&graph);
-    assert_eq!(dfs.search(0, 1), None);
+    assert_eq!(dfs.search(0, 1), Err(GraphError::InvalidNode(0)));
 }
 
 /// Tests single node graph
@@ -76,7 +83,7 @@ fn test_empty_graph() {
 fn test_single_node() {
     let graph = vec![vec![]];
     let dfs = DfsSearch::new(&graph);
-    assert_eq!(dfs.search(0, 0), Some(vec![0]));
+    assert_eq!(dfs.search(0, 0).unwrap(), Some(vec![0]));
 }
 
 /// Tests disconnected components
@@ -94,7 +101,7 @@ fn test_disconnected() {
         vec![2]
     ];
     let dfs = DfsSearch::new(&graph);
-    assert_eq!(dfs.search(0, 2), None);
+    assert_eq!(dfs.search(0, 2).unwrap(), None);
 }
 
 /// Tests complex path with multiple options
@@ -112,7 +119,7 @@ fn test_multiple_paths() {
         vec![]
     ];
     let dfs = DfsSearch::new(&graph);
-    let result = dfs.search(0, 3);
+    let result = dfs.search(0, 3).unwrap();
     assert!(result == Some(vec![0, 1, 3]) || result == Some(vec![0, 2, 3]));
 }
 
@@ -130,7 +137,7 @@ fn test_self_loop() {
         vec![]
     ];
     let dfs = DfsSearch::new(&graph);
-    assert_eq!(dfs.search(0, 2), Some(vec![0, 1, 2]));
+    assert_eq!(dfs.search(0, 2).unwrap(), Some(vec![0, 1, 2]));
 }
 
 /// Tests invalid node indices
@@ -138,12 +145,12 @@ fn test_self_loop() {
 /// # Test Case
 /// - Graph: Simple graph
 /// - Search: Invalid node index
-/// - Expected: None
+/// - Expected: InvalidNode error
 #[test]
 fn test_invalid_node() {
     let graph = vec![vec![1], vec![2], vec![]];
     let dfs = DfsSearch::new(&graph);
-    assert_eq!(dfs.search(0, 5), None);
+    assert_eq!(dfs.search(0, 5), Err(GraphError::InvalidNode(5)));
 }
 
 /// Tests dense graph
@@ -161,10 +168,278 @@ fn test_dense_graph() {
         vec![0, 1, 2]
     ];
     let dfs = DfsSearch::new(&graph);
-    let result = dfs.search(0, 3);
+    let result = dfs.search(0, 3).unwrap();
     assert!(result.is_some());
     let path = result.unwrap();
     assert!(path.starts_with(&[0]));
     assert!(path.ends_with(&[3]));
     assert!(path.len() <= 2); // Direct path should be length 2
-}
\ No newline at end of file
+}
+
+/// Tests array search over an implicit binary tree
+/// 
+/// # Test Case
+/// - Tree: [1, 2, 3, 4, 5] (children of i at 2i+1, 2i+2)
+/// - Search: Present and absent values
+/// - Expected: Index of the value or None
+#[test]
+fn test_array_tree_search() {
+    let arr = [1, 2, 3, 4, 5];
+    assert_eq!(depth_first_search(&arr, &5), Some(4));
+    assert_eq!(depth_first_search(&arr, &6), None);
+    assert_eq!(depth_first_search::<i32>(&[], &1), None);
+}
+
+/// Tests array search returns the first match in pre-order
+/// 
+/// # Test Case
+/// - Tree: Duplicate 9 at index 2 (right child) and index 3 (left grandchild)
+/// - Search: 9
+/// - Expected: Index 3, since the left subtree is explored first
+#[test]
+fn test_array_tree_pre_order_priority() {
+    let arr = [0, 1, 9, 9];
+    assert_eq!(depth_first_search(&arr, &9), Some(3));
+}
+
+/// Tests array search with non-integer elements and predicates
+/// 
+/// # Test Case
+/// - Tree: Strings
+/// - Search: By value and by predicate
+/// - Expected: Matching indices
+#[test]
+fn test_array_tree_generic_and_predicate() {
+    let arr = vec![String::from("root"), String::from("left"), String::from("right")];
+    assert_eq!(depth_first_search(&arr, &String::from("right")), Some(2));
+    assert_eq!(depth_first_search_by(&arr, |s| s.starts_with('l')), Some(1));
+}
+
+/// Tests array search on a large tree without recursion
+/// 
+/// # Test Case
+/// - Tree: 1,000,000 elements, target is the last one
+/// - Search: Full traversal
+/// - Expected: Found at the last index
+#[test]
+fn test_array_tree_large() {
+    let arr: Vec<u32> = (0..1_000_000).collect();
+    assert_eq!(depth_first_search(&arr, &999_999), Some(999_999));
+}
+
+/// Tests search on a very long path graph
+/// 
+/// # Test Case
+/// - Graph: Path 0->1->...->999,999
+/// - Search: 0 to 999,999
+/// - Expected: The full path, without overflowing the stack
+#[test]
+fn test_million_node_path_graph() {
+    let n = 1_000_000;
+    let graph: Vec<Vec<usize>> = (0..n)
+        .map(|i| if i + 1 < n { vec![i + 1] } else { vec![] })
+        .collect();
+    let dfs = DfsSearch::new(&graph);
+    let path = dfs.search(0, n - 1).unwrap().unwrap();
+    assert_eq!(path.len(), n);
+    assert!(path.iter().enumerate().all(|(i, &v)| i == v));
+    assert_eq!(dfs.search(n - 1, 0).unwrap(), None);
+}
+
+/// Tests depth-limited search
+/// 
+/// # Test Case
+/// - Graph: Linear path 0->1->2->3
+/// - Search: 0 to 3 with limits 2 and 3
+/// - Expected: None within 2 edges, full path within 3
+#[test]
+fn test_depth_limited() {
+    let graph = vec![vec![1], vec![2], vec![3], vec![]];
+    let dfs = DfsSearch::new(&graph);
+    assert_eq!(dfs.search_depth_limited(0, 3, 2).unwrap(), None);
+    assert_eq!(dfs.search_depth_limited(0, 3, 3).unwrap(), Some(vec![0, 1, 2, 3]));
+    assert_eq!(dfs.search_depth_limited(0, 0, 0).unwrap(), Some(vec![0]));
+}
+
+/// Tests that depth-limited search re-explores vertices reached by a shorter route
+/// 
+/// # Test Case
+/// - Graph: Long branch 0->1->2->3 explored first, short branch 0->3, and 3->4
+/// - Search: 0 to 4 within 2 edges
+/// - Expected: Path [0,3,4] even though 3 was first reached at depth 3
+#[test]
+fn test_depth_limited_revisits_shallower() {
+    let graph = vec![vec![1, 3], vec![2], vec![3], vec![4], vec![]];
+    let dfs = DfsSearch::new(&graph);
+    assert_eq!(dfs.search(0, 4).unwrap(), Some(vec![0, 1, 2, 3, 4]));
+    assert_eq!(dfs.search_depth_limited(0, 4, 2).unwrap(), Some(vec![0, 3, 4]));
+}
+
+/// Tests iterative deepening returns a shortest path
+/// 
+/// # Test Case
+/// - Graph: Multiple paths of different lengths to the target
+/// - Search: 0 to 5
+/// - Expected: The two-edge path, where plain DFS returns a longer one
+#[test]
+fn test_iterative_deepening_shortest() {
+    let graph = vec![
+        vec![1, 4],
+        vec![2],
+        vec![3],
+        vec![5],
+        vec![5],
+        vec![]
+    ];
+    let dfs = DfsSearch::new(&graph);
+    assert_eq!(dfs.search(0, 5).unwrap(), Some(vec![0, 1, 2, 3, 5]));
+    assert_eq!(dfs.iterative_deepening_search(0, 5).unwrap(), Some(vec![0, 4, 5]));
+}
+
+/// Tests iterative deepening terminates on unreachable targets
+/// 
+/// # Test Case
+/// - Graph: Two disconnected cycles
+/// - Search: Between components and from an invalid vertex
+/// - Expected: None, then InvalidNode error
+#[test]
+fn test_iterative_deepening_unreachable() {
+    let graph = vec![vec![1], vec![0], vec![3], vec![2]];
+    let dfs = DfsSearch::new(&graph);
+    assert_eq!(dfs.iterative_deepening_search(0, 2).unwrap(), None);
+    assert_eq!(dfs.iterative_deepening_search(9, 0), Err(GraphError::InvalidNode(9)));
+}
+
+/// Tests enumeration of all simple paths
+/// 
+/// # Test Case
+/// - Graph: Diamond 0->{1,2}->3 with a cycle back 3->0
+/// - Search: All paths 0 to 3
+/// - Expected: Both branches, cycle never followed
+#[test]
+fn test_all_paths() {
+    let graph = vec![
+        vec![1, 2],
+        vec![3],
+        vec![3],
+        vec![0]
+    ];
+    let dfs = DfsSearch::new(&graph);
+    let paths: Vec<Vec<usize>> = dfs.all_paths(0, 3).unwrap().collect();
+    assert_eq!(paths, vec![vec![0, 1, 3], vec![0, 2, 3]]);
+    assert_eq!(dfs.all_paths(0, 0).unwrap().collect::<Vec<_>>(), vec![vec![0]]);
+    assert!(matches!(dfs.all_paths(7, 0), Err(GraphError::InvalidNode(7))));
+}
+
+/// Tests path enumeration limits
+/// 
+/// # Test Case
+/// - Graph: Complete graph on 5 vertices
+/// - Search: All paths 0 to 4 with length and result limits
+/// - Expected: 16 paths in total, 4 of at most 2 edges, 3 when capped
+#[test]
+fn test_all_paths_limits() {
+    let graph: Vec<Vec<usize>> = (0..5)
+        .map(|i| (0..5).filter(|&j| j != i).collect())
+        .collect();
+    let dfs = DfsSearch::new(&graph);
+    assert_eq!(dfs.all_paths(0, 4).unwrap().count(), 16);
+    assert_eq!(dfs.all_paths(0, 4).unwrap().max_length(2).count(), 4);
+    assert!(dfs.all_paths(0, 4).unwrap().max_length(2).all(|p| p.len() <= 3));
+    assert_eq!(dfs.all_paths(0, 4).unwrap().max_results(3).count(), 3);
+}
+
+/// Tests that path enumeration is lazy on graphs with huge path counts
+/// 
+/// # Test Case
+/// - Graph: 60 chained diamonds (2^60 paths)
+/// - Search: First 5 paths only
+/// - Expected: Returns promptly with 5 valid paths
+#[test]
+fn test_all_paths_lazy() {
+    let diamonds = 60;
+    let mut graph = vec![Vec::new(); 3 * diamonds + 1];
+    for d in 0..diamonds {
+        let base = 3 * d;
+        graph[base] = vec![base + 1, base + 2];
+        graph[base + 1] = vec![base + 3];
+        graph[base + 2] = vec![base + 3];
+    }
+    let dfs = DfsSearch::new(&graph);
+    let target = 3 * diamonds;
+    let first: Vec<Vec<usize>> = dfs.all_paths(0, target).unwrap().take(5).collect();
+    assert_eq!(first.len(), 5);
+    assert!(first.iter().all(|p| p[0] == 0 && *p.last().unwrap() == target));
+    assert_eq!(dfs.count_paths(0, target).unwrap(), Some(1u128 << diamonds));
+}
+
+/// Tests DAG path counting
+/// 
+/// # Test Case
+/// - Graph: DAG with shared sub-paths, and a graph with a cycle
+/// - Search: Count paths
+/// - Expected: Exact count for the DAG, None for the cyclic graph
+#[test]
+fn test_count_paths() {
+    let dag = vec![
+        vec![1, 2, 3],
+        vec![3],
+        vec![3],
+        vec![]
+    ];
+    let dfs = DfsSearch::new(&dag);
+    assert_eq!(dfs.count_paths(0, 3).unwrap(), Some(3));
+    assert_eq!(dfs.count_paths(3, 0).unwrap(), Some(0));
+    assert_eq!(dfs.count_paths(2, 2).unwrap(), Some(1));
+    assert_eq!(dfs.count_paths(0, 3).unwrap(), Some(dfs.all_paths(0, 3).unwrap().count() as u128));
+
+    let cyclic = vec![vec![1], vec![2], vec![0, 3], vec![]];
+    assert_eq!(DfsSearch::new(&cyclic).count_paths(0, 3).unwrap(), None);
+}
+
+/// Tests search over the `Graph` data structure
+/// 
+/// # Test Case
+/// - Graph: `Graph` with edges 0->1->2 and 0->3
+/// - Search: 0 to 2, and an out-of-range target
+/// - Expected: Path [0,1,2], then InvalidNode error
+#[test]
+fn test_graph_structure() {
+    let mut graph = Graph::new(4);
+    graph.add_edge(0, 1).unwrap();
+    graph.add_edge(1, 2).unwrap();
+    graph.add_edge(0, 3).unwrap();
+    let dfs = DfsSearch::new(&graph);
+    assert_eq!(dfs.search(0, 2), Ok(Some(vec![0, 1, 2])));
+    assert_eq!(dfs.search(0, 4), Err(GraphError::InvalidNode(4)));
+    assert_eq!(dfs.count_paths(0, 2), Ok(Some(1)));
+}
+
+/// Tests search over a closure-defined graph
+/// 
+/// # Test Case
+/// - Graph: Implicit ring i -> (i + 1) % 1000 built from a closure
+/// - Search: 0 to 999
+/// - Expected: Path visiting every vertex in order
+#[test]
+fn test_closure_graph() {
+    let ring = FnGraph::new(1_000, |i: usize| [(i + 1) % 1_000]);
+    let dfs = DfsSearch::new(&ring);
+    let path = dfs.search(0, 999).unwrap().unwrap();
+    assert_eq!(path, (0..1_000).collect::<Vec<_>>());
+    assert_eq!(dfs.iterative_deepening_search(0, 3), Ok(Some(vec![0, 1, 2, 3])));
+}
+
+/// Tests that dangling neighbour entries are ignored
+/// 
+/// # Test Case
+/// - Graph: Adjacency list with an edge to nonexistent vertex 9
+/// - Search: 0 to 2
+/// - Expected: Path found through valid edges only
+#[test]
+fn test_dangling_neighbors_ignored() {
+    let graph = vec![vec![9, 1], vec![2], vec![]];
+    let dfs = DfsSearch::new(&graph);
+    assert_eq!(dfs.search(0, 2), Ok(Some(vec![0, 1, 2])));
+    assert_eq!(dfs.all_paths(0, 2).unwrap().count(), 1);
+}
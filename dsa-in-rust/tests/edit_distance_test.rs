@@ -0,0 +1,239 @@
+//! Integration tests for Edit Distances and BK-Tree Fuzzy Search
+//! 
+//! TEST CATEGORIES:
+//! - Known Values: Textbook distance examples
+//! - Reference Agreement: Distances against a naive recursive definition
+//! - Bounded Variants: Agreement with the exact distance around the bound
+//! - Metrics: `str`, `String`, slices and closures through `Metric`
+//! - BK-Tree: Range queries and nearest neighbour against a linear scan
+//! 
+//! DEV NOTES:
+//! - Random words are drawn from a tiny alphabet so distances stay small and
+//!   many pairs sit exactly at the query bound
+
+use dsa_in_rust::algorithms::searching::bk_tree::BkTree;
+use dsa_in_rust::algorithms::searching::edit_distance::{
+    damerau_levenshtein, damerau_levenshtein_bounded, hamming, hamming_bounded, levenshtein,
+    levenshtein_bounded, DamerauLevenshtein, Hamming, Levenshtein, Metric,
+};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Random words of length 0..=max_len over "abc"
+fn random_words(count: usize, max_len: usize, seed: u64) -> Vec<String> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    (0..count)
+        .map(|_| {
+            let len = rng.gen_range(0..=max_len);
+            (0..len).map(|_| (b'a' + rng.gen_range(0..3)) as char).collect()
+        })
+        .collect()
+}
+
+/// Unrestricted Damerau–Levenshtein by brute force over edit sequences is
+/// impractical, so compare against the classic full-matrix formulation
+/// with a lookup of last occurrences done by linear scan
+fn reference_damerau(a: &[u8], b: &[u8]) -> usize {
+    let (n, m) = (a.len(), b.len());
+    let inf = n + m;
+    let mut d = vec![vec![inf; m + 2]; n + 2];
+    for i in 0..=n {
+        d[i + 1][1] = i;
+    }
+    for j in 0..=m {
+        d[1][j + 1] = j;
+    }
+    for i in 1..=n {
+        let mut db = 0;
+        for j in 1..=m {
+            let k = (1..i).rev().find(|&r| a[r - 1] == b[j - 1]).unwrap_or(0);
+            let l = db;
+            let cost = if a[i - 1] == b[j - 1] {
+                db = j;
+                0
+            } else {
+                1
+            };
+            d[i + 1][j + 1] = (d[i][j] + cost)
+                .min(d[i + 1][j] + 1)
+                .min(d[i][j + 1] + 1)
+                .min(d[k][l] + (i - k - 1) + 1 + (j - l - 1));
+        }
+    }
+    d[n + 1][m + 1]
+}
+
+/// Plain recursive Levenshtein definition
+fn reference_levenshtein(a: &[u8], b: &[u8]) -> usize {
+    match (a.split_last(), b.split_last()) {
+        (None, _) => b.len(),
+        (_, None) => a.len(),
+        (Some((x, ra)), Some((y, rb))) => (reference_levenshtein(ra, rb) + usize::from(x != y))
+            .min(reference_levenshtein(ra, b) + 1)
+            .min(reference_levenshtein(a, rb) + 1),
+    }
+}
+
+/// Tests textbook examples
+/// 
+/// # Test Case
+/// - Input: Classic word pairs
+/// - Expected: Published distances
+#[test]
+fn test_known_values() {
+    assert_eq!(levenshtein(b"kitten", b"sitting"), 3);
+    assert_eq!(levenshtein(b"flaw", b"lawn"), 2);
+    assert_eq!(levenshtein(b"", b"abc"), 3);
+    assert_eq!(levenshtein::<u8>(b"", b""), 0);
+    assert_eq!(damerau_levenshtein(b"abcdef", b"abcfed"), 2);
+    assert_eq!(damerau_levenshtein(b"ca", b"abc"), 2);
+    assert_eq!(damerau_levenshtein(b"teh", b"the"), 1);
+    assert_eq!(hamming(b"1011101", b"1001001"), Some(2));
+    assert_eq!(hamming(&[1, 2], &[1, 2, 3]), None);
+}
+
+/// Tests distances against reference implementations
+/// 
+/// # Test Case
+/// - Input: All pairs of 40 random short words
+/// - Expected: Identical distances; Levenshtein is an upper bound for Damerau–Levenshtein
+#[test]
+fn test_against_reference() {
+    let words = random_words(40, 6, 7);
+    for a in &words {
+        for b in &words {
+            let (a, b) = (a.as_bytes(), b.as_bytes());
+            let lev = levenshtein(a, b);
+            let dl = damerau_levenshtein(a, b);
+            assert_eq!(lev, reference_levenshtein(a, b));
+            assert_eq!(dl, reference_damerau(a, b));
+            assert!(dl <= lev);
+        }
+    }
+}
+
+/// Tests bounded variants around the bound
+/// 
+/// # Test Case
+/// - Input: All pairs of random words, bounds 0..=7
+/// - Expected: Some(d) exactly when the distance d is within the bound
+#[test]
+fn test_bounded_variants() {
+    let words = random_words(60, 8, 11);
+    for a in &words {
+        for b in &words {
+            let (a, b) = (a.as_bytes(), b.as_bytes());
+            let (lev, dl, ham) = (levenshtein(a, b), damerau_levenshtein(a, b), hamming(a, b));
+            for max in 0..=7 {
+                assert_eq!(levenshtein_bounded(a, b, max), (lev <= max).then_some(lev));
+                assert_eq!(damerau_levenshtein_bounded(a, b, max), (dl <= max).then_some(dl));
+                assert_eq!(hamming_bounded(a, b, max), ham.filter(|&d| d <= max));
+            }
+        }
+    }
+}
+
+/// Tests the Metric implementations
+/// 
+/// # Test Case
+/// - Input: Multi-byte text, vectors and a closure
+/// - Expected: Character-based distances for text; padded Hamming
+#[test]
+fn test_metrics() {
+    assert_eq!(Levenshtein.distance("café", "cafe"), 1);
+    assert_eq!(levenshtein("café".as_bytes(), "cafe".as_bytes()), 2);
+    assert_eq!(DamerauLevenshtein.distance(&"abcd".to_string(), &"bacd".to_string()), 1);
+    assert_eq!(Hamming.distance("abc", "abd"), 1);
+    assert_eq!(Hamming.distance("abc", "abcde"), 2);
+    assert_eq!(Hamming.distance_within("abc", "xbcde", 2), None);
+    assert_eq!(Levenshtein.distance_within(&vec![1, 2, 3], &vec![3, 2, 1], 1), None);
+
+    let numeric = |a: &i64, b: &i64| a.abs_diff(*b) as usize;
+    assert_eq!(numeric.distance(&3, &-4), 7);
+    assert_eq!(numeric.distance_within(&3, &-4, 6), None);
+}
+
+/// Tests BK-tree range queries against a linear scan
+/// 
+/// # Test Case
+/// - Input: 500 random words (with duplicates), 50 random queries, k in 0..=3
+/// - Expected: Same matches, closest first
+#[test]
+fn test_bk_tree_find_within() {
+    let words = random_words(500, 7, 3);
+    let tree: BkTree<String> = words.iter().cloned().collect();
+    let mut unique = words.clone();
+    unique.sort();
+    unique.dedup();
+    assert_eq!(tree.len(), unique.len());
+
+    for query in random_words(50, 7, 5) {
+        for k in 0..=3 {
+            let mut expected: Vec<(String, usize)> = unique
+                .iter()
+                .map(|w| (w.clone(), Levenshtein.distance(w.as_str(), query.as_str())))
+                .filter(|&(_, d)| d <= k)
+                .collect();
+            expected.sort_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0)));
+
+            let mut found: Vec<(String, usize)> =
+                tree.find_within(query.as_str(), k).into_iter().map(|(w, d)| (w.clone(), d)).collect();
+            assert!(found.windows(2).all(|pair| pair[0].1 <= pair[1].1));
+            found.sort_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(&b.0)));
+            assert_eq!(found, expected, "query {:?} k {}", query, k);
+        }
+    }
+}
+
+/// Tests nearest-neighbour queries and membership
+/// 
+/// # Test Case
+/// - Input: Dictionary words under Damerau–Levenshtein
+/// - Expected: Closest correction found; exact members detected
+#[test]
+fn test_bk_tree_nearest() {
+    let mut tree = BkTree::new(DamerauLevenshtein);
+    tree.extend(["receive", "believe", "their", "weird", "separate", "definitely"].map(String::from));
+    assert!(!tree.insert("their".to_string()));
+
+    assert_eq!(tree.nearest("recieve"), Some((&"receive".to_string(), 1)));
+    assert_eq!(tree.nearest("thier").map(|(w, _)| w.as_str()), Some("their"));
+    assert_eq!(tree.nearest("seperate").map(|(_, d)| d), Some(1));
+    assert!(tree.contains("weird"));
+    assert!(!tree.contains("wierd"));
+
+    let empty: BkTree<String> = BkTree::new(Levenshtein);
+    assert!(empty.is_empty());
+    assert_eq!(empty.nearest("x"), None);
+    assert!(empty.find_within("x", 5).is_empty());
+}
+
+/// Tests nearest neighbour against a linear scan
+/// 
+/// # Test Case
+/// - Input: 300 random words, 100 random queries
+/// - Expected: Same smallest distance
+#[test]
+fn test_bk_tree_nearest_matches_scan() {
+    let words = random_words(300, 8, 17);
+    let tree: BkTree<String> = words.iter().cloned().collect();
+    for query in random_words(100, 8, 19) {
+        let best = words.iter().map(|w| Levenshtein.distance(w.as_str(), query.as_str())).min();
+        assert_eq!(tree.nearest(query.as_str()).map(|(_, d)| d), best);
+    }
+}
+
+/// Tests a BK-tree over numbers with a closure metric
+/// 
+/// # Test Case
+/// - Input: Integers 0..100 with |a - b| as the metric
+/// - Expected: Ranges around the query
+#[test]
+fn test_bk_tree_custom_metric() {
+    let mut tree = BkTree::new(|a: &i32, b: &i32| a.abs_diff(*b) as usize);
+    tree.extend(0..100);
+    let mut found: Vec<i32> = tree.find_within(&50, 3).into_iter().map(|(&x, _)| x).collect();
+    found.sort();
+    assert_eq!(found, [47, 48, 49, 50, 51, 52, 53]);
+    assert_eq!(tree.iter().count(), 100);
+}
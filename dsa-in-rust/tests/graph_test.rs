@@ -6,6 +6,9 @@
 //! - Error Handling: Invalid nodes, duplicate edges
 //! - Complex Scenarios: Cycles, disconnected components
 //! - Performance: Large graphs, dense connections
+//! - Undirected Mode: Symmetric edges counted once
+//! - Mutation: Edge and node removal, index remapping
+//! - Payloads: Node and edge data, label index lookups
 //! 
 //! DEV NOTES:
 //! - Each test focuses on a single aspect
@@ -13,7 +16,9 @@
 //! - Edge cases are explicitly tested
 //! - Performance implications are documented
 
-use dsa_in_rust::data_structures::graph::{Graph, GraphError};
+use dsa_in_rust::data_structures::graph::{Graph, GraphError, GraphKind};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
 /// Tests basic graph creation
 /// 
@@ -173,4 +178,285 @@ fn test_multiple_edges() {
     assert!(graph.has_edge(0, 1));
     assert!(graph.has_edge(0, 2));
     assert!(graph.has_edge(0, 3));
-}
\ No newline at end of file
+}
+
+/// Tests undirected edges
+/// 
+/// # Test Case
+/// - Operation: Add edges to an undirected graph
+/// - Input: Edges 0-1, 1-2 and a self-loop on 3, then 1-0 again
+/// - Expected: Edges usable both ways, counted once; the reverse is a duplicate
+/// - Validates: Undirected mode
+#[test]
+fn test_undirected_edges() {
+    let mut graph = Graph::undirected(4);
+    assert!(!graph.is_directed());
+    assert!(graph.add_edge(0, 1).is_ok());
+    assert!(graph.add_edge(1, 2).is_ok());
+    assert!(graph.add_edge(3, 3).is_ok());
+    assert!(graph.has_edge(1, 0) && graph.has_edge(2, 1));
+    assert_eq!(graph.edge_count(), 3);
+    assert_eq!(graph.add_edge(1, 0), Err(GraphError::EdgeExists(1, 0)));
+    assert!(graph.has_path(2, 0).unwrap());
+    assert_eq!(graph.edges(3).count(), 1);
+}
+
+/// Tests weighted undirected edges
+/// 
+/// # Test Case
+/// - Operation: Read weights from both endpoints
+/// - Input: Weighted edge 2-0 with weight 5
+/// - Expected: Same weight from both sides, listed once in `all_edges`
+/// - Validates: Weights are stored once per edge
+#[test]
+fn test_undirected_weights() {
+    let mut graph: Graph<(), u32> = Graph::with_kind(3, GraphKind::Undirected);
+    graph.add_weighted_edge(2, 0, 5).unwrap();
+    assert_eq!(graph.edge_weight(0, 2), Some(&5));
+    assert_eq!(graph.edge_weight(2, 0), Some(&5));
+    assert_eq!(graph.edges(0).collect::<Vec<_>>(), [(2, &5)]);
+    assert_eq!(graph.all_edges().collect::<Vec<_>>(), [(2, 0, &5)]);
+    assert_eq!(Graph::new(2).kind(), GraphKind::Directed);
+}
+
+/// Tests edge removal
+/// 
+/// # Test Case
+/// - Operation: Remove edges from directed and undirected graphs
+/// - Input: Directed 0->1, 1->2; undirected 0-1 removed as 1-0
+/// - Expected: Weights returned, edge counts updated, missing edges reported
+/// - Validates: remove_edge and its errors
+#[test]
+fn test_remove_edge() {
+    let mut graph: Graph<(), u32> = Graph::weighted(3);
+    graph.add_weighted_edge(0, 1, 4).unwrap();
+    graph.add_weighted_edge(1, 2, 6).unwrap();
+    assert_eq!(graph.remove_edge(1, 0), Err(GraphError::EdgeNotFound(1, 0)));
+    assert_eq!(graph.remove_edge(0, 1), Ok(4));
+    assert!(!graph.has_edge(0, 1));
+    assert_eq!(graph.edge_count(), 1);
+    assert_eq!(graph.edge_weight(1, 2), Some(&6));
+    assert_eq!(graph.remove_edge(0, 3), Err(GraphError::InvalidNode(3)));
+    assert!(graph.add_weighted_edge(0, 1, 9).is_ok());
+
+    let mut undirected = Graph::undirected(2);
+    undirected.add_edge(0, 1).unwrap();
+    assert_eq!(undirected.remove_edge(1, 0), Ok(()));
+    assert!(!undirected.has_edge(0, 1) && !undirected.has_edge(1, 0));
+    assert_eq!(undirected.edge_count(), 0);
+    assert_eq!(GraphError::EdgeNotFound(1, 0).to_string(), "Edge not found: 1 -> 0");
+}
+
+/// Tests node addition and removal
+/// 
+/// # Test Case
+/// - Operation: Add a node, then remove a middle and the last node
+/// - Input: Cycle 0->1->2->0 plus a new node 3 with edge 3->1
+/// - Expected: Incident edges removed, last node moved into the gap
+/// - Validates: add_node, remove_node and NodeRemapping
+#[test]
+fn test_add_remove_node() {
+    let mut graph = Graph::new(3);
+    graph.add_edge(0, 1).unwrap();
+    graph.add_edge(1, 2).unwrap();
+    graph.add_edge(2, 0).unwrap();
+    assert_eq!(graph.add_node(), 3);
+    graph.add_edge(3, 0).unwrap();
+
+    let (_, remapping) = graph.remove_node(1).unwrap();
+    assert_eq!((remapping.removed(), remapping.moved_from(), remapping.removed_edges()), (1, Some(3), 2));
+    assert_eq!(remapping.new_index(1), None);
+    assert_eq!(remapping.new_index(2), Some(2));
+    assert_eq!((graph.node_count(), graph.edge_count()), (3, 2));
+    assert!(graph.has_edge(2, 0) && graph.has_edge(1, 0));
+
+    let (_, remapping) = graph.remove_node(2).unwrap();
+    assert_eq!(remapping.moved_from(), None);
+    assert_eq!((graph.node_count(), graph.edge_count()), (2, 1));
+    assert_eq!(graph.remove_node(2), Err(GraphError::InvalidNode(2)));
+}
+
+/// Tests random mutations against a naive edge set
+/// 
+/// # Test Case
+/// - Operation: 3000 random edge/node additions and removals
+/// - Input: Directed and undirected weighted graphs
+/// - Expected: Same edges, weights and counts as a relabelled set of triples
+/// - Validates: Consistency of adjacency lists, edge ids and counts
+#[test]
+fn test_random_mutations() {
+    let mut rng = StdRng::seed_from_u64(48);
+    for kind in [GraphKind::Directed, GraphKind::Undirected] {
+        let undirected = kind == GraphKind::Undirected;
+        let mut graph: Graph<(), u32> = Graph::with_kind(5, kind);
+        let mut nodes = 5;
+        let mut edges: Vec<(usize, usize, u32)> = Vec::new();
+        let find = |edges: &[(usize, usize, u32)], a: usize, b: usize| {
+            edges.iter().position(|&(u, v, _)| (u, v) == (a, b) || (undirected && (v, u) == (a, b)))
+        };
+        for step in 0..3000 {
+            match rng.gen_range(0..10) {
+                0 => assert_eq!(graph.add_node(), nodes),
+                1 if nodes > 0 => {
+                    let node = rng.gen_range(0..nodes);
+                    let (_, remapping) = graph.remove_node(node).unwrap();
+                    let before = edges.len();
+                    edges.retain(|&(u, v, _)| u != node && v != node);
+                    assert_eq!(remapping.removed_edges(), before - edges.len());
+                    for (u, v, _) in &mut edges {
+                        *u = remapping.new_index(*u).unwrap();
+                        *v = remapping.new_index(*v).unwrap();
+                    }
+                }
+                2..=5 if nodes > 0 => {
+                    let (a, b) = (rng.gen_range(0..nodes), rng.gen_range(0..nodes));
+                    let exists = find(&edges, a, b).is_some();
+                    assert_eq!(graph.add_weighted_edge(a, b, step).is_ok(), !exists);
+                    if !exists {
+                        edges.push((a, b, step));
+                    }
+                }
+                _ if nodes > 0 => {
+                    let (a, b) = (rng.gen_range(0..nodes), rng.gen_range(0..nodes));
+                    match find(&edges, a, b) {
+                        Some(index) => assert_eq!(graph.remove_edge(a, b), Ok(edges.remove(index).2)),
+                        None => assert_eq!(graph.remove_edge(a, b), Err(GraphError::EdgeNotFound(a, b))),
+                    }
+                }
+                _ => {}
+            }
+            nodes = graph.node_count();
+            assert_eq!(graph.edge_count(), edges.len());
+            for &(u, v, weight) in &edges {
+                assert_eq!(graph.edge_weight(u, v), Some(&weight));
+            }
+            let degree: usize = (0..nodes).map(|node| graph.edges(node).count()).sum();
+            let loops = edges.iter().filter(|&&(u, v, _)| u == v).count();
+            let expected = if undirected { 2 * edges.len() - loops } else { edges.len() };
+            assert_eq!(degree, expected);
+        }
+    }
+}
+
+/// Tests node and edge payloads
+/// 
+/// # Test Case
+/// - Operation: Read and modify payloads
+/// - Input: Cities as node payloads, distances as edge payloads
+/// - Expected: Payloads follow their vertices and edges, also after removal
+/// - Validates: node_weight, set_node_weight, edge_weight_mut, remove_node payloads
+#[test]
+fn test_payloads() {
+    let mut graph: Graph<String, u32> = Graph::from_nodes(["Oslo", "Bergen"].map(String::from), GraphKind::Undirected);
+    let trondheim = graph.add_weighted_node("Trondheim".to_string());
+    graph.add_weighted_edge(0, 1, 460).unwrap();
+    graph.add_weighted_edge(0, trondheim, 490).unwrap();
+    assert_eq!(graph.node_weight(trondheim).map(String::as_str), Some("Trondheim"));
+    assert_eq!(graph.node_weight(3), None);
+
+    *graph.edge_weight_mut(trondheim, 0).unwrap() -= 10;
+    assert_eq!(graph.edge_weight(0, trondheim), Some(&480));
+    assert_eq!(graph.edge_weight_mut(1, trondheim), None);
+
+    graph.node_weight_mut(1).unwrap().push_str(" (west)");
+    assert_eq!(graph.set_node_weight(0, "Christiania".to_string()), Ok("Oslo".to_string()));
+    assert_eq!(graph.set_node_weight(5, String::new()), Err(GraphError::InvalidNode(5)));
+
+    let (removed, remapping) = graph.remove_node(1).unwrap();
+    assert_eq!(removed, "Bergen (west)");
+    assert_eq!(remapping.moved_from(), Some(2));
+    assert_eq!(graph.node_weights(), ["Christiania", "Trondheim"]);
+    assert_eq!(graph.edge_weight(1, 0), Some(&480));
+
+    let mut defaults: Graph<u8, ()> = Graph::with_kind(2, GraphKind::Directed);
+    assert_eq!(defaults.add_node(), 2);
+    assert_eq!(defaults.node_weights(), [0, 0, 0]);
+}
+
+/// Tests the label index
+/// 
+/// # Test Case
+/// - Operation: Look up vertices by label while adding, renaming and removing
+/// - Input: Labels "a".."e"; then "a", "b", "a" with a repeated label
+/// - Expected: Indexed lookups match a linear scan after every change
+/// - Validates: build_label_index, node_index and index maintenance
+#[test]
+fn test_label_index() {
+    let mut graph: Graph<&str, ()> = Graph::from_nodes(["a", "b", "c", "d"], GraphKind::Directed);
+    assert_eq!(graph.node_index(&"c"), Some(2));
+    assert!(!graph.has_label_index());
+    graph.build_label_index();
+    assert!(graph.has_label_index());
+    assert_eq!(graph.node_index(&"c"), Some(2));
+
+    assert_eq!(graph.add_weighted_node("e"), 4);
+    assert_eq!(graph.node_index(&"e"), Some(4));
+    graph.remove_node(1).unwrap();
+    assert_eq!(graph.node_index(&"b"), None);
+    assert_eq!(graph.node_index(&"e"), Some(1));
+    graph.set_node_weight(0, "z").unwrap();
+    assert_eq!(graph.node_index(&"a"), None);
+    assert_eq!(graph.node_index(&"z"), Some(0));
+    graph.remove_node(3).unwrap();
+    assert_eq!(graph.node_index(&"d"), None);
+    assert!(graph.has_label_index());
+
+    *graph.node_weight_mut(2).unwrap() = "y";
+    assert!(!graph.has_label_index());
+    assert_eq!(graph.node_index(&"y"), Some(2));
+    assert_eq!(graph.node_index(&"c"), None);
+
+    // Repeated labels: the lowest carrier wins, and removing one keeps the others
+    let mut graph: Graph<&str, ()> = Graph::from_nodes(["a", "b", "a"], GraphKind::Directed);
+    graph.build_label_index();
+    assert_eq!(graph.node_index(&"a"), Some(0));
+    graph.remove_node(2).unwrap();
+    assert_eq!(graph.node_index(&"a"), Some(0));
+    graph.add_weighted_node("a");
+    graph.remove_node(0).unwrap();
+    assert_eq!(graph.node_index(&"a"), Some(0));
+    graph.set_node_weight(0, "b").unwrap();
+    assert_eq!(graph.node_index(&"a"), None);
+    assert_eq!(graph.node_index(&"b"), Some(0));
+    graph.remove_node(0).unwrap();
+    assert_eq!(graph.node_index(&"b"), Some(0));
+}
+
+/// Tests random label index maintenance
+/// 
+/// # Test Case
+/// - Operation: 2000 random node additions, removals and renames
+/// - Input: Unique integer labels
+/// - Expected: The index agrees with the payloads after every step
+/// - Validates: Index updates under vertex moves
+#[test]
+fn test_random_label_index() {
+    let mut rng = StdRng::seed_from_u64(49);
+    let mut graph: Graph<u32, ()> = Graph::from_nodes(0..10, GraphKind::Undirected);
+    graph.build_label_index();
+    let mut next_label = 10;
+    for _ in 0..2000 {
+        let nodes = graph.node_count();
+        match rng.gen_range(0..3) {
+            0 => {
+                graph.add_weighted_node(next_label);
+                next_label += 1;
+            }
+            1 if nodes > 0 => {
+                let node = rng.gen_range(0..nodes);
+                let (label, _) = graph.remove_node(node).unwrap();
+                assert_eq!(graph.node_index(&label), None);
+            }
+            _ if nodes > 0 => {
+                let node = rng.gen_range(0..nodes);
+                let old = graph.set_node_weight(node, next_label).unwrap();
+                assert_eq!(graph.node_index(&old), None);
+                next_label += 1;
+            }
+            _ => {}
+        }
+        for node in 0..graph.node_count() {
+            assert_eq!(graph.node_index(&graph.node_weights()[node]), Some(node));
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//! Integration tests for the Insertion Sort algorithm
+//! 
+//! Test cases cover:
+//! - Basic cases (empty, single element)
+//! - Order cases (sorted, reverse sorted)
+//! - Custom comparators
+//! - Stability test (preserving order of equal elements)
+
+use dsa_in_rust::algorithms::sorting::insertion_sort::{insertion_sort, insertion_sort_by};
+
+/// Tests insertion sort with a standard unsorted array
+/// 
+/// # Test Case
+/// - Input: [64, 34, 25, 12, 22, 11, 90]
+/// - Expected: [11, 12, 22, 25, 34, 64, 90]
+#[test]
+fn test_standard_unsorted_array() {
+    let mut arr = [64, 34, 25, 12, 22, 11, 90];
+    insertion_sort(&mut arr);
+    assert_eq!(arr, [11, 12, 22, 25, 34, 64, 90]);
+}
+
+/// Tests insertion sort with empty and single element arrays
+/// 
+/// # Test Case
+/// - Input: [] and [42]
+/// - Expected: Unchanged
+#[test]
+fn test_trivial_arrays() {
+    let mut empty: [i32; 0] = [];
+    insertion_sort(&mut empty);
+    assert_eq!(empty, []);
+
+    let mut single = [42];
+    insertion_sort(&mut single);
+    assert_eq!(single, [42]);
+}
+
+/// Tests insertion sort with a reverse sorted array
+/// 
+/// # Test Case
+/// - Input: [5, 4, 3, 2, 1]
+/// - Expected: [1, 2, 3, 4, 5]
+#[test]
+fn test_reverse_sorted() {
+    let mut arr = [5, 4, 3, 2, 1];
+    insertion_sort(&mut arr);
+    assert_eq!(arr, [1, 2, 3, 4, 5]);
+}
+
+/// Tests insertion sort with a descending comparator
+/// 
+/// # Test Case
+/// - Input: [3, 1, 4, 1, 5]
+/// - Expected: [5, 4, 3, 1, 1]
+#[test]
+fn test_sort_by_descending() {
+    let mut arr = [3, 1, 4, 1, 5];
+    insertion_sort_by(&mut arr, |a, b| b.cmp(a));
+    assert_eq!(arr, [5, 4, 3, 1, 1]);
+}
+
+/// Tests insertion sort for stability (preserving order of equal elements)
+///
+/// # Test Case
+/// - Input: Pairs sorted by first field only
+/// - Expected: Pairs with equal keys keep their original order
+#[test]
+fn test_stability() {
+    let mut arr = [(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd')];
+    insertion_sort_by(&mut arr, |a, b| a.0.cmp(&b.0));
+    assert_eq!(arr, [(0, 'b'), (0, 'd'), (1, 'a'), (1, 'c')]);
+}
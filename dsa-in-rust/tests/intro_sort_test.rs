@@ -0,0 +1,87 @@
+//! Integration tests for the Introsort algorithm
+//! 
+//! Test cases cover:
+//! - Basic cases (empty, single element)
+//! - Quicksort worst cases (sorted, reverse sorted, all equal)
+//! - Size cases (large shuffled arrays)
+//! - Custom comparators
+
+use dsa_in_rust::algorithms::sorting::intro_sort::{intro_sort, intro_sort_by};
+use dsa_in_rust::utils::helpers::{generate_sorted_integers, shuffle_integers};
+
+/// Tests introsort with a standard unsorted array
+/// 
+/// # Test Case
+/// - Input: [64, 34, 25, 12, 22, 11, 90]
+/// - Expected: [11, 12, 22, 25, 34, 64, 90]
+#[test]
+fn test_standard_unsorted_array() {
+    let mut arr = [64, 34, 25, 12, 22, 11, 90];
+    intro_sort(&mut arr);
+    assert_eq!(arr, [11, 12, 22, 25, 34, 64, 90]);
+}
+
+/// Tests introsort with empty and single element arrays
+/// 
+/// # Test Case
+/// - Input: [] and [42]
+/// - Expected: Unchanged
+#[test]
+fn test_trivial_arrays() {
+    let mut empty: [i32; 0] = [];
+    intro_sort(&mut empty);
+    assert_eq!(empty, []);
+
+    let mut single = [42];
+    intro_sort(&mut single);
+    assert_eq!(single, [42]);
+}
+
+/// Tests introsort with a large shuffled array
+/// 
+/// # Test Case
+/// - Input: 10,000 shuffled integers
+/// - Expected: Integers 1..=10,000 in order
+#[test]
+fn test_large_shuffled_array() {
+    let expected = generate_sorted_integers(10_000);
+    let mut arr = expected.clone();
+    shuffle_integers(&mut arr);
+    intro_sort(&mut arr);
+    assert_eq!(arr, expected);
+}
+
+/// Tests introsort with inputs that degrade naive quicksort
+/// 
+/// # Test Case
+/// - Input: Sorted, reverse sorted and all-equal arrays of 5,000 elements
+/// - Expected: Sorted output for each
+#[test]
+fn test_quicksort_worst_cases() {
+    let sorted = generate_sorted_integers(5_000);
+
+    let mut arr = sorted.clone();
+    intro_sort(&mut arr);
+    assert_eq!(arr, sorted);
+
+    let mut arr = sorted.clone();
+    arr.reverse();
+    intro_sort(&mut arr);
+    assert_eq!(arr, sorted);
+
+    let mut arr = vec![7; 5_000];
+    intro_sort(&mut arr);
+    assert!(arr.iter().all(|&x| x == 7));
+}
+
+/// Tests introsort with a custom comparator on strings
+/// 
+/// # Test Case
+/// - Input: Words sorted by length, descending
+/// - Expected: Longest word first
+#[test]
+fn test_sort_by_length() {
+    let mut arr = vec!["a", "abcd", "ab", "abc"];
+    intro_sort_by(&mut arr, |a, b| b.len().cmp(&a.len()));
+    assert_eq!(arr, vec!["abcd", "abc", "ab", "a"]);
+}
@@ -0,0 +1,223 @@
+//! Integration tests for Maximum Flow
+//! 
+//! TEST CATEGORIES:
+//! - Known networks: Textbook example, undirected links, disconnected sinks
+//! - Randomized: Edmonds–Karp and Dinic against a brute-force minimum cut
+//! - Flow validity: Capacity limits and conservation at every vertex
+//! - Error Handling: Invalid nodes, source equal to sink, negative capacities
+//! - Scale: Long paths (stack safety) and large bipartite matchings
+//! 
+//! DEV NOTES:
+//! - Several maximum flows may exist, so per-edge flows are only checked for
+//!   validity, while values and cut capacities are compared exactly
+
+use dsa_in_rust::algorithms::graph::max_flow::MaxFlow;
+use dsa_in_rust::data_structures::graph::{Graph, GraphError, GraphKind};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+fn random_network(rng: &mut StdRng, nodes: usize, edges: usize, kind: GraphKind) -> Graph<(), i64> {
+    let mut graph = Graph::with_kind(nodes, kind);
+    for _ in 0..edges {
+        let (src, dest) = (rng.gen_range(0..nodes), rng.gen_range(0..nodes));
+        let capacity = rng.gen_range(0..=15);
+        let _ = graph.add_weighted_edge(src, dest, capacity);
+    }
+    graph
+}
+
+/// Capacity of the cut with `side[v]` marking the source side
+fn cut_capacity(graph: &Graph<(), i64>, side: &[bool]) -> i64 {
+    graph
+        .all_edges()
+        .filter(|&(u, v, _)| (side[u] && !side[v]) || (!graph.is_directed() && side[v] && !side[u]))
+        .map(|(_, _, &capacity)| capacity)
+        .sum()
+}
+
+/// Smallest s–t cut over all vertex subsets
+fn brute_force_min_cut(graph: &Graph<(), i64>, source: usize, sink: usize) -> i64 {
+    let n = graph.node_count();
+    (0..1u32 << n)
+        .filter(|mask| mask >> source & 1 == 1 && mask >> sink & 1 == 0)
+        .map(|mask| {
+            let side: Vec<bool> = (0..n).map(|node| mask >> node & 1 == 1).collect();
+            cut_capacity(graph, &side)
+        })
+        .min()
+        .unwrap()
+}
+
+fn assert_valid_flow(graph: &Graph<(), i64>, flow: &MaxFlow<i64>, source: usize, sink: usize) {
+    let mut balance = vec![0; graph.node_count()];
+    assert_eq!(flow.edge_flows().len(), graph.edge_count());
+    for (&(from, to, amount), (src, dest, &capacity)) in flow.edge_flows().iter().zip(graph.all_edges()) {
+        assert!((from, to) == (src, dest) || (!graph.is_directed() && (from, to) == (dest, src)));
+        assert!((0..=capacity).contains(&amount), "flow {} exceeds capacity {}", amount, capacity);
+        balance[from] -= amount;
+        balance[to] += amount;
+    }
+    for (node, &net) in balance.iter().enumerate() {
+        let expected = if node == source { -flow.value() } else if node == sink { flow.value() } else { 0 };
+        assert_eq!(net, expected, "conservation at {}", node);
+    }
+
+    let (source_side, sink_side) = flow.min_cut_partition();
+    assert!(source_side.contains(&source) && sink_side.contains(&sink));
+    let side: Vec<bool> = (0..graph.node_count()).map(|node| flow.is_source_side(node)).collect();
+    assert_eq!(cut_capacity(graph, &side), flow.value());
+    assert_eq!(flow.cut_edges().iter().map(|&(_, _, capacity)| capacity).sum::<i64>(), flow.value());
+}
+
+/// Tests the textbook network
+/// 
+/// # Test Case
+/// - Input: The six-vertex network from Cormen et al. (maximum flow 23)
+/// - Expected: Value 23 from both algorithms and the cut {0, 1, 2, 4} / {3, 5}
+#[test]
+fn test_textbook_network() {
+    let mut graph: Graph<(), i64> = Graph::weighted(6);
+    for (u, v, capacity) in [(0, 1, 16), (0, 2, 13), (2, 1, 4), (1, 3, 12), (3, 2, 9), (2, 4, 14), (4, 3, 7), (3, 5, 20), (4, 5, 4)] {
+        graph.add_weighted_edge(u, v, capacity).unwrap();
+    }
+    for flow in [graph.edmonds_karp(0, 5).unwrap(), graph.dinic(0, 5).unwrap()] {
+        assert_eq!(flow.value(), 23);
+        assert_valid_flow(&graph, &flow, 0, 5);
+        assert_eq!(flow.min_cut_partition(), (vec![0, 1, 2, 4], vec![3, 5]));
+        assert_eq!(flow.cut_edges(), [(1, 3, 12), (4, 3, 7), (4, 5, 4)]);
+    }
+}
+
+/// Tests random directed networks against brute force
+/// 
+/// # Test Case
+/// - Input: 300 random networks with up to 8 vertices
+/// - Expected: Both values equal the brute-force minimum cut; valid flows
+#[test]
+fn test_random_directed() {
+    let mut rng = StdRng::seed_from_u64(50);
+    for _ in 0..300 {
+        let nodes = rng.gen_range(2..9);
+        let edges = rng.gen_range(0..nodes * 3);
+        let graph = random_network(&mut rng, nodes, edges, GraphKind::Directed);
+        let (source, sink) = (0, nodes - 1);
+        let expected = brute_force_min_cut(&graph, source, sink);
+        for flow in [graph.edmonds_karp(source, sink).unwrap(), graph.dinic(source, sink).unwrap()] {
+            assert_eq!(flow.value(), expected);
+            assert_valid_flow(&graph, &flow, source, sink);
+        }
+    }
+}
+
+/// Tests random undirected networks against brute force
+/// 
+/// # Test Case
+/// - Input: 300 random undirected networks with up to 8 vertices
+/// - Expected: Both values equal the brute-force minimum cut; valid flows
+#[test]
+fn test_random_undirected() {
+    let mut rng = StdRng::seed_from_u64(51);
+    for _ in 0..300 {
+        let nodes = rng.gen_range(2..9);
+        let edges = rng.gen_range(0..nodes * 2);
+        let graph = random_network(&mut rng, nodes, edges, GraphKind::Undirected);
+        let (source, sink) = (nodes - 1, 0);
+        let expected = brute_force_min_cut(&graph, source, sink);
+        for flow in [graph.edmonds_karp(source, sink).unwrap(), graph.dinic(source, sink).unwrap()] {
+            assert_eq!(flow.value(), expected);
+            assert_valid_flow(&graph, &flow, source, sink);
+        }
+    }
+}
+
+/// Tests an undirected link used against its stored direction
+/// 
+/// # Test Case
+/// - Input: Undirected 0-1 (5), 2-1 stored as (2, 1) with capacity 3, flow 0 -> 2
+/// - Expected: Value 3; the second edge reported as 1 -> 2
+#[test]
+fn test_undirected_orientation() {
+    let mut graph: Graph<(), i64> = Graph::with_kind(3, GraphKind::Undirected);
+    graph.add_weighted_edge(0, 1, 5).unwrap();
+    graph.add_weighted_edge(2, 1, 3).unwrap();
+    let flow = graph.dinic(0, 2).unwrap();
+    assert_eq!(flow.value(), 3);
+    assert_eq!(flow.edge_flows(), [(0, 1, 3), (1, 2, 3)]);
+    assert_eq!(flow.cut_edges(), [(1, 2, 3)]);
+}
+
+/// Tests a sink that cannot be reached
+/// 
+/// # Test Case
+/// - Input: 0 -> 1, 2 -> 3; flow from 0 to 3
+/// - Expected: Value 0; the source side is {0, 1}
+#[test]
+fn test_unreachable_sink() {
+    let mut graph: Graph<(), u32> = Graph::weighted(4);
+    graph.add_weighted_edge(0, 1, 7).unwrap();
+    graph.add_weighted_edge(2, 3, 7).unwrap();
+    let flow = graph.edmonds_karp(0, 3).unwrap();
+    assert_eq!(flow.value(), 0);
+    assert_eq!(flow.min_cut_partition(), (vec![0, 1], vec![2, 3]));
+    assert!(flow.cut_edges().is_empty());
+    assert_eq!(graph.dinic(0, 3).unwrap(), flow);
+}
+
+/// Tests error handling
+/// 
+/// # Test Case
+/// - Input: Invalid vertices, source equal to sink, a negative capacity
+/// - Expected: InvalidNode, SourceIsSink and NegativeWeight errors
+#[test]
+fn test_errors() {
+    let mut graph: Graph<(), i32> = Graph::weighted(3);
+    graph.add_weighted_edge(0, 1, 4).unwrap();
+    assert_eq!(graph.dinic(0, 3), Err(GraphError::InvalidNode(3)));
+    assert_eq!(graph.edmonds_karp(5, 1), Err(GraphError::InvalidNode(5)));
+    assert_eq!(graph.dinic(1, 1), Err(GraphError::SourceIsSink(1)));
+    assert_eq!(GraphError::SourceIsSink(1).to_string(), "Source and sink are the same node: 1");
+    graph.add_weighted_edge(1, 2, -1).unwrap();
+    assert_eq!(graph.edmonds_karp(0, 2), Err(GraphError::NegativeWeight(1, 2)));
+}
+
+/// Tests a long augmenting path
+/// 
+/// # Test Case
+/// - Input: A path of 200,000 vertices with varying capacities
+/// - Expected: The smallest capacity, without stack overflow
+#[test]
+fn test_long_path() {
+    let n = 200_000;
+    let mut graph: Graph<(), u64> = Graph::weighted(n);
+    for i in 0..n - 1 {
+        graph.add_weighted_edge(i, i + 1, 10 + (i as u64 * 7919) % 1000).unwrap();
+    }
+    let expected = (0..n - 1).map(|i| 10 + (i as u64 * 7919) % 1000).min().unwrap();
+    assert_eq!(graph.dinic(0, n - 1).unwrap().value(), expected);
+    assert_eq!(graph.edmonds_karp(0, n - 1).unwrap().value(), expected);
+}
+
+/// Tests a large bipartite matching
+/// 
+/// # Test Case
+/// - Input: 2000 + 2000 vertices, 20,000 random unit edges, plus a super source and sink
+/// - Expected: Dinic and Edmonds–Karp find the same matching size, with valid flows
+#[test]
+fn test_bipartite_matching() {
+    let mut rng = StdRng::seed_from_u64(52);
+    let side = 2000;
+    let (source, sink) = (2 * side, 2 * side + 1);
+    let mut graph: Graph<(), i64> = Graph::weighted(2 * side + 2);
+    for i in 0..side {
+        graph.add_weighted_edge(source, i, 1).unwrap();
+        graph.add_weighted_edge(side + i, sink, 1).unwrap();
+    }
+    for _ in 0..20_000 {
+        let (left, right) = (rng.gen_range(0..side), rng.gen_range(0..side));
+        let _ = graph.add_weighted_edge(left, side + right, 1);
+    }
+    let dinic = graph.dinic(source, sink).unwrap();
+    assert_valid_flow(&graph, &dinic, source, sink);
+    assert!(dinic.value() > side as i64 * 9 / 10);
+    assert_eq!(graph.edmonds_karp(source, sink).unwrap().value(), dinic.value());
+}
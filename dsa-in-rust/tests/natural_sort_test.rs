@@ -0,0 +1,150 @@
+//! Integration tests for Natural Sort Order
+//! 
+//! TEST CATEGORIES:
+//! - Numeric Runs: Digit runs compared by value, including very long numbers
+//! - Tie-breaking: Leading zeros and case differences
+//! - Unicode: Non-ASCII digits and case folding
+//! - Integration: Comparator used with every `_by` sorter
+//! 
+//! DEV NOTES:
+//! - Comparator must be a total order, so equal-looking strings still get a
+//!   deterministic position
+
+use dsa_in_rust::algorithms::sorting::bubble_sort::bubble_sort_by;
+use dsa_in_rust::algorithms::sorting::insertion_sort::insertion_sort_by;
+use dsa_in_rust::algorithms::sorting::intro_sort::intro_sort_by;
+use dsa_in_rust::algorithms::sorting::natural_sort::{
+    natural_cmp, natural_cmp_ignore_case, natural_sort, natural_sort_with, NaturalOrder,
+};
+use std::cmp::Ordering;
+
+/// Tests numeric comparison of digit runs
+/// 
+/// # Test Case
+/// - Input: log9 vs log10
+/// - Expected: log9 < log10
+#[test]
+fn test_numeric_runs() {
+    assert_eq!(natural_cmp("log9", "log10"), Ordering::Less);
+    assert_eq!(natural_cmp("a2b10", "a2b9"), Ordering::Greater);
+    assert_eq!(natural_cmp("v1.10.0", "v1.9.3"), Ordering::Greater);
+}
+
+/// Tests digit runs longer than any integer type
+/// 
+/// # Test Case
+/// - Input: 40-digit numbers differing in the last digit
+/// - Expected: Compared without overflow
+#[test]
+fn test_very_long_numbers() {
+    let a = format!("id{}1", "9".repeat(40));
+    let b = format!("id{}2", "9".repeat(40));
+    assert_eq!(natural_cmp(&a, &b), Ordering::Less);
+    assert_eq!(natural_cmp("id99", &a), Ordering::Less);
+}
+
+/// Tests leading-zero tie-breaking
+/// 
+/// # Test Case
+/// - Input: Equal values with different zero padding
+/// - Expected: Fewer leading zeros first; value still dominates
+#[test]
+fn test_leading_zeros() {
+    assert_eq!(natural_cmp("file1", "file01"), Ordering::Less);
+    assert_eq!(natural_cmp("file001", "file2"), Ordering::Less);
+    assert_eq!(natural_cmp("file01b", "file1a"), Ordering::Greater);
+}
+
+/// Tests case folding and case tie-breaking
+/// 
+/// # Test Case
+/// - Input: Mixed-case file names
+/// - Expected: Case ignored except as a final tie-break
+#[test]
+fn test_ignore_case() {
+    assert_eq!(natural_cmp("Zeta", "alpha"), Ordering::Less);
+    assert_eq!(natural_cmp_ignore_case("Zeta", "alpha"), Ordering::Greater);
+    assert_eq!(natural_cmp_ignore_case("File", "file"), Ordering::Less);
+    assert_eq!(natural_cmp_ignore_case("ÉTÉ2", "été10"), Ordering::Less);
+}
+
+/// Tests non-ASCII decimal digits
+/// 
+/// # Test Case
+/// - Input: Arabic-Indic and fullwidth digits
+/// - Expected: Compared by numeric value
+#[test]
+fn test_unicode_digits() {
+    // ٩ = 9, ١٠ = 10
+    assert_eq!(natural_cmp("ص٩", "ص١٠"), Ordering::Less);
+    // Fullwidth ９ vs ASCII 10
+    assert_eq!(natural_cmp("x９", "x10"), Ordering::Less);
+}
+
+/// Tests that only identical strings compare equal
+/// 
+/// # Test Case
+/// - Input: Identical and near-identical strings
+/// - Expected: Equal only for the identical pair
+#[test]
+fn test_total_order() {
+    assert_eq!(natural_cmp("abc123", "abc123"), Ordering::Equal);
+    assert_ne!(natural_cmp("abc0123", "abc123"), Ordering::Equal);
+    assert_ne!(natural_cmp_ignore_case("ABC", "abc"), Ordering::Equal);
+}
+
+/// Tests the same number written in different scripts
+/// 
+/// # Test Case
+/// - Input: ASCII "1" and Arabic-Indic "١" (U+0661)
+/// - Expected: Not Equal; code point order decides, consistently in both directions
+#[test]
+fn test_cross_script_tie() {
+    assert_eq!(natural_cmp("1", "\u{0661}"), Ordering::Less);
+    assert_eq!(natural_cmp("\u{0661}", "1"), Ordering::Greater);
+    assert_eq!(natural_cmp("v2", "v\u{FF12}"), Ordering::Less);
+    // The numeric value still decides first
+    assert_eq!(natural_cmp("\u{0662}", "10"), Ordering::Less);
+}
+
+/// Tests the natural_sort convenience functions
+/// 
+/// # Test Case
+/// - Input: Shuffled log file names
+/// - Expected: Numeric order of the suffixes
+#[test]
+fn test_natural_sort() {
+    let mut files: Vec<String> = vec!["log10", "log2", "log1", "log20", "log3"]
+        .into_iter()
+        .map(String::from)
+        .collect();
+    natural_sort(&mut files);
+    assert_eq!(files, ["log1", "log2", "log3", "log10", "log20"]);
+
+    let mut mixed = ["B2", "a10", "A2", "b1"];
+    natural_sort_with(&mut mixed, NaturalOrder::new().ignore_case(true));
+    assert_eq!(mixed, ["A2", "a10", "b1", "B2"]);
+}
+
+/// Tests the comparator with every `_by` sorter
+/// 
+/// # Test Case
+/// - Input: Same file list sorted by bubble, insertion and introsort
+/// - Expected: Identical natural order from each
+#[test]
+fn test_with_every_sorter() {
+    let expected = ["a1", "a2", "a10", "b01", "b1a"];
+    let input = ["b1a", "a10", "a2", "b01", "a1"];
+
+    let mut arr = input;
+    bubble_sort_by(&mut arr, natural_cmp);
+    assert_eq!(arr, expected);
+
+    let mut arr = input;
+    insertion_sort_by(&mut arr, natural_cmp);
+    assert_eq!(arr, expected);
+
+    let mut arr = input;
+    intro_sort_by(&mut arr, natural_cmp);
+    assert_eq!(arr, expected);
+}
@@ -0,0 +1,120 @@
+//! Integration tests for Interpolation, Jump and Fibonacci Search
+//! 
+//! TEST CATEGORIES:
+//! - Correctness: Every element found, misses reported, empty input
+//! - Probe Counts: Uniform data needs few interpolation probes
+//! - Safeguard: Skewed data keeps interpolation within a logarithmic bound
+//! 
+//! DEV NOTES:
+//! - Uniform data comes from `generate_sorted_integers`
+//! - Probe bounds are asserted loosely to leave room for tuning
+
+use dsa_in_rust::algorithms::searching::numeric_search::{
+    fibonacci_search, interpolation_search, jump_search,
+};
+use dsa_in_rust::utils::helpers::generate_sorted_integers;
+
+/// Tests that every element of a small array is found by each search
+/// 
+/// # Test Case
+/// - Input: Odd numbers 1..=99, every element as target
+/// - Expected: Correct index from all three searches
+#[test]
+fn test_finds_every_element() {
+    let arr: Vec<i32> = (0..50).map(|i| 2 * i + 1).collect();
+    for (i, x) in arr.iter().enumerate() {
+        assert_eq!(interpolation_search(&arr, x).index, Some(i));
+        assert_eq!(jump_search(&arr, x).index, Some(i));
+        assert_eq!(fibonacci_search(&arr, x).index, Some(i));
+    }
+}
+
+/// Tests misses below, between and above the stored values
+/// 
+/// # Test Case
+/// - Input: Odd numbers 1..=99, even targets 0..=100
+/// - Expected: None from all three searches
+#[test]
+fn test_misses() {
+    let arr: Vec<i32> = (0..50).map(|i| 2 * i + 1).collect();
+    for target in (0..=100).step_by(2) {
+        assert_eq!(interpolation_search(&arr, &target).index, None);
+        assert_eq!(jump_search(&arr, &target).index, None);
+        assert_eq!(fibonacci_search(&arr, &target).index, None);
+    }
+}
+
+/// Tests empty and single element inputs
+/// 
+/// # Test Case
+/// - Input: [] and [7]
+/// - Expected: No probes on empty input; single element found
+#[test]
+fn test_trivial_inputs() {
+    let empty: [u32; 0] = [];
+    assert_eq!(interpolation_search(&empty, &1).probes, 0);
+    assert_eq!(jump_search(&empty, &1).index, None);
+    assert_eq!(fibonacci_search(&empty, &1).index, None);
+
+    let single = [7u32];
+    assert_eq!(interpolation_search(&single, &7).index, Some(0));
+    assert_eq!(jump_search(&single, &7).index, Some(0));
+    assert_eq!(fibonacci_search(&single, &7).index, Some(0));
+}
+
+/// Tests interpolation probe counts on uniform data
+/// 
+/// # Test Case
+/// - Input: 1,000,000 integers from `generate_sorted_integers`
+/// - Expected: Each lookup takes a single probe
+#[test]
+fn test_interpolation_uniform_probes() {
+    let arr = generate_sorted_integers(1_000_000);
+    for target in [1, 2, 500_000, 999_999, 1_000_000] {
+        let result = interpolation_search(&arr, &target);
+        assert_eq!(result.index, Some(target as usize - 1));
+        assert_eq!(result.probes, 1);
+    }
+}
+
+/// Tests the binary-search safeguard on skewed data
+/// 
+/// # Test Case
+/// - Input: 100,000 values where one huge outlier skews interpolation
+/// - Expected: All lookups succeed within 2 * log2(n) + 2 probes
+#[test]
+fn test_interpolation_skewed_safeguard() {
+    let mut arr: Vec<i64> = (1..100_000).collect();
+    arr.push(i64::MAX / 2);
+    let bound = 2 * 17 + 2;
+    for target in arr.iter().step_by(1_009) {
+        let result = interpolation_search(&arr, target);
+        assert_eq!(arr[result.index.unwrap()], *target);
+        assert!(result.probes <= bound, "{} probes for {}", result.probes, target);
+    }
+}
+
+/// Tests jump and Fibonacci probe bounds
+/// 
+/// # Test Case
+/// - Input: 10,000 sorted integers
+/// - Expected: Jump <= 2 * sqrt(n) + 1 probes, Fibonacci <= 1.5 * log2(n) + 3 probes
+#[test]
+fn test_jump_and_fibonacci_probes() {
+    let arr = generate_sorted_integers(10_000);
+    for target in arr.iter().step_by(37) {
+        assert!(jump_search(&arr, target).probes <= 201);
+        assert!(fibonacci_search(&arr, target).probes <= 23);
+    }
+}
+
+/// Tests that jump search returns the first of several duplicates
+/// 
+/// # Test Case
+/// - Input: [1, 2, 2, 2, 2, 2, 2, 2, 3]
+/// - Expected: Index 1
+#[test]
+fn test_jump_first_duplicate() {
+    let arr = [1, 2, 2, 2, 2, 2, 2, 2, 3];
+    assert_eq!(jump_search(&arr, &2).index, Some(1));
+}
@@ -0,0 +1,76 @@
+//! Integration tests for the Radix Sort algorithm
+//! 
+//! Test cases cover:
+//! - Signed and unsigned integer keys
+//! - Extreme values (MIN/MAX)
+//! - Size cases (large shuffled arrays)
+//! - Key extraction and stability
+
+use dsa_in_rust::algorithms::sorting::radix_sort::{radix_sort, radix_sort_by_key, RadixKey};
+use dsa_in_rust::utils::helpers::{generate_sorted_integers, shuffle_integers};
+
+/// Tests radix sort with mixed-sign integers
+/// 
+/// # Test Case
+/// - Input: [170, -45, 75, -90, 802, 24, 2, 66]
+/// - Expected: [-90, -45, 2, 24, 66, 75, 170, 802]
+#[test]
+fn test_mixed_sign_integers() {
+    let mut arr = [170, -45, 75, -90, 802, 24, 2, 66];
+    radix_sort(&mut arr);
+    assert_eq!(arr, [-90, -45, 2, 24, 66, 75, 170, 802]);
+}
+
+/// Tests radix sort with the extreme values of i64 and u64
+/// 
+/// # Test Case
+/// - Input: MIN, MAX, zero and neighbours
+/// - Expected: Natural numeric order
+#[test]
+fn test_extreme_values() {
+    let mut signed = [i64::MAX, 0, i64::MIN, -1, 1];
+    radix_sort(&mut signed);
+    assert_eq!(signed, [i64::MIN, -1, 0, 1, i64::MAX]);
+
+    let mut unsigned = [u64::MAX, 0, 1 << 40, 7];
+    radix_sort(&mut unsigned);
+    assert_eq!(unsigned, [0, 7, 1 << 40, u64::MAX]);
+}
+
+/// Tests that radix keys preserve ordering across signed boundaries
+/// 
+/// # Test Case
+/// - Input: Adjacent i32 values around zero
+/// - Expected: Keys strictly increasing
+#[test]
+fn test_radix_key_order() {
+    assert!((-1i32).radix_key() < 0i32.radix_key());
+    assert!(i32::MIN.radix_key() < (-1i32).radix_key());
+    assert!('a'.radix_key() < 'b'.radix_key());
+}
+
+/// Tests radix sort with a large shuffled array
+/// 
+/// # Test Case
+/// - Input: 100,000 shuffled integers
+/// - Expected: Integers 1..=100,000 in order
+#[test]
+fn test_large_shuffled_array() {
+    let expected = generate_sorted_integers(100_000);
+    let mut arr = expected.clone();
+    shuffle_integers(&mut arr);
+    radix_sort(&mut arr);
+    assert_eq!(arr, expected);
+}
+
+/// Tests radix sort for stability with a key function
+///
+/// # Test Case
+/// - Input: Strings keyed by length
+/// - Expected: Strings of equal length keep their original order
+#[test]
+fn test_stability_by_key() {
+    let mut arr = vec!["bb", "a", "aa", "c", "ccc"];
+    radix_sort_by_key(&mut arr, |s| s.len() as u64);
+    assert_eq!(arr, vec!["a", "c", "bb", "aa", "ccc"]);
+}
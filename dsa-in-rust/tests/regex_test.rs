@@ -0,0 +1,388 @@
+//! Integration tests for the Regular Expression Engine
+//! 
+//! TEST CATEGORIES:
+//! - Syntax: Classes, escapes, anchors, groups, error reporting and the nesting limit
+//! - Reference Agreement: Random patterns against a set-of-positions matcher
+//! - Automata: Subset construction, minimization, lazy DFA caching and the state limit
+//! - Linear Time: Patterns that make backtracking engines explode, and find_iter scaling
+//! - Unicode: Multi-byte text and classes
+//! 
+//! DEV NOTES:
+//! - The reference matcher computes, for every AST node, the set of end
+//!   positions reachable from a start position; it is slow but obviously right
+
+use dsa_in_rust::algorithms::searching::regex::dfa::Dfa;
+use dsa_in_rust::algorithms::searching::regex::engine::Regex;
+use dsa_in_rust::algorithms::searching::regex::nfa::Nfa;
+use dsa_in_rust::algorithms::searching::regex::parser::{parse, Anchor, Ast, RegexError, NESTING_LIMIT};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::BTreeSet;
+use std::time::Instant;
+
+/// End positions (in chars) of matches of `ast` starting at `i`
+fn ends(ast: &Ast, chars: &[char], i: usize) -> BTreeSet<usize> {
+    match ast {
+        Ast::Empty => BTreeSet::from([i]),
+        Ast::Class(class) => chars.get(i).filter(|&&c| class.contains(c)).map(|_| i + 1).into_iter().collect(),
+        Ast::Anchor(Anchor::Start) => (i == 0).then_some(i).into_iter().collect(),
+        Ast::Anchor(Anchor::End) => (i == chars.len()).then_some(i).into_iter().collect(),
+        Ast::Concat(items) => items.iter().fold(BTreeSet::from([i]), |starts, item| {
+            starts.iter().flat_map(|&s| ends(item, chars, s)).collect()
+        }),
+        Ast::Alternate(branches) => branches.iter().flat_map(|b| ends(b, chars, i)).collect(),
+        Ast::Optional(inner) => ends(inner, chars, i).into_iter().chain([i]).collect(),
+        Ast::Star(inner) => star(inner, chars, BTreeSet::from([i])),
+        Ast::Plus(inner) => star(inner, chars, ends(inner, chars, i)),
+    }
+}
+
+fn star(inner: &Ast, chars: &[char], mut reached: BTreeSet<usize>) -> BTreeSet<usize> {
+    let mut frontier: Vec<usize> = reached.iter().copied().collect();
+    while let Some(p) = frontier.pop() {
+        for e in ends(inner, chars, p) {
+            if reached.insert(e) {
+                frontier.push(e);
+            }
+        }
+    }
+    reached
+}
+
+/// Leftmost-longest non-overlapping matches as byte ranges
+fn reference_find_all(pattern: &str, text: &str) -> Vec<(usize, usize)> {
+    let ast = parse(pattern).unwrap();
+    let chars: Vec<char> = text.chars().collect();
+    let byte = |i: usize| text.char_indices().nth(i).map_or(text.len(), |(b, _)| b);
+    let mut found = Vec::new();
+    let mut position = 0;
+    while position <= chars.len() {
+        let Some((start, end)) =
+            (position..=chars.len()).find_map(|s| ends(&ast, &chars, s).last().map(|&e| (s, e)))
+        else {
+            break;
+        };
+        found.push((byte(start), byte(end)));
+        position = if start == end { end + 1 } else { end };
+    }
+    found
+}
+
+fn find_all(re: &Regex, text: &str) -> Vec<(usize, usize)> {
+    re.find_iter(text).map(|m| (m.start(), m.end())).collect()
+}
+
+/// Random pattern over a small alphabet from a small grammar
+fn random_pattern(rng: &mut StdRng, depth: u32) -> String {
+    let atom = |rng: &mut StdRng| ["a", "b", ".", "[ab]", "[^a]", "^", "$", "c"][rng.gen_range(0..8)].to_string();
+    if depth == 0 {
+        return atom(rng);
+    }
+    let choice = rng.gen_range(0..6);
+    let inner = random_pattern(rng, depth - 1);
+    // Quantified anchors are a syntax error
+    if choice >= 3 && (inner == "^" || inner == "$") {
+        return inner;
+    }
+    match choice {
+        0 => atom(rng),
+        1 => format!("{}{}", inner, random_pattern(rng, depth - 1)),
+        2 => format!("({}|{})", inner, random_pattern(rng, depth - 1)),
+        3 => format!("({})*", inner),
+        4 => format!("({})+", inner),
+        _ => format!("({})?", inner),
+    }
+}
+
+/// Tests basic syntax
+/// 
+/// # Test Case
+/// - Input: Literals, classes, escapes, alternation and quantifiers
+/// - Expected: Matches as in any POSIX engine
+#[test]
+fn test_syntax() {
+    let cases: [(&str, &str, &[&str]); 8] = [
+        ("colou?r", "color colour colouur", &["color", "colour"]),
+        ("[a-c]+", "xabcabx cab", &["abcab", "cab"]),
+        (r"\d{2}", "a{2}5{2}", &["5{2}"]),
+        (r"\w+@\w+\.com", "mail bob@site.com now", &["bob@site.com"]),
+        ("gr(a|e)y", "gray grey groy", &["gray", "grey"]),
+        (r"[^\s]+", " two  words ", &["two", "words"]),
+        (r"a\.b\*", "a.b* axb*", &["a.b*"]),
+        ("[]a]+", "x]a]y", &["]a]"]),
+    ];
+    for (pattern, text, expected) in cases {
+        let re = Regex::new(pattern).unwrap();
+        let found: Vec<&str> = re.find_iter(text).map(|m| m.as_str()).collect();
+        assert_eq!(found, expected, "pattern {}", pattern);
+    }
+}
+
+/// Tests leftmost-longest semantics
+/// 
+/// # Test Case
+/// - Input: Alternatives where the first branch is a prefix of the second
+/// - Expected: The longest alternative at the earliest start
+#[test]
+fn test_leftmost_longest() {
+    let re = Regex::new("a|ab|abc").unwrap();
+    assert_eq!(re.find("xabcd").map(|m| m.range()), Some(1..4));
+    let re = Regex::new("b|abcd").unwrap();
+    assert_eq!(re.find("abcd").map(|m| m.as_str()), Some("abcd"));
+    let re = Regex::new("a*").unwrap();
+    assert_eq!(find_all(&re, "baaa"), [(0, 0), (1, 4), (4, 4)]);
+}
+
+/// Tests anchors
+/// 
+/// # Test Case
+/// - Input: Start and end anchors in several positions
+/// - Expected: Matches only at the text boundaries
+#[test]
+fn test_anchors() {
+    let re = Regex::new("^ab").unwrap();
+    assert!(re.is_match("abc"));
+    assert!(!re.is_match("cab"));
+    assert_eq!(find_all(&re, "abab"), [(0, 2)]);
+
+    let re = Regex::new("b$").unwrap();
+    assert_eq!(find_all(&re, "bbb"), [(2, 3)]);
+    assert!(!re.is_match("ba"));
+
+    let re = Regex::new("^$").unwrap();
+    assert!(re.is_match(""));
+    assert!(!re.is_match("a"));
+    assert!(!Regex::new("a^b").unwrap().is_match("ab"));
+    assert!(Regex::new("(^|x)a").unwrap().is_match("xa"));
+}
+
+/// Tests whole-text matching
+/// 
+/// # Test Case
+/// - Input: Identifier pattern against valid and invalid inputs
+/// - Expected: Only complete matches accepted
+#[test]
+fn test_full_match() {
+    let re = Regex::new("[A-Za-z_][A-Za-z0-9_]*").unwrap();
+    assert!(re.is_full_match("snake_case_1"));
+    assert!(!re.is_full_match("1abc"));
+    assert!(!re.is_full_match("abc def"));
+    assert!(re.is_match("abc def"));
+    assert!(Regex::new("(ab)*").unwrap().is_full_match(""));
+}
+
+/// Tests random patterns against the reference matcher
+/// 
+/// # Test Case
+/// - Input: 400 random patterns, 15 random texts each; default and tiny DFA state limits
+/// - Expected: Same matches, `is_match` and `is_full_match` answers
+#[test]
+fn test_against_reference() {
+    let mut rng = StdRng::seed_from_u64(39);
+    for _ in 0..400 {
+        let pattern = random_pattern(&mut rng, 3);
+        let re = Regex::new(&pattern).unwrap();
+        // Runs out of DFA states part-way through most texts
+        let limited = Regex::new(&pattern).unwrap().dfa_state_limit(2);
+        for _ in 0..15 {
+            let len = rng.gen_range(0..8);
+            let text: String = (0..len).map(|_| ['a', 'b', 'c', '\n'][rng.gen_range(0..4)]).collect();
+            let expected = reference_find_all(&pattern, &text);
+            let ast = parse(&pattern).unwrap();
+            let chars: Vec<char> = text.chars().collect();
+            let full = ends(&ast, &chars, 0).contains(&chars.len());
+            for re in [&re, &limited] {
+                assert_eq!(find_all(re, &text), expected, "pattern {:?} text {:?}", pattern, text);
+                assert_eq!(re.is_match(&text), !expected.is_empty(), "pattern {:?} text {:?}", pattern, text);
+                assert_eq!(re.is_full_match(&text), full, "pattern {:?} text {:?}", pattern, text);
+            }
+        }
+    }
+}
+
+/// Tests that minimization preserves the language and never grows the DFA
+/// 
+/// # Test Case
+/// - Input: Random patterns; unminimized and minimized DFAs of both modes
+/// - Expected: Identical answers on random texts
+#[test]
+fn test_minimization() {
+    let mut rng = StdRng::seed_from_u64(4);
+    for _ in 0..200 {
+        let pattern = random_pattern(&mut rng, 3);
+        let nfa = Nfa::compile(&parse(&pattern).unwrap());
+        for anchored in [false, true] {
+            let dfa = Dfa::from_nfa(&nfa, anchored, 10_000).unwrap();
+            let minimal = dfa.minimize();
+            assert!(minimal.state_count() <= dfa.state_count());
+            assert_eq!(minimal.minimize().state_count(), minimal.state_count());
+            for _ in 0..20 {
+                let len = rng.gen_range(0..10);
+                let text: String = (0..len).map(|_| ['a', 'b', 'c'][rng.gen_range(0..3)]).collect();
+                assert_eq!(dfa.is_match(&text), minimal.is_match(&text), "{:?} {:?}", pattern, text);
+            }
+        }
+    }
+}
+
+/// Tests known minimal DFA sizes
+/// 
+/// # Test Case
+/// - Input: Equivalent patterns written differently
+/// - Expected: Minimized DFAs of the same size
+#[test]
+fn test_equivalent_patterns_minimize_alike() {
+    let size = |p: &str| Dfa::from_nfa(&Nfa::compile(&parse(p).unwrap()), true, 1000).unwrap().minimize().state_count();
+    assert_eq!(size("(a|b)*"), size("(a*b*)*"));
+    assert_eq!(size("a+"), size("aa*"));
+    assert_eq!(size("(ab|ab)c"), size("abc"));
+}
+
+/// Tests the DFA state limit and the NFA fallback
+/// 
+/// # Test Case
+/// - Input: "n-th character from the end is a", which needs 2^n DFA states
+/// - Expected: DfaTooLarge from subset construction; the lazy DFAs build only
+///   the states a text reaches, and past their limit `Regex` answers via the NFA
+#[test]
+fn test_state_limit() {
+    let pattern = format!("(a|b)*a{}", "(a|b)".repeat(14));
+    let nfa = Nfa::compile(&parse(&pattern).unwrap());
+    assert_eq!(Dfa::from_nfa(&nfa, false, 500).unwrap_err(), RegexError::DfaTooLarge(500));
+
+    let re = Regex::new(&pattern).unwrap();
+    assert_eq!(re.dfa_state_count(), 0);
+    let text = format!("a{}", "b".repeat(14));
+    assert!(re.is_match(&text));
+    assert!(re.is_full_match(&text));
+    assert!(!re.is_match(&"b".repeat(15)));
+    // At most one new state per character read, against 2^15 for the full DFA
+    assert!(re.dfa_state_count() <= 3 * 16);
+
+    let limited = Regex::new(&pattern).unwrap().dfa_state_limit(8);
+    let long: String = (0..200).map(|i| if i % 3 == 0 { 'a' } else { 'b' }).collect();
+    assert!(limited.is_match(&long));
+    assert!(!limited.is_full_match(&long));
+    assert!(!limited.is_match(&"b".repeat(200)));
+    assert_eq!(limited.find(&text).map(|m| m.range()), Some(0..15));
+    assert!(limited.dfa_state_count() <= 16);
+}
+
+/// Tests that cached DFA states are reused
+/// 
+/// # Test Case
+/// - Input: The same queries repeated, and a clone of the regex
+/// - Expected: No new states the second time; the clone keeps the cache
+#[test]
+fn test_lazy_dfa_cache() {
+    let re = Regex::new("[a-z]+@[a-z]+").unwrap();
+    assert_eq!(re.dfa_state_count(), 0);
+    assert!(re.is_match("mail bob@site now"));
+    assert!(!re.is_full_match("bob@site now"));
+    let built = re.dfa_state_count();
+    assert!(built > 0);
+    assert!(re.is_match("mail bob@site now"));
+    assert!(!re.is_full_match("bob@site now"));
+    assert_eq!(re.dfa_state_count(), built);
+    assert_eq!(re.clone().dfa_state_count(), built);
+    assert_eq!(re.dfa_state_limit(0).dfa_state_count(), 0);
+}
+
+/// Tests patterns that are exponential for backtracking engines
+/// 
+/// # Test Case
+/// - Input: (a*)*b and (a|aa)*c against long runs of 'a'
+/// - Expected: Answers in linear time
+#[test]
+fn test_linear_time() {
+    let text = "a".repeat(20_000);
+    assert!(!Regex::new("(a*)*b").unwrap().is_match(&text));
+    assert!(Regex::new("(a|aa)*c").unwrap().find(&text).is_none());
+    let nested = Regex::new("((a+)+)+$").unwrap();
+    assert_eq!(nested.find(&text).map(|m| m.range()), Some(0..20_000));
+}
+
+/// Tests that iterating over all matches scales linearly
+/// 
+/// # Test Case
+/// - Input: a|a.*b over runs of 'a' of length n and 8n; every longest-match
+///   search looks for a 'b' up to the end of the text
+/// - Expected: n one-character matches; 8x the text takes well under the 64x
+///   of a quadratic scan (best of three runs, to smooth out timing noise)
+#[test]
+fn test_find_iter_scaling() {
+    let re = Regex::new("a|a.*b").unwrap();
+    let time = |n: usize| {
+        let text = "a".repeat(n);
+        (0..3)
+            .map(|_| {
+                let started = Instant::now();
+                assert_eq!(re.find_iter(&text).count(), n);
+                started.elapsed()
+            })
+            .min()
+            .unwrap()
+    };
+    let small = time(4_000);
+    let large = time(32_000);
+    assert!(large < small * 24, "4,000 chars: {:?}, 32,000 chars: {:?}", small, large);
+
+    let text = format!("{}b", "a".repeat(1_000));
+    assert_eq!(find_all(&re, &text), [(0, 1_001)]);
+}
+
+/// Tests Unicode text and classes
+/// 
+/// # Test Case
+/// - Input: Multi-byte characters in patterns and text
+/// - Expected: Byte offsets on char boundaries
+#[test]
+fn test_unicode() {
+    let re = Regex::new("[α-ω]+").unwrap();
+    let m = re.find("abc αβγ def").unwrap();
+    assert_eq!(m.as_str(), "αβγ");
+    assert_eq!(m.range(), 4..10);
+
+    let re = Regex::new("é.").unwrap();
+    assert_eq!(re.find("caféü").map(|m| m.as_str()), Some("éü"));
+    let empties: Vec<usize> = Regex::new("x*").unwrap().find_iter("日本").map(|m| m.start()).collect();
+    assert_eq!(empties, [0, 3, 6]);
+}
+
+/// Tests the nesting limit
+/// 
+/// # Test Case
+/// - Input: 50,000 nested groups, 50,000 stacked quantifiers, deeply nested alternations
+/// - Expected: TooDeep errors instead of a stack overflow; nesting at the limit still compiles
+#[test]
+fn test_nesting_limit() {
+    let groups = format!("{}a{}", "(".repeat(50_000), ")".repeat(50_000));
+    assert_eq!(Regex::new(&groups).unwrap_err(), RegexError::TooDeep(NESTING_LIMIT));
+    let stars = format!("a{}", "*".repeat(50_000));
+    assert!(matches!(Regex::new(&stars), Err(RegexError::TooDeep(_))));
+    let alternations = format!("{}b{}", "(a|".repeat(50_000), ")".repeat(50_000));
+    assert!(matches!(Regex::new(&alternations), Err(RegexError::TooDeep(_))));
+
+    let deepest = format!("{}a{}", "(".repeat(NESTING_LIMIT), ")".repeat(NESTING_LIMIT));
+    assert_eq!(Regex::new(&deepest).unwrap().find("xa").map(|m| m.range()), Some(1..2));
+    let nested_stars = format!("{}a{}", "(".repeat(100), ")*".repeat(100));
+    assert!(Regex::new(&nested_stars).unwrap().is_full_match("aaa"));
+    assert!(RegexError::TooDeep(3).to_string().contains("position 3"));
+}
+
+/// Tests syntax errors
+/// 
+/// # Test Case
+/// - Input: Malformed patterns
+/// - Expected: The matching error with its position
+#[test]
+fn test_errors() {
+    assert_eq!(Regex::new("(ab").unwrap_err(), RegexError::UnexpectedEnd);
+    assert_eq!(Regex::new("ab)").unwrap_err(), RegexError::UnmatchedParen(2));
+    assert_eq!(Regex::new("a|*").unwrap_err(), RegexError::NothingToRepeat(2));
+    assert_eq!(Regex::new("^*").unwrap_err(), RegexError::NothingToRepeat(1));
+    assert_eq!(Regex::new(r"\q").unwrap_err(), RegexError::InvalidEscape(0));
+    assert_eq!(Regex::new("[z-a]").unwrap_err(), RegexError::InvalidRange(1));
+    assert_eq!(Regex::new("[ab").unwrap_err(), RegexError::UnexpectedEnd);
+    assert_eq!(Regex::new("a\\").unwrap_err(), RegexError::UnexpectedEnd);
+}
@@ -0,0 +1,180 @@
+//! Integration tests for Strongly Connected Components
+//! 
+//! TEST CATEGORIES:
+//! - Known graphs: The cycle from the graph tests, chains, self-loops
+//! - Random graphs: Tarjan and Kosaraju against mutual reachability
+//! - Condensation: Acyclic, ids in topological order, one edge per component pair
+//! - Scale: Long chains and cycles (stack safety)
+//! 
+//! DEV NOTES:
+//! - Component numbering may differ between the algorithms, so random tests
+//!   compare partitions and check the topological numbering separately
+
+mod common;
+
+use common::random_graph;
+use dsa_in_rust::algorithms::graph::scc::Components;
+use dsa_in_rust::data_structures::graph::{Graph, GraphKind};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashSet;
+
+/// The cyclic graph from `graph_test::test_cycle`: 0 -> 1 -> 2 -> 0
+fn triangle() -> Graph {
+    let mut graph = Graph::new(3);
+    graph.add_edge(0, 1).unwrap();
+    graph.add_edge(1, 2).unwrap();
+    graph.add_edge(2, 0).unwrap();
+    graph
+}
+
+fn assert_valid(graph: &Graph, components: &Components) {
+    let n = graph.node_count();
+    assert_eq!(components.ids().len(), n);
+    assert_eq!(components.ids().iter().collect::<HashSet<_>>().len(), components.count());
+    for a in 0..n {
+        for b in 0..n {
+            let mutual = graph.has_path(a, b).unwrap() && graph.has_path(b, a).unwrap();
+            assert_eq!(components.same_component(a, b), mutual, "vertices {} and {}", a, b);
+        }
+        for b in (0..n).filter(|&b| graph.has_edge(a, b)) {
+            assert!(components.component(a) <= components.component(b), "edge {} -> {} goes backwards", a, b);
+        }
+    }
+}
+
+/// Tests the cycle from the graph tests
+/// 
+/// # Test Case
+/// - Input: 0 -> 1 -> 2 -> 0
+/// - Expected: A single component; the condensation is one vertex without edges
+#[test]
+fn test_cycle() {
+    let graph = triangle();
+    for components in [graph.tarjan_scc(), graph.kosaraju_scc()] {
+        assert_eq!(components.count(), 1);
+        assert_eq!(components.ids(), [0, 0, 0]);
+    }
+    let (dag, _) = graph.condensation();
+    assert_eq!(dag.node_count(), 1);
+    assert_eq!(dag.edge_count(), 0);
+}
+
+/// Tests the cycle with attached vertices
+/// 
+/// # Test Case
+/// - Input: 3 -> {0 -> 1 -> 2 -> 0} -> 4, plus a self-loop on 4
+/// - Expected: Components [3], [0, 1, 2], [4] in that order; a chain condensation
+#[test]
+fn test_cycle_with_tails() {
+    let mut graph = Graph::new(5);
+    for (from, to) in [(0, 1), (1, 2), (2, 0), (3, 0), (2, 4), (4, 4)] {
+        graph.add_edge(from, to).unwrap();
+    }
+    for components in [graph.tarjan_scc(), graph.kosaraju_scc()] {
+        assert_eq!(components.members(), [vec![3], vec![0, 1, 2], vec![4]]);
+    }
+    let (dag, _) = graph.condensation();
+    assert_eq!(dag.edge_count(), 2);
+    assert!(dag.has_edge(0, 1) && dag.has_edge(1, 2));
+}
+
+/// Tests graphs without cycles
+/// 
+/// # Test Case
+/// - Input: Empty graph; edgeless graph; a DAG
+/// - Expected: No components; one component per vertex
+#[test]
+fn test_acyclic() {
+    assert_eq!(Graph::new(0).tarjan_scc().count(), 0);
+    assert_eq!(Graph::new(0).kosaraju_scc().count(), 0);
+    assert_eq!(Graph::new(4).tarjan_scc().count(), 4);
+
+    let mut graph = Graph::new(4);
+    for (from, to) in [(3, 1), (1, 0), (3, 2), (2, 0)] {
+        graph.add_edge(from, to).unwrap();
+    }
+    for components in [graph.tarjan_scc(), graph.kosaraju_scc()] {
+        assert_eq!(components.count(), 4);
+        assert_valid(&graph, &components);
+    }
+}
+
+/// Tests both algorithms on random graphs
+/// 
+/// # Test Case
+/// - Input: 300 random graphs with up to 25 vertices
+/// - Expected: Components match mutual reachability, numbered topologically,
+///   and both algorithms give the same partition
+#[test]
+fn test_random_graphs() {
+    let mut rng = StdRng::seed_from_u64(46);
+    for _ in 0..300 {
+        let nodes = rng.gen_range(1..25);
+        let edges = rng.gen_range(0..nodes * 2);
+        let graph = random_graph(&mut rng, nodes, edges, GraphKind::Directed, |_| ());
+        let (tarjan, kosaraju) = (graph.tarjan_scc(), graph.kosaraju_scc());
+        assert_valid(&graph, &tarjan);
+        assert_valid(&graph, &kosaraju);
+        let mut tarjan_members = tarjan.members();
+        let mut kosaraju_members = kosaraju.members();
+        tarjan_members.sort();
+        kosaraju_members.sort();
+        assert_eq!(tarjan_members, kosaraju_members);
+    }
+}
+
+/// Tests the condensation of random graphs
+/// 
+/// # Test Case
+/// - Input: 200 random graphs
+/// - Expected: A DAG with an edge exactly between components joined by an original edge
+#[test]
+fn test_condensation() {
+    let mut rng = StdRng::seed_from_u64(47);
+    for _ in 0..200 {
+        let nodes = rng.gen_range(1..30);
+        let edges = rng.gen_range(0..nodes * 2);
+        let graph = random_graph(&mut rng, nodes, edges, GraphKind::Directed, |_| ());
+        let (dag, components) = graph.condensation();
+        assert_eq!(dag.node_count(), components.count());
+        assert!(dag.topological_sort().is_ok());
+
+        let mut expected = HashSet::new();
+        for from in 0..nodes {
+            for to in (0..nodes).filter(|&to| graph.has_edge(from, to)) {
+                let (a, b) = (components.component(from), components.component(to));
+                if a != b {
+                    expected.insert((a, b));
+                }
+            }
+        }
+        assert_eq!(dag.edge_count(), expected.len());
+        for (a, b) in expected {
+            assert!(a < b && dag.has_edge(a, b));
+        }
+    }
+}
+
+/// Tests long chains and cycles
+/// 
+/// # Test Case
+/// - Input: A path of 1,000,000 vertices, then closed into one cycle
+/// - Expected: 1,000,000 singleton components without stack overflow; then one component
+#[test]
+fn test_deep_graphs() {
+    let n = 1_000_000;
+    let mut graph = Graph::new(n);
+    for i in 0..n - 1 {
+        graph.add_edge(i, i + 1).unwrap();
+    }
+    let expected: Vec<usize> = (0..n).collect();
+    assert_eq!(graph.tarjan_scc().ids(), expected);
+    assert_eq!(graph.kosaraju_scc().ids(), expected);
+
+    graph.add_edge(n - 1, 0).unwrap();
+    assert_eq!(graph.tarjan_scc().count(), 1);
+    assert_eq!(graph.kosaraju_scc().count(), 1);
+    let (dag, _) = graph.condensation();
+    assert_eq!((dag.node_count(), dag.edge_count()), (1, 0));
+}
@@ -0,0 +1,166 @@
+//! Integration tests for Shortest Paths on Weighted Graphs
+//! 
+//! TEST CATEGORIES:
+//! - Correctness: Dijkstra against exhaustive relaxation on random graphs
+//! - Paths: Reconstructed paths are real paths with the reported length
+//! - Error Handling: Unreachable targets, invalid nodes, negative weights
+//! - Weight Types: Unsigned and signed integer weights
+//! 
+//! DEV NOTES:
+//! - The reference repeatedly relaxes every edge until nothing changes, which
+//!   is slow but independent of any priority queue logic
+
+mod common;
+
+use common::random_graph;
+use dsa_in_rust::data_structures::graph::{Graph, GraphError, GraphKind};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+fn relaxation_distances(graph: &Graph<(), u64>, source: usize) -> Vec<Option<u64>> {
+    let mut distances = vec![None; graph.node_count()];
+    distances[source] = Some(0);
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for node in 0..graph.node_count() {
+            let Some(distance) = distances[node] else { continue };
+            for (next, &weight) in graph.edges(node) {
+                if distances[next].is_none_or(|current| distance + weight < current) {
+                    distances[next] = Some(distance + weight);
+                    changed = true;
+                }
+            }
+        }
+    }
+    distances
+}
+
+fn path_length(graph: &Graph<(), u64>, path: &[usize]) -> u64 {
+    path.windows(2).map(|pair| *graph.edge_weight(pair[0], pair[1]).expect("path uses real edges")).sum()
+}
+
+/// Tests Dijkstra against exhaustive relaxation
+/// 
+/// # Test Case
+/// - Input: 200 random graphs with up to 40 nodes, including zero weights
+/// - Expected: Identical distances from every source
+#[test]
+fn test_against_relaxation() {
+    let mut rng = StdRng::seed_from_u64(41);
+    for _ in 0..200 {
+        let nodes = rng.gen_range(1..40);
+        let edges = rng.gen_range(0..nodes * 4);
+        let graph = random_graph(&mut rng, nodes, edges, GraphKind::Directed, |rng| rng.gen_range(0..=20u64));
+        for source in 0..nodes {
+            let paths = graph.dijkstra(source).unwrap();
+            assert_eq!(paths.distances(), relaxation_distances(&graph, source));
+        }
+    }
+}
+
+/// Tests path reconstruction
+/// 
+/// # Test Case
+/// - Input: Random graphs; paths to every reachable vertex
+/// - Expected: Paths start at the source, follow edges and sum to the distance
+#[test]
+fn test_paths() {
+    let mut rng = StdRng::seed_from_u64(5);
+    for _ in 0..100 {
+        let graph = random_graph(&mut rng, 30, 90, GraphKind::Directed, |rng| rng.gen_range(0..=50u64));
+        let paths = graph.dijkstra(0).unwrap();
+        assert_eq!(paths.source(), 0);
+        assert_eq!(paths.predecessor(0), None);
+        for target in 0..30 {
+            match paths.distance(target) {
+                Some(distance) => {
+                    let path = paths.path_to(target).unwrap();
+                    assert_eq!((path[0], *path.last().unwrap()), (0, target));
+                    assert_eq!(path_length(&graph, &path), distance);
+                    assert_eq!(graph.shortest_path(0, target), Ok((distance, path)));
+                }
+                None => {
+                    assert_eq!(paths.path_to(target), Err(GraphError::NoPath(0, target)));
+                    assert_eq!(graph.shortest_path(0, target), Err(GraphError::NoPath(0, target)));
+                }
+            }
+        }
+    }
+}
+
+/// Tests a small road network
+/// 
+/// # Test Case
+/// - Input: A direct road that is slower than a detour
+/// - Expected: The detour, and the predecessor tree that goes with it
+#[test]
+fn test_detour() {
+    let mut roads: Graph<(), u32> = Graph::weighted(5);
+    for (src, dest, minutes) in [(0, 4, 100), (0, 1, 10), (1, 2, 10), (2, 3, 10), (3, 4, 10), (1, 3, 25)] {
+        roads.add_weighted_edge(src, dest, minutes).unwrap();
+    }
+    let paths = roads.dijkstra(0).unwrap();
+    assert_eq!(paths.distances(), [Some(0), Some(10), Some(20), Some(30), Some(40)]);
+    assert_eq!(paths.predecessors(), [None, Some(0), Some(1), Some(2), Some(3)]);
+    assert_eq!(paths.path_to(4).unwrap(), [0, 1, 2, 3, 4]);
+    assert_eq!(paths.path_to(0).unwrap(), [0]);
+}
+
+/// Tests error handling
+/// 
+/// # Test Case
+/// - Input: Invalid sources and targets, unreachable targets, negative edges
+/// - Expected: InvalidNode, NoPath and NegativeWeight errors
+#[test]
+fn test_errors() {
+    let mut graph: Graph<(), i32> = Graph::weighted(3);
+    graph.add_weighted_edge(0, 1, 5).unwrap();
+    assert_eq!(graph.dijkstra(3).unwrap_err(), GraphError::InvalidNode(3));
+    assert_eq!(graph.shortest_path(0, 9).unwrap_err(), GraphError::InvalidNode(9));
+    assert_eq!(graph.shortest_path(0, 2).unwrap_err(), GraphError::NoPath(0, 2));
+    assert_eq!(graph.dijkstra(0).unwrap().path_to(7).unwrap_err(), GraphError::InvalidNode(7));
+
+    graph.add_weighted_edge(1, 2, -1).unwrap();
+    assert_eq!(graph.dijkstra(0).unwrap_err(), GraphError::NegativeWeight(1, 2));
+    assert_eq!(graph.shortest_path(0, 1).unwrap_err(), GraphError::NegativeWeight(1, 2));
+}
+
+/// Tests the weighted edge API
+/// 
+/// # Test Case
+/// - Input: Weighted edges, a duplicate edge and lookups
+/// - Expected: Weights stored per edge; duplicates rejected; unweighted graphs unchanged
+#[test]
+fn test_weighted_edges() {
+    let mut graph: Graph<(), u8> = Graph::weighted(3);
+    graph.add_weighted_edge(0, 1, 3).unwrap();
+    graph.add_weighted_edge(0, 2, 4).unwrap();
+    assert_eq!(graph.add_weighted_edge(0, 1, 9), Err(GraphError::EdgeExists(0, 1)));
+    assert_eq!(graph.edge_weight(0, 1), Some(&3));
+    assert_eq!(graph.edge_weight(1, 0), None);
+    assert_eq!(graph.edge_weight(5, 0), None);
+    assert_eq!(graph.edges(0).collect::<Vec<_>>(), [(1, &3), (2, &4)]);
+    assert_eq!(graph.edge_count(), 2);
+
+    let mut plain = Graph::new(2);
+    plain.add_edge(0, 1).unwrap();
+    assert_eq!(plain.edge_weight(0, 1), Some(&()));
+}
+
+/// Tests a long path graph
+/// 
+/// # Test Case
+/// - Input: A 100,000-vertex path with unit weights
+/// - Expected: Distance to the end equals the vertex count minus one
+#[test]
+fn test_long_path() {
+    let n = 100_000;
+    let mut graph: Graph<(), u64> = Graph::weighted(n);
+    for i in 0..n - 1 {
+        graph.add_weighted_edge(i, i + 1, 1).unwrap();
+    }
+    let paths = graph.dijkstra(0).unwrap();
+    assert_eq!(paths.distance(n - 1), Some(n as u64 - 1));
+    assert_eq!(paths.path_to(n - 1).unwrap().len(), n);
+}
@@ -0,0 +1,157 @@
+//! Integration tests for the auto-dispatching Smart Sort
+//! 
+//! TEST CATEGORIES:
+//! - Dispatch: Each input profile selects the expected algorithm
+//! - Correctness: Output is sorted regardless of the chosen path
+//! - Report: Measured runs, key range and stability are accurate
+//! 
+//! DEV NOTES:
+//! - Inputs are built from `generate_sorted_integers` and `shuffle_integers`
+//! - Dispatch assertions pin the documented decision order
+
+use dsa_in_rust::algorithms::sorting::smart_sort::{smart_sort, SortAlgorithm, SortKey};
+use dsa_in_rust::utils::helpers::{generate_sorted_integers, shuffle_integers};
+
+fn is_sorted<T: Ord>(arr: &[T]) -> bool {
+    arr.windows(2).all(|w| w[0] <= w[1])
+}
+
+/// Tests that sorted input is detected and left untouched
+/// 
+/// # Test Case
+/// - Input: 1,000 sorted integers
+/// - Expected: `SortAlgorithm::None` with a single run
+#[test]
+fn test_already_sorted() {
+    let mut arr = generate_sorted_integers(1_000);
+    let report = smart_sort(&mut arr);
+    assert_eq!(report.algorithm, SortAlgorithm::None);
+    assert_eq!(report.runs, 1);
+    assert_eq!(arr, generate_sorted_integers(1_000));
+}
+
+/// Tests that strictly descending input is reversed
+/// 
+/// # Test Case
+/// - Input: 1,000 integers in reverse order
+/// - Expected: `SortAlgorithm::Reverse`
+#[test]
+fn test_strictly_descending() {
+    let mut arr = generate_sorted_integers(1_000);
+    arr.reverse();
+    let report = smart_sort(&mut arr);
+    assert_eq!(report.algorithm, SortAlgorithm::Reverse);
+    assert_eq!(arr, generate_sorted_integers(1_000));
+}
+
+/// Tests that tiny inputs use insertion sort
+/// 
+/// # Test Case
+/// - Input: [3, 1, 2]
+/// - Expected: `SortAlgorithm::Insertion`
+#[test]
+fn test_tiny_input() {
+    let mut arr = [3, 1, 2];
+    let report = smart_sort(&mut arr);
+    assert_eq!(report.algorithm, SortAlgorithm::Insertion);
+    assert_eq!(arr, [1, 2, 3]);
+}
+
+/// Tests that nearly sorted input uses insertion sort
+/// 
+/// # Test Case
+/// - Input: 10,000 sorted integers with a few adjacent swaps
+/// - Expected: `SortAlgorithm::Insertion`
+#[test]
+fn test_nearly_sorted() {
+    let mut arr = generate_sorted_integers(10_000);
+    for i in (0..10_000).step_by(1_000) {
+        arr.swap(i, i + 1);
+    }
+    let report = smart_sort(&mut arr);
+    assert_eq!(report.algorithm, SortAlgorithm::Insertion);
+    assert_eq!(arr, generate_sorted_integers(10_000));
+}
+
+/// Tests that a rotated sequence exhausts the insertion budget
+/// 
+/// # Test Case
+/// - Input: Two sorted halves swapped (one descent, n²/4 inversions)
+/// - Expected: Falls through to a key-based sort and output is sorted
+#[test]
+fn test_insertion_budget_fallback() {
+    let mut arr = generate_sorted_integers(10_000);
+    arr.rotate_left(5_000);
+    let report = smart_sort(&mut arr);
+    assert_eq!(report.runs, 2);
+    assert_ne!(report.algorithm, SortAlgorithm::Insertion);
+    assert_eq!(arr, generate_sorted_integers(10_000));
+}
+
+/// Tests that a dense key range uses counting sort
+/// 
+/// # Test Case
+/// - Input: 10,000 shuffled integers (range 9,999)
+/// - Expected: `SortAlgorithm::Counting` with exact key range
+#[test]
+fn test_small_range_uses_counting() {
+    let mut arr = generate_sorted_integers(10_000);
+    shuffle_integers(&mut arr);
+    let report = smart_sort(&mut arr);
+    assert_eq!(report.algorithm, SortAlgorithm::Counting);
+    assert_eq!(report.key_range, Some(9_999));
+    assert!(report.stable);
+    assert_eq!(arr, generate_sorted_integers(10_000));
+}
+
+/// Tests that a sparse key range uses radix sort
+/// 
+/// # Test Case
+/// - Input: 10,000 shuffled multiples of 1,000,003
+/// - Expected: `SortAlgorithm::Radix`
+#[test]
+fn test_wide_range_uses_radix() {
+    let mut arr: Vec<i64> = generate_sorted_integers(10_000)
+        .into_iter()
+        .map(|x| x as i64 * 1_000_003)
+        .collect();
+    arr.reverse();
+    arr.swap(0, 1);
+    let report = smart_sort(&mut arr);
+    assert_eq!(report.algorithm, SortAlgorithm::Radix);
+    assert!(is_sorted(&arr));
+}
+
+/// Tests that comparison-only types use introsort
+/// 
+/// # Test Case
+/// - Input: 1,000 shuffled strings
+/// - Expected: `SortAlgorithm::Introsort`, no key range, unstable
+#[test]
+fn test_strings_use_introsort() {
+    let mut nums = generate_sorted_integers(1_000);
+    shuffle_integers(&mut nums);
+    let mut arr: Vec<String> = nums.iter().map(|n| format!("item-{:04}", n)).collect();
+    let report = smart_sort(&mut arr);
+    assert_eq!(report.algorithm, SortAlgorithm::Introsort);
+    assert_eq!(report.key_range, None);
+    assert!(!report.stable);
+    assert!(is_sorted(&arr));
+}
+
+/// Tests duplicate estimation and custom `SortKey` types
+/// 
+/// # Test Case
+/// - Input: 1,000 structs with only two distinct values
+/// - Expected: High duplicate ratio, sorted output
+#[test]
+fn test_custom_type_duplicates() {
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    struct Flag(bool, u8);
+    impl SortKey for Flag {}
+
+    let mut arr: Vec<Flag> = (0..1_000).map(|i| Flag(i % 3 == 0, 1)).collect();
+    let report = smart_sort(&mut arr);
+    assert!(report.duplicate_ratio > 0.9);
+    assert!(is_sorted(&arr));
+}
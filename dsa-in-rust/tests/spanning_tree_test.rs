@@ -0,0 +1,176 @@
+//! Integration tests for Minimum Spanning Trees
+//! 
+//! TEST CATEGORIES:
+//! - Correctness: Total weight against brute force over edge subsets
+//! - Consistency: Kruskal, Prim and the forest agree on connected graphs
+//! - Forests: Disconnected graphs, one tree per component
+//! - Error Handling: Directed graphs, disconnected graphs
+//! 
+//! DEV NOTES:
+//! - Different minimum trees can exist, so tests compare total weights and
+//!   check structure (acyclic, spanning) rather than exact edge sets
+
+mod common;
+
+use common::random_graph;
+use dsa_in_rust::algorithms::graph::spanning_tree::SpanningForest;
+use dsa_in_rust::data_structures::graph::{Graph, GraphError, GraphKind};
+use dsa_in_rust::data_structures::union_find::UnionFind;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+fn component_count(graph: &Graph<(), i64>) -> usize {
+    let mut sets = UnionFind::new(graph.node_count());
+    for (u, v, _) in graph.all_edges() {
+        sets.union(u, v);
+    }
+    sets.set_count()
+}
+
+/// Checks the forest uses real edges, has no cycle and spans every component
+fn assert_spanning(graph: &Graph<(), i64>, forest: &SpanningForest<i64>) {
+    let mut sets = UnionFind::new(graph.node_count());
+    for &(u, v, weight) in forest.edges() {
+        assert_eq!(graph.edge_weight(u, v), Some(&weight));
+        assert!(sets.union(u, v), "edge {}-{} closes a cycle", u, v);
+    }
+    assert_eq!(sets.set_count(), component_count(graph));
+    assert_eq!(forest.tree_count(), component_count(graph));
+    assert_eq!(forest.total_weight(), forest.edges().iter().map(|&(_, _, weight)| weight).sum::<i64>());
+}
+
+/// Minimum weight over all acyclic edge subsets of maximal size
+fn brute_force(graph: &Graph<(), i64>) -> i64 {
+    let edges: Vec<(usize, usize, i64)> = graph.all_edges().map(|(u, v, &w)| (u, v, w)).collect();
+    let target = graph.node_count() - component_count(graph);
+    (0..1u32 << edges.len())
+        .filter(|mask| mask.count_ones() as usize == target)
+        .filter_map(|mask| {
+            let mut sets = UnionFind::new(graph.node_count());
+            let chosen = edges.iter().enumerate().filter(|&(i, _)| mask >> i & 1 == 1);
+            let mut total = 0;
+            for (_, &(u, v, weight)) in chosen {
+                if !sets.union(u, v) {
+                    return None;
+                }
+                total += weight;
+            }
+            Some(total)
+        })
+        .min()
+        .unwrap()
+}
+
+/// Tests against brute force
+/// 
+/// # Test Case
+/// - Input: 200 random small graphs (up to 7 vertices, 12 edges)
+/// - Expected: Forest weight equals the brute-force minimum
+#[test]
+fn test_brute_force() {
+    let mut rng = StdRng::seed_from_u64(470);
+    for _ in 0..200 {
+        let nodes = rng.gen_range(1..8);
+        let graph = random_graph(&mut rng, nodes, 12, GraphKind::Undirected, |rng| rng.gen_range(-5..=20));
+        let forest = graph.minimum_spanning_forest().unwrap();
+        assert_spanning(&graph, &forest);
+        assert_eq!(forest.total_weight(), brute_force(&graph));
+    }
+}
+
+/// Tests Kruskal and Prim on connected graphs
+/// 
+/// # Test Case
+/// - Input: 200 random graphs made connected by a random spanning path
+/// - Expected: Both return spanning trees of equal total weight
+#[test]
+fn test_kruskal_prim_agree() {
+    let mut rng = StdRng::seed_from_u64(471);
+    for _ in 0..200 {
+        let nodes = rng.gen_range(1..60);
+        let edges = rng.gen_range(0..nodes * 3);
+        let mut graph = random_graph(&mut rng, nodes, edges, GraphKind::Undirected, |rng| rng.gen_range(-5..=20));
+        for node in 1..nodes {
+            let weight = rng.gen_range(0..30);
+            let _ = graph.add_weighted_edge(node - 1, node, weight);
+        }
+        let (kruskal, prim) = (graph.kruskal().unwrap(), graph.prim().unwrap());
+        assert_spanning(&graph, &kruskal);
+        assert_spanning(&graph, &prim);
+        assert!(kruskal.is_tree() && prim.is_tree());
+        assert_eq!(kruskal.edges().len(), nodes - 1);
+        assert_eq!(kruskal.total_weight(), prim.total_weight());
+    }
+}
+
+/// Tests disconnected graphs
+/// 
+/// # Test Case
+/// - Input: Components {0, 1, 2}, {3, 4}, {5}
+/// - Expected: A forest of 3 trees; Kruskal and Prim report the first missing vertex
+#[test]
+fn test_disconnected() {
+    let mut graph: Graph<(), i64> = Graph::with_kind(6, GraphKind::Undirected);
+    graph.add_weighted_edge(0, 1, 2).unwrap();
+    graph.add_weighted_edge(1, 2, 2).unwrap();
+    graph.add_weighted_edge(2, 0, 1).unwrap();
+    graph.add_weighted_edge(3, 4, 9).unwrap();
+    let forest = graph.minimum_spanning_forest().unwrap();
+    assert_spanning(&graph, &forest);
+    assert_eq!(forest.tree_count(), 3);
+    assert_eq!(forest.total_weight(), 12);
+    assert!(!forest.is_tree());
+    assert_eq!(graph.kruskal(), Err(GraphError::NoPath(0, 3)));
+    assert_eq!(graph.prim(), Err(GraphError::NoPath(0, 3)));
+}
+
+/// Tests edge cases
+/// 
+/// # Test Case
+/// - Input: Empty graph, single vertex with a self-loop, directed graph
+/// - Expected: Empty trees; Err(DirectedGraph) for the directed graph
+#[test]
+fn test_edge_cases() {
+    let empty: Graph<(), i64> = Graph::with_kind(0, GraphKind::Undirected);
+    assert_eq!(empty.kruskal().unwrap().edges(), []);
+    assert_eq!(empty.prim().unwrap().tree_count(), 0);
+
+    let mut looped: Graph<(), i64> = Graph::with_kind(1, GraphKind::Undirected);
+    looped.add_weighted_edge(0, 0, -3).unwrap();
+    assert_eq!(looped.prim().unwrap().total_weight(), 0);
+    assert_eq!(looped.kruskal().unwrap().edges(), []);
+
+    let mut directed: Graph<(), i64> = Graph::weighted(2);
+    directed.add_weighted_edge(0, 1, 1).unwrap();
+    assert_eq!(directed.kruskal(), Err(GraphError::DirectedGraph));
+    assert_eq!(directed.prim(), Err(GraphError::DirectedGraph));
+    assert_eq!(GraphError::DirectedGraph.to_string(), "Operation requires an undirected graph");
+}
+
+/// Tests a large graph
+/// 
+/// # Test Case
+/// - Input: A 300 x 300 grid with random weights
+/// - Expected: Kruskal and Prim agree on the total weight with V - 1 edges
+#[test]
+fn test_large_grid() {
+    let mut rng = StdRng::seed_from_u64(472);
+    let side = 300;
+    let mut graph: Graph<(), i64> = Graph::with_kind(side * side, GraphKind::Undirected);
+    for y in 0..side {
+        for x in 0..side {
+            let node = y * side + x;
+            if x + 1 < side {
+                let weight = rng.gen_range(1..1000);
+                graph.add_weighted_edge(node, node + 1, weight).unwrap();
+            }
+            if y + 1 < side {
+                let weight = rng.gen_range(1..1000);
+                graph.add_weighted_edge(node, node + side, weight).unwrap();
+            }
+        }
+    }
+    let (kruskal, prim) = (graph.kruskal().unwrap(), graph.prim().unwrap());
+    assert_eq!(kruskal.edges().len(), side * side - 1);
+    assert_eq!(kruskal.total_weight(), prim.total_weight());
+}
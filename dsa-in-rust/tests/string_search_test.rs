@@ -0,0 +1,160 @@
+//! Integration tests for String Pattern Matching
+//! 
+//! TEST CATEGORIES:
+//! - Agreement: KMP, Horspool and Rabin–Karp agree with a naive scan
+//! - Overlapping: Overlapping and non-overlapping match iteration
+//! - Element Types: `&str`, `&[u8]` and generic `&[T: Eq]` haystacks
+//! - Edge Cases: Empty patterns, patterns longer than the haystack
+//! - Multi-pattern: Rabin–Karp over several equal-length patterns
+//! 
+//! DEV NOTES:
+//! - Matchers are exercised through the shared `Matcher` trait where possible
+
+use dsa_in_rust::algorithms::searching::string::horspool::Horspool;
+use dsa_in_rust::algorithms::searching::string::kmp::{prefix_function, Kmp};
+use dsa_in_rust::algorithms::searching::string::matcher::{Matcher, PatternError};
+use dsa_in_rust::algorithms::searching::string::rabin_karp::RabinKarp;
+
+/// Naive overlapping search used as the reference
+fn naive<T: Eq>(haystack: &[T], pattern: &[T]) -> Vec<usize> {
+    if pattern.is_empty() {
+        return (0..=haystack.len()).collect();
+    }
+    haystack
+        .windows(pattern.len())
+        .enumerate()
+        .filter(|(_, w)| *w == pattern)
+        .map(|(i, _)| i)
+        .collect()
+}
+
+fn overlapping<M: Matcher>(matcher: &M, haystack: &[M::Item]) -> Vec<usize> {
+    matcher.find_overlapping_iter(haystack).collect()
+}
+
+/// Tests all matchers against a naive scan on many pattern/text pairs
+/// 
+/// # Test Case
+/// - Input: Every substring of length 1..=4 of a repetitive text as a pattern
+/// - Expected: Identical overlapping match positions
+#[test]
+fn test_agree_with_naive() {
+    let text = b"abaababaabaababaababa";
+    for len in 1..=4 {
+        for start in 0..=text.len() - len {
+            let pattern = &text[start..start + len];
+            let expected = naive(text, pattern);
+            assert_eq!(overlapping(&Kmp::new(pattern), text), expected);
+            assert_eq!(overlapping(&Horspool::new(pattern), text), expected);
+            assert_eq!(overlapping(&RabinKarp::new(pattern), text), expected);
+        }
+    }
+}
+
+/// Tests overlapping versus non-overlapping iteration
+/// 
+/// # Test Case
+/// - Input: Pattern "aba" in "abababa"
+/// - Expected: [0, 2, 4] overlapping, [0, 4] non-overlapping
+#[test]
+fn test_overlapping_and_non_overlapping() {
+    let text = b"abababa";
+    let kmp = Kmp::new(b"aba");
+    assert_eq!(kmp.find_overlapping_iter(text).collect::<Vec<_>>(), [0, 2, 4]);
+    assert_eq!(kmp.find_iter(text).collect::<Vec<_>>(), [0, 4]);
+    let horspool = Horspool::new(b"aba");
+    assert_eq!(horspool.find_iter(text).collect::<Vec<_>>(), [0, 4]);
+}
+
+/// Tests searching UTF-8 text through its bytes
+/// 
+/// # Test Case
+/// - Input: Multi-byte text and pattern
+/// - Expected: Byte offsets matching `str::match_indices`
+#[test]
+fn test_utf8_text() {
+    let text = "naïve café, naïve thé";
+    let pattern = "naïve";
+    let expected: Vec<usize> = text.match_indices(pattern).map(|(i, _)| i).collect();
+    let kmp = Kmp::new(pattern.as_bytes());
+    let found: Vec<usize> = kmp.find_iter(text.as_bytes()).collect();
+    assert_eq!(found, expected);
+    assert!(found.iter().all(|&i| text.is_char_boundary(i)));
+}
+
+/// Tests generic element types
+/// 
+/// # Test Case
+/// - Input: Slices of integers and of strings
+/// - Expected: Matches found by element equality
+#[test]
+fn test_generic_elements() {
+    let readings = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 9, 2];
+    assert_eq!(Kmp::new(&[5, 9, 2]).find_iter(&readings).collect::<Vec<_>>(), [4, 10]);
+    assert_eq!(Horspool::new(&[5, 9, 2]).find(&readings), Some(4));
+
+    let tokens = ["GET", "/", "HTTP", "GET", "/api", "HTTP"];
+    let matcher = RabinKarp::new(&["GET", "/api"]);
+    assert_eq!(matcher.find(&tokens), Some(3));
+}
+
+/// Tests edge cases around pattern length
+/// 
+/// # Test Case
+/// - Input: Empty pattern; pattern longer than text; empty text
+/// - Expected: Empty pattern matches everywhere; others find nothing
+#[test]
+fn test_pattern_length_edges() {
+    let empty: &[u8] = b"";
+    assert_eq!(Kmp::new(empty).find_iter(b"abc").collect::<Vec<_>>(), [0, 1, 2, 3]);
+    assert_eq!(Horspool::new(empty).find_overlapping_iter(b"ab").count(), 3);
+    assert_eq!(RabinKarp::new(empty).find(b""), Some(0));
+
+    assert_eq!(Kmp::new(b"abcd").find(b"abc"), None);
+    assert_eq!(Horspool::new(b"abcd").find(b"abc"), None);
+    assert_eq!(RabinKarp::new(b"abcd").find(b"abc"), None);
+    assert_eq!(Kmp::new(b"a").find(b""), None);
+}
+
+/// Tests the exposed prefix function
+/// 
+/// # Test Case
+/// - Input: Classic examples
+/// - Expected: Textbook failure tables
+#[test]
+fn test_prefix_function() {
+    assert_eq!(prefix_function(b"aabaaab"), [0, 1, 0, 1, 2, 2, 3]);
+    assert_eq!(prefix_function(b"abcd"), [0, 0, 0, 0]);
+    assert_eq!(prefix_function::<u8>(b""), Vec::<usize>::new());
+    assert_eq!(Kmp::new(b"aaaa").prefix(), [0, 1, 2, 3]);
+}
+
+/// Tests Rabin–Karp with several patterns
+/// 
+/// # Test Case
+/// - Input: Log text and three four-letter keywords
+/// - Expected: Every occurrence with the index of the matching keyword
+#[test]
+fn test_rabin_karp_multi_pattern() {
+    let text = b"WARN disk; INFO boot; WARN net; FAIL db";
+    let patterns: [&[u8]; 3] = [b"WARN", b"FAIL", b"INFO"];
+    let matcher = RabinKarp::with_patterns(&patterns).unwrap();
+    let hits: Vec<(usize, usize)> = matcher.find_patterns_iter(text).collect();
+    assert_eq!(hits, [(0, 0), (11, 2), (22, 0), (32, 1)]);
+}
+
+/// Tests Rabin–Karp construction errors
+/// 
+/// # Test Case
+/// - Input: No patterns; patterns of different lengths
+/// - Expected: NoPatterns and UnequalLengths errors
+#[test]
+fn test_rabin_karp_errors() {
+    let none: [&[u8]; 0] = [];
+    assert_eq!(RabinKarp::with_patterns(&none).unwrap_err(), PatternError::NoPatterns);
+    let mixed: [&[u8]; 2] = [b"abc", b"de"];
+    assert_eq!(
+        RabinKarp::with_patterns(&mixed).unwrap_err(),
+        PatternError::UnequalLengths { expected: 3, found: 2 }
+    );
+}
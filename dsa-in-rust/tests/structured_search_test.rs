@@ -0,0 +1,193 @@
+//! Integration tests for Searches over Structured Inputs
+//! 
+//! TEST CATEGORIES:
+//! - Rotated Arrays: Every rotation, with and without duplicates
+//! - Sorted Matrices: Staircase search and k-th smallest
+//! - Peaks: 1D and 2D peaks in shuffled data
+//! - Edge Cases: Empty and single-element inputs
+//! 
+//! DEV NOTES:
+//! - Inputs are built from `generate_sorted_integers` and `shuffle_integers`
+//! - Peaks are verified by the neighbour property, not a fixed index
+
+use dsa_in_rust::algorithms::searching::structured_search::{
+    find_peak, find_peak_2d, kth_smallest, rotated_min, rotated_search, staircase_search,
+};
+use dsa_in_rust::utils::helpers::{generate_sorted_integers, shuffle_integers};
+
+/// Builds a rows x cols matrix with sorted rows and columns from shuffled data
+fn young_tableau(rows: usize, cols: usize, duplicates: bool) -> Vec<Vec<i32>> {
+    let mut values = generate_sorted_integers(rows * cols);
+    if duplicates {
+        values.iter_mut().for_each(|x| *x /= 4);
+    }
+    shuffle_integers(&mut values);
+    let mut matrix: Vec<Vec<i32>> = values.chunks(cols).map(|row| row.to_vec()).collect();
+    // Sorting rows and then columns keeps the rows sorted
+    matrix.iter_mut().for_each(|row| row.sort());
+    for col in 0..cols {
+        let mut column: Vec<i32> = matrix.iter().map(|row| row[col]).collect();
+        column.sort();
+        matrix.iter_mut().zip(column).for_each(|(row, x)| row[col] = x);
+    }
+    matrix
+}
+
+/// Expected rotation point: the index after the only descent, or 0
+fn descent(arr: &[i32]) -> usize {
+    (1..arr.len()).find(|&i| arr[i - 1] > arr[i]).unwrap_or(0)
+}
+
+/// Tests search and minimum over every rotation of distinct values
+/// 
+/// # Test Case
+/// - Input: 1..=37 rotated by every offset
+/// - Expected: Every value found at its index; misses reported; minimum at the offset
+#[test]
+fn test_rotated_distinct() {
+    let sorted = generate_sorted_integers(37);
+    for offset in 0..sorted.len() {
+        let mut arr = sorted.clone();
+        arr.rotate_left(offset);
+        for (i, x) in arr.iter().enumerate() {
+            assert_eq!(rotated_search(&arr, x), Some(i));
+        }
+        assert_eq!(rotated_search(&arr, &0), None);
+        assert_eq!(rotated_search(&arr, &38), None);
+        assert_eq!(rotated_min(&arr), Some((sorted.len() - offset) % sorted.len()));
+    }
+}
+
+/// Tests search and minimum with duplicates
+/// 
+/// # Test Case
+/// - Input: Values with runs of equal elements, rotated by every offset
+/// - Expected: Found indices hold the target; minimum is the rotation point
+#[test]
+fn test_rotated_duplicates() {
+    let sorted: Vec<i32> = generate_sorted_integers(40).into_iter().map(|x| x / 7).collect();
+    let mut patterns = vec![sorted, vec![2; 9], vec![1, 1, 1, 1, 2, 1, 1]];
+    patterns.push(vec![3, 3, 1, 3]);
+    for base in patterns {
+        for offset in 0..base.len() {
+            let mut arr = base.clone();
+            arr.rotate_left(offset);
+            for target in -1..=8 {
+                match rotated_search(&arr, &target) {
+                    Some(i) => assert_eq!(arr[i], target),
+                    None => assert!(!arr.contains(&target), "{:?} missed {}", arr, target),
+                }
+            }
+            assert_eq!(rotated_min(&arr), Some(descent(&arr)), "{:?}", arr);
+        }
+    }
+}
+
+/// Tests staircase search on sorted matrices
+/// 
+/// # Test Case
+/// - Input: Row-major 1..=rows*cols and shuffled tableaux of several shapes
+/// - Expected: Every value found at a matching cell; absent values not found
+#[test]
+fn test_staircase_search() {
+    let row_major: Vec<Vec<i32>> = generate_sorted_integers(48).chunks(8).map(|r| r.to_vec()).collect();
+    assert_eq!(staircase_search(&row_major, &27), Some((3, 2)));
+    assert_eq!(staircase_search(&row_major, &49), None);
+
+    for (rows, cols) in [(1, 1), (1, 9), (9, 1), (7, 5), (12, 12)] {
+        for duplicates in [false, true] {
+            let matrix = young_tableau(rows, cols, duplicates);
+            for target in -1..=(rows * cols) as i32 + 1 {
+                match staircase_search(&matrix, &target) {
+                    Some((r, c)) => assert_eq!(matrix[r][c], target),
+                    None => assert!(matrix.iter().all(|row| !row.contains(&target))),
+                }
+            }
+        }
+    }
+}
+
+/// Tests k-th smallest against a full sort
+/// 
+/// # Test Case
+/// - Input: Shuffled tableaux with and without duplicates
+/// - Expected: Rank k matches the sorted flattening; out-of-range k is None
+#[test]
+fn test_kth_smallest() {
+    for (rows, cols) in [(1, 1), (1, 9), (9, 1), (6, 11), (10, 10)] {
+        for duplicates in [false, true] {
+            let matrix = young_tableau(rows, cols, duplicates);
+            let mut flat: Vec<i32> = matrix.concat();
+            flat.sort();
+            for (k, expected) in flat.iter().enumerate() {
+                assert_eq!(kth_smallest(&matrix, k), Some(expected));
+            }
+            assert_eq!(kth_smallest(&matrix, flat.len()), None);
+        }
+    }
+}
+
+/// Tests 1D peak finding on shuffled and monotone data
+/// 
+/// # Test Case
+/// - Input: Shuffled 1..=n for several n; sorted ascending and descending
+/// - Expected: Returned index is not smaller than its neighbours
+#[test]
+fn test_find_peak() {
+    for n in [1, 2, 3, 10, 1000] {
+        let mut arr = generate_sorted_integers(n);
+        shuffle_integers(&mut arr);
+        let i = find_peak(&arr).unwrap();
+        assert!(i == 0 || arr[i] >= arr[i - 1]);
+        assert!(i + 1 == n || arr[i] >= arr[i + 1]);
+    }
+    let ascending = generate_sorted_integers(100);
+    assert_eq!(find_peak(&ascending), Some(99));
+    let descending: Vec<i32> = ascending.iter().rev().copied().collect();
+    assert_eq!(find_peak(&descending), Some(0));
+    assert_eq!(find_peak(&[5, 5, 5]).map(|i| i < 3), Some(true));
+}
+
+/// Tests 2D peak finding on shuffled grids
+/// 
+/// # Test Case
+/// - Input: Shuffled 1..=rows*cols laid out in several shapes
+/// - Expected: Returned cell is not smaller than any of its four neighbours
+#[test]
+fn test_find_peak_2d() {
+    for (rows, cols) in [(1, 1), (1, 20), (20, 1), (15, 17), (50, 50)] {
+        for _ in 0..10 {
+            let mut values = generate_sorted_integers(rows * cols);
+            shuffle_integers(&mut values);
+            let grid: Vec<&[i32]> = values.chunks(cols).collect();
+            let (r, c) = find_peak_2d(&grid).unwrap();
+            let value = grid[r][c];
+            let neighbours = [(r.wrapping_sub(1), c), (r + 1, c), (r, c.wrapping_sub(1)), (r, c + 1)];
+            for (nr, nc) in neighbours {
+                if let Some(&other) = grid.get(nr).and_then(|row| row.get(nc)) {
+                    assert!(value >= other, "({}, {}) is not a peak", r, c);
+                }
+            }
+        }
+    }
+}
+
+/// Tests empty inputs
+/// 
+/// # Test Case
+/// - Input: Empty slices, empty matrices and matrices of empty rows
+/// - Expected: None everywhere
+#[test]
+fn test_empty_inputs() {
+    let empty: Vec<i32> = Vec::new();
+    let no_rows: Vec<Vec<i32>> = Vec::new();
+    let empty_rows: Vec<Vec<i32>> = vec![vec![], vec![]];
+    assert_eq!(rotated_search(&empty, &1), None);
+    assert_eq!(rotated_min(&empty), None);
+    assert_eq!(find_peak(&empty), None);
+    for matrix in [&no_rows, &empty_rows] {
+        assert_eq!(staircase_search(matrix, &1), None);
+        assert_eq!(kth_smallest(matrix, 0), None);
+        assert_eq!(find_peak_2d(matrix), None);
+    }
+}
@@ -0,0 +1,210 @@
+//! Integration tests for the Suffix Array
+//! 
+//! TEST CATEGORIES:
+//! - Construction: Suffix order and LCP values against naive sorting
+//! - Queries: Counting and locating patterns against a naive scan
+//! - Repeats: Longest repeated and longest common substrings
+//! - Element Types: Bytes, chars and arbitrary `Ord` values
+//! - Edge Cases: Empty texts, single elements, runs of one element
+//! 
+//! DEV NOTES:
+//! - Random texts use small alphabets so repeats and shared prefixes are common
+
+use dsa_in_rust::algorithms::searching::string::suffix_array::{longest_common_substring, SuffixArray};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+fn random_text(rng: &mut StdRng, len: usize, alphabet: u8) -> Vec<u8> {
+    (0..len).map(|_| b'a' + rng.gen_range(0..alphabet)).collect()
+}
+
+fn naive_suffixes<T: Ord>(text: &[T]) -> Vec<usize> {
+    let mut suffixes: Vec<usize> = (0..text.len()).collect();
+    suffixes.sort_by(|&a, &b| text[a..].cmp(&text[b..]));
+    suffixes
+}
+
+fn common_prefix<T: Eq>(a: &[T], b: &[T]) -> usize {
+    a.iter().zip(b).take_while(|(x, y)| x == y).count()
+}
+
+fn naive_locate<T: Eq>(text: &[T], pattern: &[T]) -> Vec<usize> {
+    (0..=text.len().saturating_sub(pattern.len()))
+        .filter(|&i| text.len() >= pattern.len() && text[i..i + pattern.len()] == *pattern)
+        .collect()
+}
+
+/// Longest length shared by some substring of `a` and some substring of `b`
+fn naive_common_length(a: &[u8], b: &[u8]) -> usize {
+    let mut best = 0;
+    for i in 0..a.len() {
+        for j in 0..b.len() {
+            best = best.max(common_prefix(&a[i..], &b[j..]));
+        }
+    }
+    best
+}
+
+/// Tests construction against naive suffix sorting
+/// 
+/// # Test Case
+/// - Input: Random texts of length 0..200 over alphabets of 1 to 4 letters
+/// - Expected: Same suffix order; LCP values equal to direct comparison
+#[test]
+fn test_construction() {
+    let mut rng = StdRng::seed_from_u64(40);
+    for _ in 0..300 {
+        let len = rng.gen_range(0..200);
+        let alphabet = rng.gen_range(1..=4);
+        let text = random_text(&mut rng, len, alphabet);
+        let sa = SuffixArray::new(&text);
+        let expected = naive_suffixes(&text);
+        assert_eq!(sa.suffixes(), expected, "text {:?}", String::from_utf8_lossy(&text));
+        for i in 1..expected.len() {
+            assert_eq!(sa.lcp()[i], common_prefix(&text[expected[i - 1]..], &text[expected[i]..]));
+        }
+        assert!(sa.lcp().first().is_none_or(|&lcp| lcp == 0));
+    }
+}
+
+/// Tests the worst case for prefix doubling
+/// 
+/// # Test Case
+/// - Input: 100,000 copies of one letter
+/// - Expected: Suffixes from shortest to longest; LCP counts up by one
+#[test]
+fn test_single_letter_run() {
+    let n = 100_000;
+    let sa = SuffixArray::new(&vec![b'a'; n]);
+    assert!(sa.suffixes().iter().copied().eq((0..n).rev()));
+    assert!(sa.lcp().iter().copied().eq(0..n));
+    assert_eq!(sa.longest_repeated_substring().map(|range| range.len()), Some(n - 1));
+}
+
+/// Tests counting and locating
+/// 
+/// # Test Case
+/// - Input: Every substring up to length 5 of random texts, plus absent patterns
+/// - Expected: Same positions as a naive scan
+#[test]
+fn test_count_and_locate() {
+    let mut rng = StdRng::seed_from_u64(7);
+    for _ in 0..50 {
+        let text = random_text(&mut rng, 120, 3);
+        let sa = SuffixArray::new(&text);
+        for start in 0..text.len() {
+            for len in 1..=5.min(text.len() - start) {
+                let pattern = &text[start..start + len];
+                let expected = naive_locate(&text, pattern);
+                assert_eq!(sa.locate(pattern), expected);
+                assert_eq!(sa.count(pattern), expected.len());
+            }
+        }
+        assert_eq!(sa.count(b"d"), 0);
+        assert!(!sa.contains(b"abcabcabcabcabc"));
+        assert!(sa.range(b"zz").is_empty());
+    }
+}
+
+/// Tests the empty pattern and the empty text
+/// 
+/// # Test Case
+/// - Input: Empty patterns; an empty text
+/// - Expected: The empty pattern occurs at every position including the end
+#[test]
+fn test_empty() {
+    let sa = SuffixArray::new(b"abc");
+    assert_eq!(sa.count(b""), 4);
+    assert_eq!(sa.locate(b""), [0, 1, 2, 3]);
+
+    let empty = SuffixArray::<u8>::new(&[]);
+    assert!(empty.is_empty());
+    assert!(empty.suffixes().is_empty());
+    assert_eq!(empty.count(b"a"), 0);
+    assert_eq!(empty.locate(b""), [0]);
+    assert_eq!(empty.longest_repeated_substring(), None);
+    assert_eq!(longest_common_substring::<u8>(&[], b"abc"), None);
+}
+
+/// Tests the longest repeated substring
+/// 
+/// # Test Case
+/// - Input: Known examples and random texts
+/// - Expected: A substring occurring twice, with the naive maximum length
+#[test]
+fn test_longest_repeated_substring() {
+    let text = b"to be or not to be";
+    let sa = SuffixArray::new(text);
+    assert_eq!(&text[sa.longest_repeated_substring().unwrap()], b"to be");
+    assert_eq!(SuffixArray::new(b"abcd").longest_repeated_substring(), None);
+    // Overlapping occurrences count
+    let sa = SuffixArray::new(b"aaaa");
+    assert_eq!(sa.longest_repeated_substring().map(|range| range.len()), Some(3));
+
+    let mut rng = StdRng::seed_from_u64(11);
+    for _ in 0..100 {
+        let len = rng.gen_range(1..80);
+        let text = random_text(&mut rng, len, 3);
+        let sa = SuffixArray::new(&text);
+        let expected = (0..len)
+            .flat_map(|i| (i + 1..len).map(move |j| (i, j)))
+            .map(|(i, j)| common_prefix(&text[i..], &text[j..]))
+            .max()
+            .unwrap_or(0);
+        match sa.longest_repeated_substring() {
+            Some(range) => {
+                assert_eq!(range.len(), expected);
+                assert!(sa.count(&text[range]) >= 2);
+            }
+            None => assert_eq!(expected, 0),
+        }
+    }
+}
+
+/// Tests the longest common substring of two sequences
+/// 
+/// # Test Case
+/// - Input: Known pairs and random pairs of texts
+/// - Expected: Reported runs really match and have the naive maximum length
+#[test]
+fn test_longest_common_substring() {
+    let (a, b) = (b"the quick brown fox", b"a quick brown dog");
+    let found = longest_common_substring(a, b).unwrap();
+    assert_eq!(&a[found.first..found.first + found.len], b" quick brown ");
+    assert_eq!(found.second, 1);
+
+    let mut rng = StdRng::seed_from_u64(12);
+    for _ in 0..200 {
+        let (n, m) = (rng.gen_range(0..40), rng.gen_range(0..40));
+        let a = random_text(&mut rng, n, 3);
+        let b = random_text(&mut rng, m, 3);
+        let expected = naive_common_length(&a, &b);
+        match longest_common_substring(&a, &b) {
+            Some(found) => {
+                assert_eq!(found.len, expected);
+                assert_eq!(a[found.first..found.first + found.len], b[found.second..found.second + found.len]);
+            }
+            None => assert_eq!(expected, 0),
+        }
+    }
+}
+
+/// Tests non-byte element types
+/// 
+/// # Test Case
+/// - Input: A char sequence and a sequence of words
+/// - Expected: Queries work over whole elements
+#[test]
+fn test_element_types() {
+    let chars: Vec<char> = "αβγαβ".chars().collect();
+    let sa = SuffixArray::new(&chars);
+    assert_eq!(sa.locate(&['α', 'β']), [0, 3]);
+    assert_eq!(chars[sa.longest_repeated_substring().unwrap()], ['α', 'β']);
+
+    let words: Vec<&str> = "the cat saw the cat sit".split(' ').collect();
+    let sa = SuffixArray::new(&words);
+    assert_eq!(sa.locate(&["the", "cat"]), [0, 3]);
+    assert_eq!(words[sa.longest_repeated_substring().unwrap()], ["the", "cat"]);
+    let found = longest_common_substring(&words, &["a", "cat", "sit"]).unwrap();
+    assert_eq!((found.first, found.second, found.len), (4, 1, 2));
+}
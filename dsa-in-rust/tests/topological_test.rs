@@ -0,0 +1,200 @@
+//! Integration tests for Topological Sorting
+//! 
+//! TEST CATEGORIES:
+//! - Validity: Every edge points forward in Kahn, DFS and lexicographic orders
+//! - Lexicographic: Smallest order against brute force over permutations
+//! - Cycles: Reported cycles are real, simple and start at their smallest vertex
+//! - Scale: Long dependency chains (stack safety)
+//! 
+//! DEV NOTES:
+//! - Random DAGs are built from a hidden random order, so no cycle is possible
+
+use dsa_in_rust::data_structures::graph::{Graph, GraphError};
+use dsa_in_rust::shuffle_integers;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashSet;
+
+fn random_dag(rng: &mut StdRng, nodes: usize, edges: usize) -> Graph {
+    let mut hidden: Vec<i32> = (0..nodes as i32).collect();
+    shuffle_integers(&mut hidden);
+    let mut graph = Graph::new(nodes);
+    for _ in 0..edges {
+        let (a, b) = (rng.gen_range(0..nodes), rng.gen_range(0..nodes));
+        if a < b {
+            let _ = graph.add_edge(hidden[a] as usize, hidden[b] as usize);
+        }
+    }
+    graph
+}
+
+fn edge_list(graph: &Graph) -> Vec<(usize, usize)> {
+    (0..graph.node_count())
+        .flat_map(|u| (0..graph.node_count()).filter(move |&v| graph.has_edge(u, v)).map(move |v| (u, v)))
+        .collect()
+}
+
+fn assert_topological(graph: &Graph, order: &[usize]) {
+    let mut position = vec![usize::MAX; graph.node_count()];
+    for (index, &node) in order.iter().enumerate() {
+        assert_eq!(position[node], usize::MAX, "vertex {} repeated", node);
+        position[node] = index;
+    }
+    assert_eq!(order.len(), graph.node_count());
+    for (u, v) in edge_list(graph) {
+        assert!(position[u] < position[v], "edge {} -> {} points backwards", u, v);
+    }
+}
+
+fn assert_cycle(graph: &Graph, cycle: &[usize]) {
+    assert!(!cycle.is_empty());
+    assert_eq!(cycle.iter().collect::<HashSet<_>>().len(), cycle.len());
+    assert_eq!(cycle[0], *cycle.iter().min().unwrap());
+    for i in 0..cycle.len() {
+        assert!(graph.has_edge(cycle[i], cycle[(i + 1) % cycle.len()]), "cycle {:?} uses a missing edge", cycle);
+    }
+}
+
+/// Tests all variants on random DAGs
+/// 
+/// # Test Case
+/// - Input: 300 random DAGs with up to 40 vertices
+/// - Expected: Valid topological orders from all three variants
+#[test]
+fn test_random_dags() {
+    let mut rng = StdRng::seed_from_u64(45);
+    for _ in 0..300 {
+        let nodes = rng.gen_range(0..40);
+        let graph = random_dag(&mut rng, nodes.max(1), nodes * 3);
+        assert_topological(&graph, &graph.topological_sort().unwrap());
+        assert_topological(&graph, &graph.topological_sort_dfs().unwrap());
+        assert_topological(&graph, &graph.topological_sort_lexicographic().unwrap());
+    }
+}
+
+/// Tests the lexicographically smallest order
+/// 
+/// # Test Case
+/// - Input: Random DAGs with 6 vertices
+/// - Expected: The smallest of all valid permutations
+#[test]
+fn test_lexicographic_brute_force() {
+    fn permutations(items: &mut Vec<usize>, k: usize, out: &mut Vec<Vec<usize>>) {
+        if k == items.len() {
+            out.push(items.clone());
+            return;
+        }
+        for i in k..items.len() {
+            items.swap(k, i);
+            permutations(items, k + 1, out);
+            items.swap(k, i);
+        }
+    }
+    let mut all = Vec::new();
+    permutations(&mut (0..6).collect(), 0, &mut all);
+    all.sort();
+
+    let mut rng = StdRng::seed_from_u64(6);
+    for _ in 0..100 {
+        let graph = random_dag(&mut rng, 6, 8);
+        let edges = edge_list(&graph);
+        let expected = all
+            .iter()
+            .find(|order| {
+                let position = |node| order.iter().position(|&o| o == node).unwrap();
+                edges.iter().all(|&(u, v)| position(u) < position(v))
+            })
+            .unwrap();
+        assert_eq!(&graph.topological_sort_lexicographic().unwrap(), expected);
+    }
+}
+
+/// Tests cycle reporting
+/// 
+/// # Test Case
+/// - Input: Random graphs that usually contain cycles
+/// - Expected: Err(Cycle) from all variants exactly when a cycle exists, with a valid cycle
+#[test]
+fn test_cycles() {
+    let mut rng = StdRng::seed_from_u64(8);
+    for _ in 0..300 {
+        let nodes = rng.gen_range(1..15);
+        let mut graph = Graph::new(nodes);
+        for _ in 0..nodes + 2 {
+            let _ = graph.add_edge(rng.gen_range(0..nodes), rng.gen_range(0..nodes));
+        }
+        let results = [graph.topological_sort(), graph.topological_sort_dfs(), graph.topological_sort_lexicographic()];
+        let acyclic = results[1].is_ok();
+        for result in results {
+            match result {
+                Ok(order) => {
+                    assert!(acyclic);
+                    assert_topological(&graph, &order);
+                }
+                Err(GraphError::Cycle(cycle)) => {
+                    assert!(!acyclic);
+                    assert_cycle(&graph, &cycle);
+                }
+                Err(other) => panic!("unexpected error {:?}", other),
+            }
+        }
+    }
+}
+
+/// Tests the triangle from the graph tests and a self-loop
+/// 
+/// # Test Case
+/// - Input: 0 -> 1 -> 2 -> 0; a single vertex with a self-loop
+/// - Expected: Cycle [0, 1, 2] and Cycle [v]
+#[test]
+fn test_known_cycles() {
+    let mut graph = Graph::new(3);
+    graph.add_edge(0, 1).unwrap();
+    graph.add_edge(1, 2).unwrap();
+    graph.add_edge(2, 0).unwrap();
+    assert_eq!(graph.topological_sort(), Err(GraphError::Cycle(vec![0, 1, 2])));
+    assert_eq!(graph.topological_sort_dfs(), Err(GraphError::Cycle(vec![0, 1, 2])));
+    assert_eq!(GraphError::Cycle(vec![0, 1, 2]).to_string(), "Cycle detected: 0 -> 1 -> 2 -> 0");
+
+    let mut looped = Graph::new(3);
+    looped.add_edge(0, 1).unwrap();
+    looped.add_edge(1, 1).unwrap();
+    assert_eq!(looped.topological_sort_lexicographic(), Err(GraphError::Cycle(vec![1])));
+}
+
+/// Tests edge cases
+/// 
+/// # Test Case
+/// - Input: Empty graph, edgeless graph
+/// - Expected: Empty order; vertices in index order
+#[test]
+fn test_edge_cases() {
+    assert_eq!(Graph::new(0).topological_sort().unwrap(), Vec::<usize>::new());
+    let graph = Graph::new(4);
+    assert_eq!(graph.topological_sort().unwrap(), [0, 1, 2, 3]);
+    assert_eq!(graph.topological_sort_lexicographic().unwrap(), [0, 1, 2, 3]);
+    assert_eq!(graph.topological_sort_dfs().unwrap(), [3, 2, 1, 0]);
+}
+
+/// Tests a long dependency chain
+/// 
+/// # Test Case
+/// - Input: A path of 1,000,000 vertices listed in reverse, then closed into a cycle
+/// - Expected: The path order without stack overflow; then the full cycle
+#[test]
+fn test_long_chain() {
+    let n = 1_000_000;
+    let mut graph = Graph::new(n);
+    for i in (1..n).rev() {
+        graph.add_edge(i, i - 1).unwrap();
+    }
+    let expected: Vec<usize> = (0..n).rev().collect();
+    assert_eq!(graph.topological_sort().unwrap(), expected);
+    assert_eq!(graph.topological_sort_dfs().unwrap(), expected);
+
+    graph.add_edge(0, n - 1).unwrap();
+    match graph.topological_sort_dfs() {
+        Err(GraphError::Cycle(cycle)) => assert_eq!(cycle.len(), n),
+        other => panic!("expected a cycle, got {:?}", other.map(|order| order.len())),
+    }
+}
@@ -0,0 +1,99 @@
+//! Integration tests for array-encoded binary tree traversals
+//! 
+//! TEST CATEGORIES:
+//! - Orders: Pre-, in-, post- and level-order on complete and incomplete trees
+//! - Edge Cases: Empty and single-node trees
+//! - Invariants: Each traversal visits every index exactly once
+//! 
+//! DEV NOTES:
+//! - Trees use the heap layout: children of i at 2i+1 and 2i+2
+//! - In-order over a heap-layout BST must yield sorted values
+
+use dsa_in_rust::algorithms::searching::tree_traversal::{in_order, level_order, post_order, pre_order};
+
+fn indices<'a, I: Iterator<Item = (usize, &'a i32)>>(iter: I) -> Vec<usize> {
+    iter.map(|(i, _)| i).collect()
+}
+
+/// Tests all four orders on a complete tree of seven nodes
+/// 
+/// # Test Case
+/// - Tree: Indices 0..7 (three full levels)
+/// - Expected: Textbook traversal orders
+#[test]
+fn test_complete_tree_orders() {
+    let tree = [0, 1, 2, 3, 4, 5, 6];
+    assert_eq!(indices(pre_order(&tree)), [0, 1, 3, 4, 2, 5, 6]);
+    assert_eq!(indices(in_order(&tree)), [3, 1, 4, 0, 5, 2, 6]);
+    assert_eq!(indices(post_order(&tree)), [3, 4, 1, 5, 6, 2, 0]);
+    assert_eq!(indices(level_order(&tree)), [0, 1, 2, 3, 4, 5, 6]);
+}
+
+/// Tests traversals on an incomplete last level
+/// 
+/// # Test Case
+/// - Tree: Indices 0..4 (node 1 has only a left child)
+/// - Expected: Missing children are skipped
+#[test]
+fn test_incomplete_tree_orders() {
+    let tree = [0, 1, 2, 3];
+    assert_eq!(indices(pre_order(&tree)), [0, 1, 3, 2]);
+    assert_eq!(indices(in_order(&tree)), [3, 1, 0, 2]);
+    assert_eq!(indices(post_order(&tree)), [3, 1, 2, 0]);
+}
+
+/// Tests empty and single-node trees
+/// 
+/// # Test Case
+/// - Tree: [] and [42]
+/// - Expected: No items, then exactly the root
+#[test]
+fn test_trivial_trees() {
+    let empty: [i32; 0] = [];
+    assert_eq!(pre_order(&empty).count(), 0);
+    assert_eq!(in_order(&empty).count(), 0);
+    assert_eq!(post_order(&empty).count(), 0);
+    assert_eq!(level_order(&empty).count(), 0);
+
+    let single = [42];
+    for order in [
+        indices(pre_order(&single)),
+        indices(in_order(&single)),
+        indices(post_order(&single)),
+        indices(level_order(&single)),
+    ] {
+        assert_eq!(order, [0]);
+    }
+}
+
+/// Tests in-order traversal of a heap-layout binary search tree
+/// 
+/// # Test Case
+/// - Tree: BST with root 4 over values 1..=7
+/// - Expected: Values in ascending order
+#[test]
+fn test_in_order_bst_sorted() {
+    let bst = [4, 2, 6, 1, 3, 5, 7];
+    let values: Vec<i32> = in_order(&bst).map(|(_, &x)| x).collect();
+    assert_eq!(values, [1, 2, 3, 4, 5, 6, 7]);
+}
+
+/// Tests that every traversal is a permutation of the indices
+/// 
+/// # Test Case
+/// - Tree: 1,000 nodes
+/// - Expected: Each order visits 0..1000 exactly once
+#[test]
+fn test_visits_every_node_once() {
+    let tree: Vec<i32> = (0..1_000).collect();
+    for mut order in [
+        indices(pre_order(&tree)),
+        indices(in_order(&tree)),
+        indices(post_order(&tree)),
+        indices(level_order(&tree)),
+    ] {
+        order.sort();
+        assert_eq!(order, (0..1_000).collect::<Vec<_>>());
+    }
+    assert_eq!(level_order(&tree).len(), 1_000);
+}
@@ -0,0 +1,150 @@
+//! Integration tests for Ternary, Golden-Section and Answer Search
+//! 
+//! TEST CATEGORIES:
+//! - Ternary Search: Minima and maxima over integer ranges, plateaus at the optimum
+//! - Golden Section: Float optima within tolerance, evaluation counts
+//! - Partition Point: Monotone predicates over integer and float domains
+//! - Edge Cases: Empty and single-point ranges, extreme integer bounds
+//! 
+//! DEV NOTES:
+//! - Evaluation counts are asserted as upper bounds only
+
+use dsa_in_rust::algorithms::searching::binary_search::partition_point;
+use dsa_in_rust::algorithms::searching::unimodal_search::{golden_section_search, ternary_search, Goal};
+
+/// Tests ternary search against a linear scan
+/// 
+/// # Test Case
+/// - Input: Parabolas with every vertex in -50..=50 over -60..=60
+/// - Expected: The scan's optimum for both goals
+#[test]
+fn test_ternary_matches_scan() {
+    for vertex in -50..=50i64 {
+        let bowl = |x: i64| (x - vertex) * (x - vertex);
+        let hill = |x: i64| -(x - vertex) * (x - vertex);
+        let min = ternary_search(-60..=60, Goal::Minimize, bowl).unwrap();
+        let max = ternary_search(-60..=60, Goal::Maximize, hill).unwrap();
+        assert_eq!((min.argument, min.value), (vertex, 0));
+        assert_eq!((max.argument, max.value), (vertex, 0));
+    }
+}
+
+/// Tests an optimum at the range boundary and a flat optimum
+/// 
+/// # Test Case
+/// - Input: Monotone function; function flat on 10..=20
+/// - Expected: Boundary argument; an argument inside the plateau
+#[test]
+fn test_ternary_boundaries_and_plateau() {
+    assert_eq!(ternary_search(0..=1000, Goal::Minimize, |x| x).unwrap().argument, 0);
+    assert_eq!(ternary_search(0..=1000, Goal::Maximize, |x| x).unwrap().argument, 1000);
+
+    let flat = |x: i64| if x < 10 { 10 - x } else if x > 20 { x - 20 } else { 0 };
+    let best = ternary_search(-1000..=1000, Goal::Minimize, flat).unwrap();
+    assert!((10..=20).contains(&best.argument));
+    assert_eq!(best.value, 0);
+}
+
+/// Tests evaluation counts and extreme bounds
+/// 
+/// # Test Case
+/// - Input: The full i64 domain
+/// - Expected: Correct optimum with a logarithmic number of evaluations
+#[test]
+fn test_ternary_full_domain() {
+    let target = 123_456_789_012i64;
+    let distance = |x: i64| (x as i128 - target as i128).abs();
+    let best = ternary_search(i64::MIN..=i64::MAX, Goal::Minimize, distance).unwrap();
+    assert_eq!(best.argument, target);
+    assert!(best.evaluations <= 250, "{} evaluations", best.evaluations);
+}
+
+/// Tests empty and single-point ranges
+/// 
+/// # Test Case
+/// - Input: 5..=4 and 7..=7
+/// - Expected: None; the only point
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn test_ternary_small_ranges() {
+    assert!(ternary_search(5..=4, Goal::Minimize, |x| x).is_none());
+    let single = ternary_search(7..=7, Goal::Maximize, |x| x * 2).unwrap();
+    assert_eq!((single.argument, single.value, single.evaluations), (7, 14, 1));
+}
+
+/// Tests golden-section search on smooth functions
+/// 
+/// # Test Case
+/// - Input: Shifted parabola and cosine
+/// - Expected: Optimum within tolerance, few evaluations
+#[test]
+fn test_golden_section() {
+    let best = golden_section_search(-10.0..=10.0, 1e-8, Goal::Minimize, |x| (x - 3.25).powi(2) + 1.0);
+    assert!((best.argument - 3.25).abs() < 1e-7);
+    assert!((best.value - 1.0).abs() < 1e-12);
+    assert!(best.evaluations <= 60, "{} evaluations", best.evaluations);
+
+    let peak = golden_section_search(0.0..=6.0, 1e-10, Goal::Maximize, f64::sin);
+    assert!((peak.argument - std::f64::consts::FRAC_PI_2).abs() < 1e-6);
+}
+
+/// Tests golden-section search with an optimum at the boundary and zero tolerance
+/// 
+/// # Test Case
+/// - Input: Increasing function; tolerance 0
+/// - Expected: Argument converges to the upper bound and the loop terminates
+#[test]
+fn test_golden_section_edges() {
+    let best = golden_section_search(0.0..=1.0, 0.0, Goal::Maximize, |x| x);
+    assert!((best.argument - 1.0).abs() < 1e-12);
+}
+
+/// Tests partition_point over integers
+/// 
+/// # Test Case
+/// - Input: Threshold predicates across signed, unsigned and extreme ranges
+/// - Expected: Exact first false value, or the range end
+#[test]
+fn test_partition_point_integers() {
+    for boundary in -20..=20i32 {
+        assert_eq!(partition_point(-10..10, 1, |x| x < boundary), boundary.clamp(-10, 10));
+    }
+    assert_eq!(partition_point(0u8..255, 1, |x| x < 200), 200);
+    assert_eq!(partition_point(i64::MIN..i64::MAX, 1, |x| x < -5), -5);
+    assert_eq!(partition_point(0usize..usize::MAX, 1, |x| x <= 1 << 40), (1 << 40) + 1);
+    assert_eq!(partition_point(0..100, 0, |x| x < 37), 37);
+}
+
+/// Tests partition_point with a coarse integer precision
+/// 
+/// # Test Case
+/// - Input: Precision 10 over 0..10_000
+/// - Expected: A false value at most 9 above the boundary, with fewer calls
+#[test]
+fn test_partition_point_coarse() {
+    let mut calls = 0;
+    let found = partition_point(0..10_000, 10, |x| {
+        calls += 1;
+        x < 4321
+    });
+    assert!((4321..4331).contains(&found));
+    assert!(calls <= 11);
+}
+
+/// Tests partition_point over floats
+/// 
+/// # Test Case
+/// - Input: Cube root and a budget-constrained rate
+/// - Expected: Values within the requested precision
+#[test]
+fn test_partition_point_floats() {
+    let cube_root = partition_point(0.0..10.0, 1e-12, |x: f64| x * x * x < 27.0);
+    assert!((cube_root - 3.0).abs() <= 1e-12);
+
+    let rate = partition_point(0.0f32..1.0, 1e-4, |r| 100.0 * r < 42.0);
+    assert!(rate >= 0.42 && rate - 0.42 <= 1e-4);
+
+    // Zero precision stops once the interval can no longer be split
+    let exact = partition_point(0.0..1.0, 0.0, |x: f64| x < 0.3);
+    assert!(exact >= 0.3 && exact - 0.3 <= f64::EPSILON);
+}
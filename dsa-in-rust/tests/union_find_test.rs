@@ -0,0 +1,78 @@
+//! Integration tests for Union-Find
+//! 
+//! TEST CATEGORIES:
+//! - Basic Operations: Union, find, set sizes and counts
+//! - Randomized: Against a naive component labelling
+//! - Scale: Long union chains (iterative find)
+//! 
+//! DEV NOTES:
+//! - The naive reference relabels a whole set on every merge
+
+use dsa_in_rust::data_structures::union_find::UnionFind;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Tests basic operations
+/// 
+/// # Test Case
+/// - Input: 6 elements; unions 0-1, 2-3, 1-3
+/// - Expected: {0, 1, 2, 3}, {4}, {5}
+#[test]
+fn test_basic_operations() {
+    let mut sets = UnionFind::new(6);
+    assert_eq!((sets.len(), sets.set_count()), (6, 6));
+    assert!(sets.union(0, 1));
+    assert!(sets.union(2, 3));
+    assert!(sets.union(1, 3));
+    assert!(!sets.union(0, 2));
+    assert_eq!(sets.set_count(), 3);
+    assert_eq!(sets.set_size(2), 4);
+    assert_eq!(sets.find(0), sets.find(3));
+    assert!(!sets.connected(4, 5));
+    assert!(UnionFind::new(0).is_empty());
+}
+
+/// Tests random unions against a naive labelling
+/// 
+/// # Test Case
+/// - Input: 2000 random unions and queries over 100 elements
+/// - Expected: Same connectivity, set sizes and set counts as the reference
+#[test]
+fn test_random_operations() {
+    let mut rng = StdRng::seed_from_u64(47);
+    let n = 100;
+    let mut sets = UnionFind::new(n);
+    let mut labels: Vec<usize> = (0..n).collect();
+    for _ in 0..2000 {
+        let (a, b) = (rng.gen_range(0..n), rng.gen_range(0..n));
+        if rng.gen_bool(0.3) {
+            let merged = labels[a] != labels[b];
+            assert_eq!(sets.union(a, b), merged);
+            let (old, new) = (labels[b], labels[a]);
+            labels.iter_mut().filter(|label| **label == old).for_each(|label| *label = new);
+        }
+        assert_eq!(sets.connected(a, b), labels[a] == labels[b]);
+        assert_eq!(sets.set_size(a), labels.iter().filter(|&&label| label == labels[a]).count());
+    }
+    let mut distinct = labels.clone();
+    distinct.sort();
+    distinct.dedup();
+    assert_eq!(sets.set_count(), distinct.len());
+}
+
+/// Tests a long chain of unions
+/// 
+/// # Test Case
+/// - Input: 1,000,000 elements joined as i-(i+1)
+/// - Expected: One set containing everything
+#[test]
+fn test_long_chain() {
+    let n = 1_000_000;
+    let mut sets = UnionFind::new(n);
+    for i in 0..n - 1 {
+        assert!(sets.union(i, i + 1));
+    }
+    assert_eq!(sets.set_count(), 1);
+    assert_eq!(sets.set_size(0), n);
+    assert!(sets.connected(0, n - 1));
+}